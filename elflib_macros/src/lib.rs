@@ -213,8 +213,16 @@ fn variants_find_common_name_prefix(variants: &[syn::ItemStruct]) -> String {
     strings_find_common_prefix(&variant_names)
 }
 
-fn gen_variant_struct_derives(variant: &syn::ItemStruct) -> proc_macro2::TokenStream {
-    if variant.attrs.iter().any(|attr| {
+fn gen_variant_struct_derives(
+    variant: &syn::ItemStruct,
+    enum_name: &str,
+) -> proc_macro2::TokenStream {
+    // all variants are serialized under the name of the combined enum, so that the serialized form doesn't depend on
+    // the bit length of the file.
+    let serde_derives = quote! {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize), serde(rename = #enum_name))]
+    };
+    let derives = if variant.attrs.iter().any(|attr| {
         matches!(attr, syn::Attribute { style: syn::AttrStyle::Outer, meta: syn::Meta::List(syn::MetaList {
             path, ..
         }), .. } if path.to_token_stream().to_string() == "binary_serde_bitfield")
@@ -226,6 +234,10 @@ fn gen_variant_struct_derives(variant: &syn::ItemStruct) -> proc_macro2::TokenSt
         quote! {
             #[derive(Debug, BinarySerde, PartialEq, Eq, Clone, Hash)]
         }
+    };
+    quote! {
+        #derives
+        #serde_derives
     }
 }
 
@@ -314,6 +326,14 @@ fn gen_ref_wrapper(
 
         #deref_impl
         #deserialize_impl
+
+        #[cfg(feature = "serde")]
+        #[automatically_derived]
+        impl<'a> ::serde::Serialize for #wrapper_ident<'a> {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                ::serde::Serialize::serialize(&self.raw, serializer)
+            }
+        }
     }
 }
 
@@ -351,6 +371,23 @@ fn gen_raw_struct_by_variants(
         variant.vis = public_visibility.clone();
         for field in &mut variant.fields {
             field.vis = public_visibility.clone();
+
+            // fields whose size depends on the bit length of the file are addresses, offsets and sizes, so they are
+            // serialized as hex.
+            let type_info = &field_type_by_name[&field.ident.as_ref().unwrap().to_string()];
+            if !type_info.are_all_fields_of_the_same_type
+                && matches!(
+                    type_info.field_type,
+                    VariantFieldType::Int {
+                        is_signed: false,
+                        ..
+                    }
+                )
+            {
+                field.attrs.push(syn::parse_quote! {
+                    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serde_support::serialize_hex"))]
+                });
+            }
         }
     }
     let enum_variants = variants.iter().map(|variant| {
@@ -415,6 +452,23 @@ fn gen_raw_struct_by_variants(
     });
     let enum_derives = gen_enum_derives();
     let enum_ident = quote::format_ident!("{}", &variant_names_common_prefix,);
+    let enum_serialize_branches = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        quote! {
+            Self::#ident(x) => ::serde::Serialize::serialize(x, serializer),
+        }
+    });
+    let serialize_impl = quote! {
+        #[cfg(feature = "serde")]
+        #[automatically_derived]
+        impl ::serde::Serialize for #enum_ident {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                match self {
+                    #(#enum_serialize_branches)*
+                }
+            }
+        }
+    };
     let is_generic_bitlen_struct = are_variants_of_generic_bitlen_struct(&variants);
     let deserialize_impl = if is_generic_bitlen_struct {
         let self_32 = quote::format_ident!("{}32", enum_ident);
@@ -459,10 +513,12 @@ fn gen_raw_struct_by_variants(
             #(#field_access_fns)*
         }
         #deserialize_impl
+        #serialize_impl
     };
     let ref_wrapper = gen_ref_wrapper(&enum_ident, context_ty);
+    let enum_name = enum_ident.to_string();
     let variants_with_derives = variants.into_iter().map(|variant| {
-        let derives = gen_variant_struct_derives(&variant);
+        let derives = gen_variant_struct_derives(&variant, &enum_name);
         quote! {
             #derives
             #variant
@@ -577,6 +633,7 @@ impl VariantsFieldTypeInfo {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 enum VariantFieldType {
     Int { bit_length: usize, is_signed: bool },
     Other(syn::Type),
@@ -584,7 +641,7 @@ enum VariantFieldType {
 impl VariantFieldType {
     fn from_syn_type(ty: syn::Type) -> Self {
         let type_str = ty.to_token_stream().to_string();
-        let Some(&first_char) = type_str.as_bytes().get(0) else {
+        let Some(&first_char) = type_str.as_bytes().first() else {
             return Self::Other(ty);
         };
         if first_char == b'u' || first_char == b'i' {
@@ -0,0 +1,348 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    is_exported_definition, ElfParser, MaybeUnknown, Result, SectionData, SymbolBinding,
+    SymbolType, SymbolVisibility, SHN_ABS,
+};
+
+/// whether an abi change can break binaries which were linked against the old version of the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AbiSeverity {
+    Compatible,
+    Breaking,
+}
+
+/// a change of the abi of a shared library.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiChange {
+    /// a version node was added to the `.gnu.version_d` section. the name of the change is the name of the version.
+    VersionNodeAdded,
+
+    /// a version node was removed from the `.gnu.version_d` section. the name of the change is the name of the
+    /// version.
+    VersionNodeRemoved,
+
+    SymbolAdded,
+    SymbolRemoved,
+
+    /// the default version of the symbol, which is the version used when linking against the library, was changed.
+    DefaultVersionChanged {
+        old: Option<Vec<u8>>,
+        new: Option<Vec<u8>>,
+    },
+
+    /// an unversioned symbol was given a version. binaries which were linked against the old library reference the
+    /// symbol without a version, and are bound to its new default version.
+    VersionAdded {
+        new: Vec<u8>,
+    },
+
+    /// the size of a data object was changed, which breaks copy relocations.
+    SizeChanged {
+        old: u64,
+        new: u64,
+    },
+    TypeChanged {
+        old: MaybeUnknown<SymbolType>,
+        new: MaybeUnknown<SymbolType>,
+    },
+    BindingChanged {
+        old: MaybeUnknown<SymbolBinding>,
+        new: MaybeUnknown<SymbolBinding>,
+    },
+    VisibilityChanged {
+        old: SymbolVisibility,
+        new: SymbolVisibility,
+    },
+}
+
+/// a single difference between the abis of two versions of a shared library.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbiDifference {
+    /// the name of the symbol, or of the version node for version node changes.
+    pub name: Vec<u8>,
+
+    /// the version of the symbol, if it has one.
+    pub version: Option<Vec<u8>>,
+    pub change: AbiChange,
+    pub severity: AbiSeverity,
+}
+
+/// the differences between the exported dynamic symbols of two versions of a shared library, sorted by name and
+/// version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbiReport {
+    pub differences: Vec<AbiDifference>,
+}
+impl AbiReport {
+    /// can binaries which were linked against the old version of the library use the new version.
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes().next().is_none()
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &AbiDifference> {
+        self.differences
+            .iter()
+            .filter(|difference| difference.severity == AbiSeverity::Breaking)
+    }
+}
+
+/// an exported symbol of a library, as seen by the abi checker.
+#[derive(Debug, Clone, Copy)]
+struct ExportedSymbol {
+    ty: MaybeUnknown<SymbolType>,
+    binding: MaybeUnknown<SymbolBinding>,
+    visibility: SymbolVisibility,
+    size: u64,
+
+    /// the symbol can only be bound using an explicit version.
+    hidden: bool,
+}
+
+/// the abi of a library, which is its exported dynamic symbols, keyed by name and version, and its version nodes.
+struct LibraryAbi<'a> {
+    symbols: BTreeMap<(&'a [u8], Option<&'a [u8]>), ExportedSymbol>,
+    version_nodes: Vec<&'a [u8]>,
+}
+impl<'a> LibraryAbi<'a> {
+    fn new(parser: &ElfParser<'a>) -> Result<Self> {
+        let versions = parser.symbol_versions()?;
+        let mut abi = Self {
+            symbols: BTreeMap::new(),
+            version_nodes: Vec::new(),
+        };
+        if let Some(versions) = &versions {
+            for version in versions.defined_versions() {
+                let version = version?;
+                if !version.is_base() {
+                    abi.version_nodes.push(version.name);
+                }
+            }
+        }
+        for section in parser.section_headers()? {
+            let SectionData::DynamicSymbolTable(symbols) = section?.data()? else {
+                continue;
+            };
+            for (index, symbol) in symbols.iter().enumerate() {
+                let symbol = symbol?;
+                if !is_exported_definition(&symbol) {
+                    continue;
+                }
+                let version = match &versions {
+                    Some(versions) => versions.version(index)?,
+                    None => None,
+                };
+                let name = symbol.name_bytes()?;
+                let version_name = version.and_then(|version| version.name());
+                // the linker defines an absolute symbol for each version node, which is already covered by the
+                // version node changes.
+                if symbol.related_section_index() == SHN_ABS && version_name == Some(name) {
+                    continue;
+                }
+                let info = symbol.info();
+                // the first definition of a symbol and version is the one which the dynamic loader binds to.
+                abi.symbols
+                    .entry((name, version_name))
+                    .or_insert(ExportedSymbol {
+                        ty: info.ty,
+                        binding: info.binding,
+                        visibility: symbol.other_info().visibility,
+                        size: symbol.size(),
+                        hidden: version.is_some_and(|version| version.is_hidden()),
+                    });
+            }
+        }
+        Ok(abi)
+    }
+
+    /// the version which is used when linking against the library without specifying a version.
+    fn default_version_of(&self, name: &[u8]) -> Option<Option<&'a [u8]>> {
+        self.symbols
+            .range((name, None)..)
+            .take_while(|((symbol_name, _), _)| *symbol_name == name)
+            .find(|(_, symbol)| !symbol.hidden)
+            .map(|((_, version), _)| *version)
+    }
+}
+
+/// compares the exported dynamic symbols of two versions of a shared library, and classifies each difference as
+/// breaking or compatible for binaries which were linked against the old version.
+pub fn compare_abi(old: &ElfParser, new: &ElfParser) -> Result<AbiReport> {
+    let old = LibraryAbi::new(old)?;
+    let new = LibraryAbi::new(new)?;
+    let mut differences = Vec::new();
+    let mut push = |name: &[u8], version: Option<&[u8]>, change: AbiChange, severity| {
+        differences.push(AbiDifference {
+            name: name.to_vec(),
+            version: version.map(<[u8]>::to_vec),
+            change,
+            severity,
+        })
+    };
+
+    for &node in &old.version_nodes {
+        if !new.version_nodes.contains(&node) {
+            push(
+                node,
+                None,
+                AbiChange::VersionNodeRemoved,
+                AbiSeverity::Breaking,
+            );
+        }
+    }
+    for &node in &new.version_nodes {
+        if !old.version_nodes.contains(&node) {
+            push(
+                node,
+                None,
+                AbiChange::VersionNodeAdded,
+                AbiSeverity::Compatible,
+            );
+        }
+    }
+
+    // the new symbols which unversioned symbols of the old library are bound to.
+    let mut newly_versioned = Vec::new();
+    for (&(name, version), old_symbol) in &old.symbols {
+        let new_symbol = match new.symbols.get(&(name, version)) {
+            Some(new_symbol) => new_symbol,
+            None => {
+                // an unversioned reference binds to the default version of the symbol.
+                let new_version = new
+                    .default_version_of(name)
+                    .flatten()
+                    .filter(|_| version.is_none());
+                let Some(new_version) = new_version else {
+                    push(
+                        name,
+                        version,
+                        AbiChange::SymbolRemoved,
+                        AbiSeverity::Breaking,
+                    );
+                    continue;
+                };
+                let change = AbiChange::VersionAdded {
+                    new: new_version.to_vec(),
+                };
+                push(name, version, change, AbiSeverity::Compatible);
+                newly_versioned.push((name, Some(new_version)));
+                &new.symbols[&(name, Some(new_version))]
+            }
+        };
+
+        if old_symbol.ty != new_symbol.ty {
+            let severity = if type_class(old_symbol.ty) == type_class(new_symbol.ty) {
+                AbiSeverity::Compatible
+            } else {
+                AbiSeverity::Breaking
+            };
+            let change = AbiChange::TypeChanged {
+                old: old_symbol.ty,
+                new: new_symbol.ty,
+            };
+            push(name, version, change, severity);
+        } else if type_class(old_symbol.ty) == TypeClass::Data && old_symbol.size != new_symbol.size
+        {
+            // executables hold copies of the data objects which they reference, whose size is fixed at link time.
+            let change = AbiChange::SizeChanged {
+                old: old_symbol.size,
+                new: new_symbol.size,
+            };
+            push(name, version, change, AbiSeverity::Breaking);
+        }
+
+        if old_symbol.binding != new_symbol.binding {
+            let is_weak_or_global = |binding: MaybeUnknown<SymbolBinding>| {
+                matches!(
+                    binding.known(),
+                    Some(SymbolBinding::Global | SymbolBinding::Weak)
+                )
+            };
+            let severity =
+                if is_weak_or_global(old_symbol.binding) && is_weak_or_global(new_symbol.binding) {
+                    AbiSeverity::Compatible
+                } else {
+                    AbiSeverity::Breaking
+                };
+            let change = AbiChange::BindingChanged {
+                old: old_symbol.binding,
+                new: new_symbol.binding,
+            };
+            push(name, version, change, severity);
+        }
+
+        if old_symbol.visibility != new_symbol.visibility {
+            // a protected data object can't be copied into the executable.
+            let severity = if new_symbol.visibility == SymbolVisibility::Protected
+                && type_class(new_symbol.ty) == TypeClass::Data
+            {
+                AbiSeverity::Breaking
+            } else {
+                AbiSeverity::Compatible
+            };
+            let change = AbiChange::VisibilityChanged {
+                old: old_symbol.visibility,
+                new: new_symbol.visibility,
+            };
+            push(name, version, change, severity);
+        }
+    }
+
+    for &(name, version) in new.symbols.keys() {
+        if !old.symbols.contains_key(&(name, version))
+            && !newly_versioned.contains(&(name, version))
+        {
+            push(
+                name,
+                version,
+                AbiChange::SymbolAdded,
+                AbiSeverity::Compatible,
+            );
+        }
+    }
+
+    let mut names: Vec<&[u8]> = old.symbols.keys().map(|(name, _)| *name).collect();
+    names.dedup();
+    for name in names {
+        let (Some(old_default), Some(new_default)) =
+            (old.default_version_of(name), new.default_version_of(name))
+        else {
+            continue;
+        };
+        if old_default != new_default
+            && !newly_versioned
+                .iter()
+                .any(|&(newly_versioned_name, _)| newly_versioned_name == name)
+        {
+            // binaries which were linked against the old library reference the old version, so only newly linked
+            // binaries are affected.
+            let change = AbiChange::DefaultVersionChanged {
+                old: old_default.map(<[u8]>::to_vec),
+                new: new_default.map(<[u8]>::to_vec),
+            };
+            push(name, None, change, AbiSeverity::Compatible);
+        }
+    }
+
+    differences.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    Ok(AbiReport { differences })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeClass {
+    Code,
+    Data,
+    ThreadLocal,
+    Other,
+}
+
+/// the kind of entity which a symbol of the given type describes. changing the type of a symbol within the same class,
+/// for example from a function to an ifunc, is compatible.
+fn type_class(ty: MaybeUnknown<SymbolType>) -> TypeClass {
+    match ty.known() {
+        Some(SymbolType::Func | SymbolType::GnuIfunc) => TypeClass::Code,
+        Some(SymbolType::Object | SymbolType::Common) => TypeClass::Data,
+        Some(SymbolType::Tls) => TypeClass::ThreadLocal,
+        _ => TypeClass::Other,
+    }
+}
@@ -0,0 +1,196 @@
+//! a small patchelf-like tool for editing the dynamic linking information of elf files.
+
+use std::{ffi::OsString, process::ExitCode};
+
+use elflib::{DynamicEditor, ElfParser, LossyStr};
+
+const USAGE: &str = "usage: elfpatch [options] <file>
+
+options:
+    --print-interpreter              print the program interpreter
+    --print-needed                   print the needed libraries
+    --print-soname                   print the soname
+    --print-rpath                    print the rpath or runpath
+    --set-interpreter <path>         set the program interpreter
+    --set-rpath <path>               set the runpath, removing the rpath
+    --force-rpath                    make --set-rpath set the rpath instead of the runpath
+    --remove-rpath                   remove both the rpath and the runpath
+    --add-needed <library>           add a needed library
+    --remove-needed <library>        remove a needed library
+    --replace-needed <old> <new>     replace a needed library
+    --set-soname <soname>            set the soname
+    --output <file>                  write the result to the given file instead of modifying the file in place";
+
+enum Action {
+    PrintInterpreter,
+    PrintNeeded,
+    PrintSoname,
+    PrintRpath,
+    SetInterpreter(Vec<u8>),
+    SetRpath(Vec<u8>),
+    RemoveRpath,
+    AddNeeded(Vec<u8>),
+    RemoveNeeded(Vec<u8>),
+    ReplaceNeeded(Vec<u8>, Vec<u8>),
+    SetSoname(Vec<u8>),
+}
+impl Action {
+    fn modifies_file(&self) -> bool {
+        !matches!(
+            self,
+            Action::PrintInterpreter
+                | Action::PrintNeeded
+                | Action::PrintSoname
+                | Action::PrintRpath
+        )
+    }
+}
+
+struct Args {
+    actions: Vec<Action>,
+    force_rpath: bool,
+    output: Option<OsString>,
+    input: OsString,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args_os().skip(1);
+    let mut actions = Vec::new();
+    let mut force_rpath = false;
+    let mut output = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .map(OsString::into_encoded_bytes)
+                .ok_or_else(|| format!("missing value for {}", arg.to_string_lossy()))
+        };
+        let action = match arg.to_str() {
+            Some("--print-interpreter") => Action::PrintInterpreter,
+            Some("--print-needed") => Action::PrintNeeded,
+            Some("--print-soname") => Action::PrintSoname,
+            Some("--print-rpath") => Action::PrintRpath,
+            Some("--set-interpreter") => Action::SetInterpreter(value()?),
+            Some("--set-rpath") => Action::SetRpath(value()?),
+            Some("--remove-rpath") => Action::RemoveRpath,
+            Some("--add-needed") => Action::AddNeeded(value()?),
+            Some("--remove-needed") => Action::RemoveNeeded(value()?),
+            Some("--replace-needed") => Action::ReplaceNeeded(value()?, value()?),
+            Some("--set-soname") => Action::SetSoname(value()?),
+            Some("--force-rpath") => {
+                force_rpath = true;
+                continue;
+            }
+            Some("--output") => {
+                output = Some(args.next().ok_or("missing value for --output")?);
+                continue;
+            }
+            Some(option) if option.starts_with("--") => {
+                return Err(format!("unknown option {}", option))
+            }
+            _ => {
+                if input.replace(arg).is_some() {
+                    return Err("more than one input file was specified".into());
+                }
+                continue;
+            }
+        };
+        actions.push(action);
+    }
+    Ok(Args {
+        actions,
+        force_rpath,
+        output,
+        input: input.ok_or("no input file was specified")?,
+    })
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let content = std::fs::read(&args.input)
+        .map_err(|err| format!("failed to read {}: {}", args.input.to_string_lossy(), err))?;
+    let parser = ElfParser::new(&content).map_err(|err| err.to_string())?;
+    let mut editor = DynamicEditor::new(&parser).map_err(|err| err.to_string())?;
+    let edit_error = |err: elflib::Error| err.to_string();
+    let mut modified = false;
+    for action in &args.actions {
+        match action {
+            Action::PrintInterpreter => match editor.interpreter() {
+                Some(interpreter) => println!("{}", LossyStr(interpreter)),
+                None => return Err("file has no program interpreter".into()),
+            },
+            Action::PrintNeeded => {
+                for needed in editor.needed() {
+                    println!("{}", LossyStr(needed));
+                }
+            }
+            Action::PrintSoname => {
+                if let Some(soname) = editor.soname() {
+                    println!("{}", LossyStr(soname));
+                }
+            }
+            Action::PrintRpath => {
+                if let Some(path) = editor.runpath().or(editor.rpath()) {
+                    println!("{}", LossyStr(path));
+                }
+            }
+            Action::SetInterpreter(interpreter) => editor
+                .set_interpreter(interpreter.as_slice())
+                .map_err(edit_error)?,
+            Action::SetRpath(path) => {
+                if args.force_rpath {
+                    editor.set_runpath(None).map_err(edit_error)?;
+                    editor.set_rpath(Some(path)).map_err(edit_error)?;
+                } else {
+                    editor.set_rpath(None).map_err(edit_error)?;
+                    editor.set_runpath(Some(path)).map_err(edit_error)?;
+                }
+            }
+            Action::RemoveRpath => {
+                editor.set_rpath(None).map_err(edit_error)?;
+                editor.set_runpath(None).map_err(edit_error)?;
+            }
+            Action::AddNeeded(name) => editor.add_needed(name).map_err(edit_error)?,
+            Action::RemoveNeeded(name) => {
+                editor.remove_needed(name);
+            }
+            Action::ReplaceNeeded(old_name, new_name) => {
+                editor
+                    .replace_needed(old_name, new_name)
+                    .map_err(edit_error)?;
+            }
+            Action::SetSoname(soname) => editor.set_soname(soname).map_err(edit_error)?,
+        }
+        modified |= action.modifies_file();
+    }
+    if !modified && args.output.is_none() {
+        return Ok(());
+    }
+    let result = editor.write().map_err(|err| err.to_string())?;
+    let output = args.output.as_ref().unwrap_or(&args.input);
+    let write_error =
+        |err: std::io::Error| format!("failed to write {}: {}", output.to_string_lossy(), err);
+    std::fs::write(output, result).map_err(write_error)?;
+
+    // the output must keep the mode of the input, so that patched executables stay executable.
+    let permissions = std::fs::metadata(&args.input)
+        .map_err(|err| format!("failed to read {}: {}", args.input.to_string_lossy(), err))?
+        .permissions();
+    std::fs::set_permissions(output, permissions).map_err(write_error)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("elfpatch: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("elfpatch: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
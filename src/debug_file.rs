@@ -0,0 +1,192 @@
+use std::{
+    ffi::OsStr,
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+};
+
+use crate::{gnu_debuglink_crc32, ElfParser, Result};
+
+/// the default global debug directory.
+pub const DEFAULT_DEBUG_DIRECTORY: &str = "/usr/lib/debug";
+
+/// how a debug file was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugFileMatch {
+    /// the debug file was found in a `.build-id` directory, and has the same build id as the file.
+    BuildId,
+
+    /// the debug file was found using the `.gnu_debuglink` section of the file, and its crc32 checksum matches.
+    DebugLink,
+}
+
+/// a separate debug file which was found for an elf file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolvedDebugFile {
+    /// the path of the debug file, including the root directory of the resolver.
+    pub path: PathBuf,
+    pub found_by: DebugFileMatch,
+}
+
+/// finds the separate debug files of elf files, using the same search order as gdb.
+///
+/// the debug file is first searched by the build id of the file, at `<debug-dir>/.build-id/xx/yyyy.debug`. if it is
+/// not found, it is searched by the file name in the `.gnu_debuglink` section of the file, in the directory of the file,
+/// in its `.debug` subdirectory, and in the directory of the file under each of the global debug directories.
+///
+/// all paths are looked up under a configurable root directory, which allows finding the debug files of a sysroot.
+#[derive(Debug, Clone)]
+pub struct DebugFileResolver {
+    root: PathBuf,
+    debug_directories: Vec<PathBuf>,
+}
+impl Default for DebugFileResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DebugFileResolver {
+    /// creates a resolver which uses the default global debug directory and the root directory `/`.
+    pub fn new() -> Self {
+        Self {
+            root: PathBuf::from("/"),
+            debug_directories: vec![PathBuf::from(DEFAULT_DEBUG_DIRECTORY)],
+        }
+    }
+
+    /// sets the root directory under which all paths are looked up.
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// replaces the global debug directories.
+    pub fn with_debug_directories(
+        mut self,
+        debug_directories: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.debug_directories = debug_directories.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn debug_directories(&self) -> &[PathBuf] {
+        &self.debug_directories
+    }
+
+    /// translates a path as seen inside the root directory to a path which can be accessed. relative paths are
+    /// relative to the current directory, and are not translated.
+    fn under_root(&self, path: &Path) -> PathBuf {
+        match path.strip_prefix("/") {
+            Ok(relative_path) => self.root.join(relative_path),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+
+    /// returns the path of the file with the given build id in the `.build-id` directory of each of the global debug
+    /// directories, with the given extension.
+    fn build_id_paths(&self, build_id: &[u8], extension: &str) -> Vec<PathBuf> {
+        let Some((first, rest)) = build_id.split_first() else {
+            return Vec::new();
+        };
+        let mut file_name: String = rest.iter().map(|byte| format!("{:02x}", byte)).collect();
+        file_name.push_str(extension);
+        self.debug_directories
+            .iter()
+            .map(|debug_directory| {
+                self.under_root(
+                    &debug_directory
+                        .join(".build-id")
+                        .join(format!("{:02x}", first))
+                        .join(&file_name),
+                )
+            })
+            .collect()
+    }
+
+    /// finds the debug file of the elf file at the given path, which is the path of the file as seen inside the root
+    /// directory.
+    pub fn resolve(
+        &self,
+        file_path: impl AsRef<Path>,
+        parser: &ElfParser,
+    ) -> Result<Option<ResolvedDebugFile>> {
+        let file_path = file_path.as_ref();
+        if let Some(build_id) = parser.build_id()? {
+            for path in self.build_id_paths(build_id, ".debug") {
+                if has_build_id(&path, build_id) {
+                    return Ok(Some(ResolvedDebugFile {
+                        path,
+                        found_by: DebugFileMatch::BuildId,
+                    }));
+                }
+            }
+        }
+
+        let Some(debuglink) = parser.debuglink()? else {
+            return Ok(None);
+        };
+        let file_name = Path::new(OsStr::from_bytes(debuglink.file_name));
+        let file_directory = file_path.parent().unwrap_or(Path::new(""));
+        let mut candidates = vec![
+            self.under_root(&file_directory.join(file_name)),
+            self.under_root(&file_directory.join(".debug").join(file_name)),
+        ];
+        for debug_directory in &self.debug_directories {
+            let relative_directory = file_directory.strip_prefix("/").unwrap_or(file_directory);
+            candidates
+                .push(self.under_root(&debug_directory.join(relative_directory).join(file_name)));
+        }
+        let own_path = self.under_root(file_path);
+        for path in candidates {
+            // the debug link may have the same name as the file itself.
+            if path == own_path {
+                continue;
+            }
+            if matches!(std::fs::read(&path), Ok(content) if gnu_debuglink_crc32(&content) == debuglink.crc)
+            {
+                return Ok(Some(ResolvedDebugFile {
+                    path,
+                    found_by: DebugFileMatch::DebugLink,
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// finds the supplementary debug file referenced by the `.gnu_debugaltlink` section of the debug file at the given
+    /// path, which is the path of the debug file including the root directory, as returned by
+    /// [`DebugFileResolver::resolve`].
+    pub fn resolve_debugaltlink(
+        &self,
+        debug_file_path: impl AsRef<Path>,
+        parser: &ElfParser,
+    ) -> Result<Option<PathBuf>> {
+        let Some(debugaltlink) = parser.debugaltlink()? else {
+            return Ok(None);
+        };
+        let file_name = Path::new(OsStr::from_bytes(debugaltlink.file_name));
+        let mut candidates = if file_name.is_absolute() {
+            vec![self.under_root(file_name)]
+        } else {
+            let debug_file_directory = debug_file_path.as_ref().parent().unwrap_or(Path::new(""));
+            vec![debug_file_directory.join(file_name)]
+        };
+        candidates.extend(self.build_id_paths(debugaltlink.build_id, ".debug"));
+        Ok(candidates
+            .into_iter()
+            .find(|path| has_build_id(path, debugaltlink.build_id)))
+    }
+}
+
+/// does the elf file at the given path have the given build id.
+fn has_build_id(path: &Path, build_id: &[u8]) -> bool {
+    let Ok(content) = std::fs::read(path) else {
+        return false;
+    };
+    ElfParser::new(&content)
+        .and_then(|parser| parser.build_id())
+        .is_ok_and(|file_build_id| file_build_id == Some(build_id))
+}
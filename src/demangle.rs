@@ -0,0 +1,174 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{Result, SymbolRef};
+
+/// options which control the output of symbol demangling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DemangleOptions {
+    hide_params: bool,
+    hide_templates: bool,
+    hide_hash: bool,
+}
+impl DemangleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// don't display the parameters and return types of c++ functions.
+    pub fn hide_params(mut self) -> Self {
+        self.hide_params = true;
+        self
+    }
+
+    /// don't display template arguments, or generic arguments for rust symbols.
+    pub fn hide_templates(mut self) -> Self {
+        self.hide_templates = true;
+        self
+    }
+
+    /// don't display the hash at the end of rust symbols.
+    pub fn hide_hash(mut self) -> Self {
+        self.hide_hash = true;
+        self
+    }
+}
+
+/// demangles an itanium c++ (`_Z`) or rust (legacy `_ZN...E` and v0 `_R`) symbol name. a `@VERSION` or `@@VERSION`
+/// suffix, which appears in the names of versioned symbols in static symbol tables, is kept as is after the demangled
+/// name.
+///
+/// returns `None` if the name is not a mangled name, or if it can't be demangled.
+pub fn demangle(name: &str) -> Option<String> {
+    demangle_with_options(name, &DemangleOptions::new())
+}
+
+/// demangles the given symbol name using the given options. see `demangle` for more information.
+pub fn demangle_with_options(name: &str, options: &DemangleOptions) -> Option<String> {
+    let (base_name, version) = match name.find('@') {
+        Some(version_start) => name.split_at(version_start),
+        None => (name, ""),
+    };
+    let mut demangled = demangle_base_name(base_name, options)?;
+    if options.hide_templates {
+        demangled = strip_template_args(&demangled);
+    }
+    demangled.push_str(version);
+    Some(demangled)
+}
+
+fn demangle_base_name(name: &str, options: &DemangleOptions) -> Option<String> {
+    if !name.starts_with("_Z") && !name.starts_with("_R") {
+        return None;
+    }
+
+    // legacy rust symbols are also valid itanium symbols, so rust demangling must be tried first.
+    if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+        let mut out = String::new();
+        let result = if options.hide_hash {
+            write!(out, "{:#}", demangled)
+        } else {
+            write!(out, "{}", demangled)
+        };
+        return result.ok().map(|()| out);
+    }
+
+    if !name.starts_with("_Z") {
+        return None;
+    }
+    let symbol = cpp_demangle::Symbol::new(name.as_bytes()).ok()?;
+    let mut cpp_options = cpp_demangle::DemangleOptions::new();
+    if options.hide_params {
+        cpp_options = cpp_options.no_params().no_return_type();
+    }
+    symbol.demangle_with_options(&cpp_options).ok()
+}
+
+/// the c++ operators which contain angle brackets, longest first.
+const ANGLE_BRACKET_OPERATORS: &[&str] = &[
+    "<=>", "<<=", ">>=", "->*", "<<", "<=", ">>", ">=", "->", "<", ">",
+];
+
+/// removes the template arguments from a demangled name, for example `std::vector<int>::push_back` becomes
+/// `std::vector::push_back`, and `core::mem::drop::<u8>` becomes `core::mem::drop`. angle brackets which don't follow a
+/// name, like those of rust qualified paths (`<T as Trait>::method`), and angle brackets which are part of operator
+/// names are kept.
+fn strip_template_args(demangled: &str) -> String {
+    let mut out = String::with_capacity(demangled.len());
+    let mut depth = 0usize;
+    let mut after_operator = false;
+    let mut chars = demangled.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let before = &demangled[..index];
+
+        // operator names like `operator<<` and `operator->` contain angle brackets which don't open or close a
+        // template argument list.
+        if before.ends_with("operator") {
+            if let Some(operator) = ANGLE_BRACKET_OPERATORS
+                .iter()
+                .find(|operator| demangled[index..].starts_with(*operator))
+            {
+                if depth == 0 {
+                    out.push_str(operator);
+                }
+                for _ in 1..operator.len() {
+                    chars.next();
+                }
+                after_operator = true;
+                continue;
+            }
+        }
+
+        if depth > 0 {
+            match c {
+                '<' => depth += 1,
+                '>' if !before.ends_with('-') => depth -= 1,
+                _ => {}
+            }
+            continue;
+        }
+
+        if c == '<' {
+            // the template arguments of an operator may be separated from its name by a space, to avoid ambiguity
+            // with the operator's own angle brackets.
+            if after_operator {
+                if out.ends_with(' ') {
+                    out.pop();
+                }
+                depth += 1;
+                continue;
+            }
+            // rust places generic arguments of functions after a path separator.
+            if let Some(stripped_len) = out.strip_suffix("::").map(str::len) {
+                out.truncate(stripped_len);
+                depth += 1;
+                continue;
+            }
+            if out.ends_with(|prev: char| prev.is_alphanumeric() || prev == '_') {
+                depth += 1;
+                continue;
+            }
+        }
+        if c != ' ' {
+            after_operator = false;
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl<'a> SymbolRef<'a> {
+    /// returns the demangled name of the symbol, or `None` if its name is not a mangled itanium c++ or rust name. see
+    /// `demangle` for more information.
+    pub fn demangled_name(&self) -> Result<Option<String>> {
+        self.demangled_name_with_options(&DemangleOptions::new())
+    }
+
+    /// returns the demangled name of the symbol using the given options.
+    pub fn demangled_name_with_options(&self, options: &DemangleOptions) -> Result<Option<String>> {
+        let Ok(name) = core::str::from_utf8(self.name_bytes()?) else {
+            return Ok(None);
+        };
+        Ok(demangle_with_options(name, options))
+    }
+}
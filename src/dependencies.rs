@@ -0,0 +1,606 @@
+use std::{
+    ffi::OsStr,
+    os::unix::{ffi::OsStrExt, fs::MetadataExt},
+    path::{Path, PathBuf},
+};
+
+use crate::{ArchBitLength, Architechture, DynamicTag, ElfFileInfo, ElfParser, LdSoCache, Result};
+
+/// the path of the ld.so cache of the gnu dynamic loader.
+pub const DEFAULT_LD_SO_CACHE_PATH: &str = "/etc/ld.so.cache";
+
+const DF_1_NODEFLIB: u64 = 0x800;
+
+/// where a loaded object was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LibrarySearchLocation {
+    /// the object is the file whose dependencies were resolved.
+    Root,
+
+    /// the object is the program interpreter of the root file.
+    Interpreter,
+
+    /// the needed name contains a slash, so it was used as a path without searching.
+    Path,
+
+    /// the `DT_RPATH` of the object which needs the library, or of one of its loaders.
+    Rpath,
+
+    /// the configured library path, which is the equivalent of `LD_LIBRARY_PATH`.
+    LibraryPath,
+
+    /// the `DT_RUNPATH` of the object which needs the library.
+    Runpath,
+
+    /// the ld.so cache.
+    Cache,
+
+    /// one of the default library directories.
+    DefaultDirectory,
+}
+
+/// a `DT_NEEDED` entry of a loaded object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dependency {
+    /// the name of the library, as it appears in the `DT_NEEDED` entry.
+    pub name: Vec<u8>,
+
+    /// the index of the loaded object which satisfies this dependency, or `None` if the library was not found.
+    pub object: Option<usize>,
+}
+
+/// an object which is part of the dependency tree.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoadedObject {
+    /// the path of the object as seen inside the sysroot.
+    pub path: PathBuf,
+
+    /// the path of the object including the sysroot, which can be used to access it.
+    pub host_path: PathBuf,
+    pub soname: Option<Vec<u8>>,
+    pub found_by: LibrarySearchLocation,
+
+    /// the index of the object which caused this object to be loaded. only the root has no loader.
+    pub loader: Option<usize>,
+    pub dependencies: Vec<Dependency>,
+}
+
+/// the objects which are loaded for an elf file, in the order in which the dynamic loader loads them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DependencyTree {
+    /// the loaded objects. the first object is always the root file.
+    pub objects: Vec<LoadedObject>,
+}
+impl DependencyTree {
+    pub fn root(&self) -> &LoadedObject {
+        &self.objects[0]
+    }
+
+    /// the loaded objects other than the root file, including the program interpreter.
+    pub fn libraries(&self) -> &[LoadedObject] {
+        &self.objects[1..]
+    }
+
+    /// the indexes of the loaded objects in the order of the global lookup scope of the dynamic loader, which is the
+    /// breadth first order of the dependencies of the root file. the program interpreter is placed where it is first
+    /// needed, or last if no object needs it.
+    pub fn global_scope(&self) -> Vec<usize> {
+        let mut scope = vec![0];
+        let mut index = 0;
+        while index < scope.len() {
+            for dependency in &self.objects[scope[index]].dependencies {
+                if let Some(object) = dependency.object {
+                    if !scope.contains(&object) {
+                        scope.push(object);
+                    }
+                }
+            }
+            index += 1;
+        }
+        for object in 0..self.objects.len() {
+            if !scope.contains(&object) {
+                scope.push(object);
+            }
+        }
+        scope
+    }
+
+    /// returns an iterator over the dependencies which were not found, along with the index of the object which
+    /// needs them.
+    pub fn missing(&self) -> impl Iterator<Item = (usize, &Dependency)> {
+        self.objects.iter().enumerate().flat_map(|(index, object)| {
+            object
+                .dependencies
+                .iter()
+                .filter(|dependency| dependency.object.is_none())
+                .map(move |dependency| (index, dependency))
+        })
+    }
+}
+
+/// the information about an object which is needed for resolving its dependencies.
+struct ObjectInfo {
+    file_info: ElfFileInfo,
+    needed: Vec<Vec<u8>>,
+    rpath: Option<Vec<u8>>,
+    runpath: Option<Vec<u8>>,
+    soname: Option<Vec<u8>>,
+    interpreter: Option<Vec<u8>>,
+    no_default_lib: bool,
+}
+impl ObjectInfo {
+    fn new(parser: &ElfParser) -> Result<Self> {
+        let mut info = Self {
+            file_info: parser.file_info(),
+            needed: Vec::new(),
+            rpath: None,
+            runpath: None,
+            soname: None,
+            interpreter: parser.interpreter()?.map(<[u8]>::to_vec),
+            no_default_lib: false,
+        };
+        if let Some(dynamic) = parser.dynamic_section()? {
+            for needed in dynamic.needed() {
+                info.needed.push(needed?.to_vec());
+            }
+            info.rpath = dynamic.rpath()?.map(<[u8]>::to_vec);
+            info.runpath = dynamic.runpath()?.map(<[u8]>::to_vec);
+            info.soname = dynamic.soname()?.map(<[u8]>::to_vec);
+            info.no_default_lib = dynamic
+                .value_of(DynamicTag::Flags1 as i64)?
+                .is_some_and(|flags_1| flags_1 & DF_1_NODEFLIB != 0);
+        }
+        Ok(info)
+    }
+}
+
+/// the state of an object while the dependency tree is built.
+struct ObjectState {
+    info: ObjectInfo,
+
+    /// the names which refer to this object, which are the names used to load it and its soname.
+    names: Vec<Vec<u8>>,
+    device_and_inode: Option<(u64, u64)>,
+}
+
+/// computes the shared library dependencies of elf files without executing anything, by emulating the search order
+/// of the gnu dynamic loader inside a sysroot.
+///
+/// each `DT_NEEDED` entry is looked up in the following order:
+/// 1. if the name contains a slash, it is used as a path.
+/// 2. the `DT_RPATH` of the object which needs the library and of its loaders, if the object has no `DT_RUNPATH`.
+/// 3. the configured library path, which is the equivalent of `LD_LIBRARY_PATH`.
+/// 4. the `DT_RUNPATH` of the object which needs the library.
+/// 5. the ld.so cache of the sysroot, unless the object is marked with `DF_1_NODEFLIB`.
+/// 6. the default library directories, unless the object is marked with `DF_1_NODEFLIB`.
+///
+/// a library which was already loaded, under the same name or soname, is reused. candidates which don't match the
+/// class, endianness and architechture of the root file are skipped.
+#[derive(Debug, Clone)]
+pub struct DependencyResolver {
+    sysroot: PathBuf,
+    library_path: Vec<PathBuf>,
+    default_directories: Option<Vec<PathBuf>>,
+    cache_entries: Vec<(Vec<u8>, PathBuf)>,
+    ld_so_cache_path: Option<PathBuf>,
+    lib: Option<String>,
+    platform: Option<String>,
+}
+impl Default for DependencyResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DependencyResolver {
+    /// creates a resolver which resolves dependencies in the root directory `/`, with an empty library path.
+    pub fn new() -> Self {
+        Self {
+            sysroot: PathBuf::from("/"),
+            library_path: Vec::new(),
+            default_directories: None,
+            cache_entries: Vec::new(),
+            ld_so_cache_path: Some(PathBuf::from(DEFAULT_LD_SO_CACHE_PATH)),
+            lib: None,
+            platform: None,
+        }
+    }
+
+    /// sets the directory under which all paths are looked up.
+    pub fn with_sysroot(mut self, sysroot: impl Into<PathBuf>) -> Self {
+        self.sysroot = sysroot.into();
+        self
+    }
+
+    /// sets the directories which are searched before `DT_RUNPATH`, like `LD_LIBRARY_PATH`.
+    pub fn with_library_path(
+        mut self,
+        library_path: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.library_path = library_path.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// replaces the default library directories. by default, they are `/lib64` and `/usr/lib64` for 64 bit files,
+    /// followed by `/lib` and `/usr/lib`.
+    pub fn with_default_directories(
+        mut self,
+        default_directories: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.default_directories = Some(default_directories.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// sets the path of the ld.so cache as seen inside the sysroot, or disables the use of the ld.so cache. a cache
+    /// which doesn't exist or can't be parsed is ignored, like the dynamic loader does.
+    pub fn with_ld_so_cache_path(mut self, ld_so_cache_path: Option<impl Into<PathBuf>>) -> Self {
+        self.ld_so_cache_path = ld_so_cache_path.map(Into::into);
+        self
+    }
+
+    /// sets additional ld.so cache entries, as pairs of library name and path, which are searched in order before the
+    /// ld.so cache.
+    pub fn with_cache_entries(
+        mut self,
+        cache_entries: impl IntoIterator<Item = (impl Into<Vec<u8>>, impl Into<PathBuf>)>,
+    ) -> Self {
+        self.cache_entries = cache_entries
+            .into_iter()
+            .map(|(name, path)| (name.into(), path.into()))
+            .collect();
+        self
+    }
+
+    /// sets the value of `$LIB` in search paths. by default, it is `lib64` for 64 bit files and `lib` for 32 bit
+    /// files.
+    pub fn with_lib(mut self, lib: impl Into<String>) -> Self {
+        self.lib = Some(lib.into());
+        self
+    }
+
+    /// sets the value of `$PLATFORM` in search paths. by default, it is derived from the architechture of the root
+    /// file, and search paths which use it are skipped for architechtures which have no known platform name.
+    pub fn with_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+
+    pub fn sysroot(&self) -> &Path {
+        &self.sysroot
+    }
+
+    /// translates a path as seen inside the sysroot to a path which can be accessed. relative paths are relative to
+    /// the current directory, and are not translated.
+    fn under_sysroot(&self, path: &Path) -> PathBuf {
+        match path.strip_prefix("/") {
+            Ok(relative_path) => self.sysroot.join(relative_path),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+
+    fn default_directories(&self, file_info: &ElfFileInfo) -> Vec<PathBuf> {
+        if let Some(default_directories) = &self.default_directories {
+            return default_directories.clone();
+        }
+        let mut directories = Vec::new();
+        if file_info.bit_length == ArchBitLength::Arch64Bit {
+            directories.extend(["/lib64", "/usr/lib64"].map(PathBuf::from));
+        }
+        directories.extend(["/lib", "/usr/lib"].map(PathBuf::from));
+        directories
+    }
+
+    /// substitutes the dynamic string tokens in a path. returns `None` if the path uses a token which has no value.
+    fn expand_tokens(
+        &self,
+        path: &[u8],
+        origin: &Path,
+        file_info: &ElfFileInfo,
+    ) -> Option<PathBuf> {
+        let lib = self.lib.as_deref().unwrap_or(match file_info.bit_length {
+            ArchBitLength::Arch32Bit => "lib",
+            ArchBitLength::Arch64Bit => "lib64",
+        });
+        let platform = self
+            .platform
+            .as_deref()
+            .or_else(|| default_platform(file_info));
+
+        let mut expanded = Vec::with_capacity(path.len());
+        let mut rest = path;
+        while let Some(dollar) = rest.iter().position(|&b| b == b'$') {
+            expanded.extend_from_slice(&rest[..dollar]);
+            rest = &rest[dollar + 1..];
+            let (token, token_len) = match rest.strip_prefix(b"{") {
+                Some(braced) => {
+                    let end = braced.iter().position(|&b| b == b'}')?;
+                    (&braced[..end], end + 2)
+                }
+                None => {
+                    let end = rest
+                        .iter()
+                        .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                        .unwrap_or(rest.len());
+                    (&rest[..end], end)
+                }
+            };
+            let value = match token {
+                b"ORIGIN" => origin.as_os_str().as_bytes(),
+                b"LIB" => lib.as_bytes(),
+                b"PLATFORM" => platform?.as_bytes(),
+                _ => return None,
+            };
+            expanded.extend_from_slice(value);
+            rest = &rest[token_len..];
+        }
+        expanded.extend_from_slice(rest);
+        Some(PathBuf::from(OsStr::from_bytes(&expanded)))
+    }
+
+    /// splits a colon separated search path and expands the tokens in each of its directories.
+    fn search_path_directories(
+        &self,
+        search_path: &[u8],
+        origin: &Path,
+        file_info: &ElfFileInfo,
+    ) -> Vec<PathBuf> {
+        search_path
+            .split(|&b| b == b':')
+            .filter(|directory| !directory.is_empty())
+            .filter_map(|directory| self.expand_tokens(directory, origin, file_info))
+            .collect()
+    }
+
+    /// reads the object at the given path, if it exists and is compatible with the root file.
+    fn try_load(
+        &self,
+        path: &Path,
+        expected: &ElfFileInfo,
+    ) -> Option<(ObjectInfo, PathBuf, (u64, u64))> {
+        let host_path = self.under_sysroot(path);
+        let metadata = std::fs::metadata(&host_path).ok()?;
+        if !metadata.is_file() {
+            return None;
+        }
+        let data = std::fs::read(&host_path).ok()?;
+        let parser = ElfParser::new(&data).ok()?;
+        if !is_compatible(&parser.file_info(), expected) {
+            return None;
+        }
+        let info = ObjectInfo::new(&parser).ok()?;
+        Some((info, host_path, (metadata.dev(), metadata.ino())))
+    }
+
+    /// computes the dependency tree of the elf file at the given path, which is the path of the file as seen inside
+    /// the sysroot.
+    pub fn resolve(
+        &self,
+        file_path: impl AsRef<Path>,
+        parser: &ElfParser,
+    ) -> Result<DependencyTree> {
+        let file_path = file_path.as_ref();
+        let root_info = ObjectInfo::new(parser)?;
+        let root_file_info = root_info.file_info;
+        let root_host_path = self.under_sysroot(file_path);
+        let mut objects = vec![LoadedObject {
+            path: file_path.to_path_buf(),
+            host_path: root_host_path.clone(),
+            soname: root_info.soname.clone(),
+            found_by: LibrarySearchLocation::Root,
+            loader: None,
+            dependencies: Vec::new(),
+        }];
+        let mut states = vec![ObjectState {
+            names: root_info.soname.iter().cloned().collect(),
+            device_and_inode: std::fs::metadata(&root_host_path)
+                .ok()
+                .map(|metadata| (metadata.dev(), metadata.ino())),
+            info: root_info,
+        }];
+
+        // the interpreter is loaded before any of the libraries, so that libraries which need it by its soname use it.
+        if let Some(interpreter) = states[0].info.interpreter.clone() {
+            let path = PathBuf::from(OsStr::from_bytes(&interpreter));
+            if let Some((info, host_path, device_and_inode)) = self.try_load(&path, &root_file_info)
+            {
+                objects.push(LoadedObject {
+                    path,
+                    host_path,
+                    soname: info.soname.clone(),
+                    found_by: LibrarySearchLocation::Interpreter,
+                    loader: Some(0),
+                    dependencies: Vec::new(),
+                });
+                states.push(ObjectState {
+                    names: [Some(interpreter), info.soname.clone()]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                    device_and_inode: Some(device_and_inode),
+                    info,
+                });
+            }
+        }
+
+        let ld_so_cache_data = self
+            .ld_so_cache_path
+            .as_ref()
+            .and_then(|path| std::fs::read(self.under_sysroot(path)).ok());
+        let ld_so_cache = ld_so_cache_data
+            .as_deref()
+            .and_then(|data| LdSoCache::new(data).ok());
+
+        // the dependencies are loaded breadth first, like the dynamic loader does.
+        let mut index = 0;
+        while index < objects.len() {
+            let needed = states[index].info.needed.clone();
+            for name in needed {
+                let object = self.resolve_needed(
+                    &name,
+                    index,
+                    &mut objects,
+                    &mut states,
+                    &root_file_info,
+                    ld_so_cache.as_ref(),
+                );
+                objects[index]
+                    .dependencies
+                    .push(Dependency { name, object });
+            }
+            index += 1;
+        }
+        Ok(DependencyTree { objects })
+    }
+
+    /// finds the object which satisfies the given dependency of the given object, loading it if needed.
+    fn resolve_needed(
+        &self,
+        name: &[u8],
+        loader: usize,
+        objects: &mut Vec<LoadedObject>,
+        states: &mut Vec<ObjectState>,
+        root_file_info: &ElfFileInfo,
+        ld_so_cache: Option<&LdSoCache>,
+    ) -> Option<usize> {
+        if let Some(existing) = states
+            .iter()
+            .position(|state| state.names.iter().any(|n| n == name))
+        {
+            return Some(existing);
+        }
+
+        let origin_of = |index: usize| -> PathBuf {
+            objects[index]
+                .path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        };
+        let name_path = Path::new(OsStr::from_bytes(name));
+        let mut candidates: Vec<(PathBuf, LibrarySearchLocation)> = Vec::new();
+        if name.contains(&b'/') {
+            if let Some(path) = self.expand_tokens(name, &origin_of(loader), root_file_info) {
+                candidates.push((path, LibrarySearchLocation::Path));
+            }
+        } else {
+            let loader_info = &states[loader].info;
+            if loader_info.runpath.is_none() {
+                // the rpaths of the loader chain are searched, starting from the object which needs the library.
+                let mut current = Some(loader);
+                while let Some(index) = current {
+                    let info = &states[index].info;
+                    if info.runpath.is_none() {
+                        if let Some(rpath) = &info.rpath {
+                            for directory in self.search_path_directories(
+                                rpath,
+                                &origin_of(index),
+                                &info.file_info,
+                            ) {
+                                candidates.push((
+                                    directory.join(name_path),
+                                    LibrarySearchLocation::Rpath,
+                                ));
+                            }
+                        }
+                    }
+                    current = objects[index].loader;
+                }
+            }
+            for directory in &self.library_path {
+                let directory = self.expand_tokens(
+                    directory.as_os_str().as_bytes(),
+                    &origin_of(0),
+                    root_file_info,
+                );
+                if let Some(directory) = directory {
+                    candidates.push((
+                        directory.join(name_path),
+                        LibrarySearchLocation::LibraryPath,
+                    ));
+                }
+            }
+            if let Some(runpath) = &loader_info.runpath {
+                for directory in self.search_path_directories(
+                    runpath,
+                    &origin_of(loader),
+                    &loader_info.file_info,
+                ) {
+                    candidates.push((directory.join(name_path), LibrarySearchLocation::Runpath));
+                }
+            }
+            if !loader_info.no_default_lib {
+                for (cache_name, path) in &self.cache_entries {
+                    if cache_name == name {
+                        candidates.push((path.clone(), LibrarySearchLocation::Cache));
+                    }
+                }
+                let cache_entry = ld_so_cache
+                    .and_then(|cache| cache.find(name, root_file_info).ok())
+                    .flatten();
+                if let Some(cache_entry) = cache_entry {
+                    candidates.push((
+                        PathBuf::from(OsStr::from_bytes(cache_entry.path)),
+                        LibrarySearchLocation::Cache,
+                    ));
+                }
+                for directory in self.default_directories(root_file_info) {
+                    candidates.push((
+                        directory.join(name_path),
+                        LibrarySearchLocation::DefaultDirectory,
+                    ));
+                }
+            }
+        }
+
+        for (path, found_by) in candidates {
+            let Some((info, host_path, device_and_inode)) = self.try_load(&path, root_file_info)
+            else {
+                continue;
+            };
+            // the same file may be found under a different name, in which case it is not loaded again.
+            if let Some(existing) = states
+                .iter()
+                .position(|state| state.device_and_inode == Some(device_and_inode))
+            {
+                states[existing].names.push(name.to_vec());
+                return Some(existing);
+            }
+            objects.push(LoadedObject {
+                path,
+                host_path,
+                soname: info.soname.clone(),
+                found_by,
+                loader: Some(loader),
+                dependencies: Vec::new(),
+            });
+            states.push(ObjectState {
+                names: [Some(name.to_vec()), info.soname.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                device_and_inode: Some(device_and_inode),
+                info,
+            });
+            return Some(objects.len() - 1);
+        }
+        None
+    }
+}
+
+/// can an object with the given file info be loaded into a process whose root file has the expected file info.
+fn is_compatible(file_info: &ElfFileInfo, expected: &ElfFileInfo) -> bool {
+    file_info.bit_length == expected.bit_length
+        && file_info.endianness == expected.endianness
+        && file_info.arch == expected.arch
+}
+
+/// the default value of `$PLATFORM` for the architechture of the given file.
+fn default_platform(file_info: &ElfFileInfo) -> Option<&'static str> {
+    Some(match file_info.arch.known()? {
+        Architechture::X8664 => "x86_64",
+        Architechture::I386 => "i686",
+        Architechture::AArch64 => "aarch64",
+        Architechture::Ppc64 => "power8",
+        Architechture::S390 => "z900",
+        _ => return None,
+    })
+}
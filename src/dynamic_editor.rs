@@ -0,0 +1,401 @@
+use alloc::vec::Vec;
+
+use binary_serde::{BinarySerde, Endianness};
+
+use crate::{
+    push_serialized, ArchBitLength, DynamicEntry32, DynamicEntry64, DynamicTag, ElfParser,
+    ElfRewriter, Error, ProgramHeaderFlags, ProgramHeaderType, Result, RewriterSectionId,
+    SectionHeaderType, StringTableBuilder, VersionNeed,
+};
+
+/// edits the dynamic linking information of an executable or a shared library, like `patchelf` does.
+///
+/// strings which are added to the dynamic string table are appended to it, or share the storage of existing strings
+/// which end with them, so the offsets of the existing strings stay valid. if the dynamic string table, the dynamic
+/// section or the program interpreter path no longer fit in their original location, they are moved to a new loadable
+/// segment at the end of the file, and the `DT_STRTAB` entry and the `PT_DYNAMIC` and `PT_INTERP` program headers are
+/// updated accordingly.
+///
+/// the file must have section headers, which are used to find the parts of the file which need to be moved.
+#[derive(Debug, Clone)]
+pub struct DynamicEditor<'a> {
+    rewriter: ElfRewriter<'a>,
+    bit_length: ArchBitLength,
+    endianness: Endianness,
+    dynamic_section: RewriterSectionId,
+    string_table_section: RewriterSectionId,
+    interpreter_section: Option<RewriterSectionId>,
+
+    /// the entries of the dynamic section, not including the terminating `DT_NULL` entry.
+    entries: Vec<(i64, u64)>,
+    strings: StringTableBuilder,
+    interpreter: Option<Vec<u8>>,
+    interpreter_changed: bool,
+
+    /// the names which were replaced using [`DynamicEditor::replace_needed`], which must also be replaced in the
+    /// version requirements.
+    replaced_needed: Vec<(u32, u32)>,
+}
+impl<'a> DynamicEditor<'a> {
+    pub fn new(parser: &ElfParser<'a>) -> Result<Self> {
+        let dynamic = parser.dynamic_section()?.ok_or(Error::NoDynamicSection)?;
+        let entries = dynamic
+            .live_entries()
+            .map(|entry| {
+                let entry = entry?;
+                Ok((entry.tag(), entry.value()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let rewriter = ElfRewriter::new(parser)?;
+        let dynamic_section = rewriter
+            .section_ids()
+            .find(|&id| *rewriter.section(id).header.ty() == SectionHeaderType::Dynamic)
+            .ok_or(Error::NoDynamicSection)?;
+        let string_table_section = rewriter
+            .section(dynamic_section)
+            .link()
+            .filter(|&id| *rewriter.section(id).header.ty() == SectionHeaderType::Strtab)
+            .ok_or(Error::LinkedSectionOfDynamicSectionIsNotAStringTable)?;
+
+        let interpreter = parser.interpreter()?;
+        let mut interpreter_section = None;
+        for program_header in parser.program_headers()? {
+            let program_header = program_header?;
+            if *program_header.ty() != ProgramHeaderType::Interp {
+                continue;
+            }
+            interpreter_section = rewriter.section_ids().find(|&id| {
+                let header = &rewriter.section(id).header;
+                header.offset() == program_header.offset()
+                    && header.size() == program_header.size_in_file()
+            });
+        }
+
+        Ok(Self {
+            strings: StringTableBuilder::from_bytes(
+                rewriter.section(string_table_section).content().to_vec(),
+            )?,
+            rewriter,
+            bit_length: parser.file_info().bit_length,
+            endianness: parser.file_info().endianness,
+            dynamic_section,
+            string_table_section,
+            interpreter_section,
+            entries,
+            interpreter: interpreter.map(|interpreter| interpreter.to_vec()),
+            interpreter_changed: false,
+            replaced_needed: Vec::new(),
+        })
+    }
+
+    /// the entries of the dynamic section, not including the terminating `DT_NULL` entry, as `(tag, value)` pairs.
+    pub fn entries(&self) -> &[(i64, u64)] {
+        &self.entries
+    }
+
+    fn string_at(&self, offset: u64) -> &[u8] {
+        let tail = self
+            .strings
+            .as_bytes()
+            .get(offset as usize..)
+            .unwrap_or(&[]);
+        let len = tail.iter().position(|&b| b == 0).unwrap_or(tail.len());
+        &tail[..len]
+    }
+
+    /// returns the offset of the given string in the dynamic string table, adding it if it is not already present.
+    /// fails if the string contains a null byte.
+    fn add_string(&mut self, string: &[u8]) -> Result<u64> {
+        Ok(self.strings.add(string)?.into())
+    }
+
+    fn string_of(&self, tag: DynamicTag) -> Option<&[u8]> {
+        let tag = tag as i64;
+        self.entries
+            .iter()
+            .find(|(entry_tag, _)| *entry_tag == tag)
+            .map(|&(_, value)| self.string_at(value))
+    }
+
+    /// sets the value of the entry with the given tag which references a string, adding an entry if there is none, or
+    /// removes the entry if `None` is given.
+    fn set_string_of(&mut self, tag: DynamicTag, string: Option<&[u8]>) -> Result<()> {
+        let tag = tag as i64;
+        let Some(string) = string else {
+            self.entries.retain(|(entry_tag, _)| *entry_tag != tag);
+            return Ok(());
+        };
+        let offset = self.add_string(string)?;
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_tag, _)| *entry_tag == tag)
+        {
+            Some(entry) => entry.1 = offset,
+            None => {
+                let index = self.needed_insertion_index();
+                self.entries.insert(index, (tag, offset));
+            }
+        }
+        Ok(())
+    }
+
+    /// the index right after the last `DT_NEEDED` entry.
+    fn needed_insertion_index(&self) -> usize {
+        self.entries
+            .iter()
+            .rposition(|(tag, _)| *tag == DynamicTag::Needed as i64)
+            .map_or(0, |index| index + 1)
+    }
+
+    /// the path of the program interpreter.
+    pub fn interpreter(&self) -> Option<&[u8]> {
+        self.interpreter.as_deref()
+    }
+
+    /// sets the path of the program interpreter. fails if the file has no `PT_INTERP` segment.
+    pub fn set_interpreter(&mut self, interpreter: impl Into<Vec<u8>>) -> Result<()> {
+        if self.interpreter_section.is_none() {
+            return Err(Error::NoInterpreter);
+        }
+        self.interpreter = Some(interpreter.into());
+        self.interpreter_changed = true;
+        Ok(())
+    }
+
+    /// the names of the needed libraries, in order.
+    pub fn needed(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.entries
+            .iter()
+            .filter(|(tag, _)| *tag == DynamicTag::Needed as i64)
+            .map(|&(_, value)| self.string_at(value))
+    }
+
+    /// adds a needed library after the existing needed libraries. does nothing if the library is already needed.
+    pub fn add_needed(&mut self, name: impl AsRef<[u8]>) -> Result<()> {
+        let name = name.as_ref();
+        if self.needed().any(|needed| needed == name) {
+            return Ok(());
+        }
+        let offset = self.add_string(name)?;
+        let index = self.needed_insertion_index();
+        self.entries
+            .insert(index, (DynamicTag::Needed as i64, offset));
+        Ok(())
+    }
+
+    /// removes a needed library. returns whether the library was needed.
+    pub fn remove_needed(&mut self, name: impl AsRef<[u8]>) -> bool {
+        let name = name.as_ref();
+        let needed_tag = DynamicTag::Needed as i64;
+        let old_len = self.entries.len();
+        let strings = self.strings.as_bytes();
+        self.entries.retain(|&(tag, value)| {
+            let tail = strings.get(value as usize..).unwrap_or(&[]);
+            !(tag == needed_tag && tail.starts_with(name) && tail.get(name.len()) == Some(&0))
+        });
+        self.entries.len() != old_len
+    }
+
+    /// replaces a needed library with another one, also replacing it in the version requirements of the file. returns
+    /// whether the library was needed.
+    pub fn replace_needed(
+        &mut self,
+        old_name: impl AsRef<[u8]>,
+        new_name: impl AsRef<[u8]>,
+    ) -> Result<bool> {
+        let old_name = old_name.as_ref();
+        let needed_indexes: Vec<usize> = (0..self.entries.len())
+            .filter(|&index| {
+                let (tag, value) = self.entries[index];
+                tag == DynamicTag::Needed as i64 && self.string_at(value) == old_name
+            })
+            .collect();
+        if needed_indexes.is_empty() {
+            return Ok(false);
+        }
+        let new_offset = self.add_string(new_name.as_ref())?;
+        for index in needed_indexes {
+            let old_offset = self.entries[index].1;
+            self.replaced_needed
+                .push((old_offset as u32, new_offset as u32));
+            self.entries[index].1 = new_offset;
+        }
+        Ok(true)
+    }
+
+    pub fn soname(&self) -> Option<&[u8]> {
+        self.string_of(DynamicTag::Soname)
+    }
+
+    /// sets the soname of a shared library, adding a `DT_SONAME` entry if there is none.
+    pub fn set_soname(&mut self, soname: impl AsRef<[u8]>) -> Result<()> {
+        self.set_string_of(DynamicTag::Soname, Some(soname.as_ref()))
+    }
+
+    pub fn rpath(&self) -> Option<&[u8]> {
+        self.string_of(DynamicTag::Rpath)
+    }
+
+    /// sets the `DT_RPATH` entry, or removes it if `None` is given.
+    pub fn set_rpath(&mut self, rpath: Option<&[u8]>) -> Result<()> {
+        self.set_string_of(DynamicTag::Rpath, rpath)
+    }
+
+    pub fn runpath(&self) -> Option<&[u8]> {
+        self.string_of(DynamicTag::Runpath)
+    }
+
+    /// sets the `DT_RUNPATH` entry, or removes it if `None` is given.
+    pub fn set_runpath(&mut self, runpath: Option<&[u8]>) -> Result<()> {
+        self.set_string_of(DynamicTag::Runpath, runpath)
+    }
+
+    fn serialize_entries(&self, entries_amount: usize) -> Vec<u8> {
+        let mut content = Vec::new();
+        let padding = core::iter::repeat((DynamicTag::Null as i64, 0));
+        for (tag, value) in self
+            .entries
+            .iter()
+            .copied()
+            .chain(padding)
+            .take(entries_amount)
+        {
+            match self.bit_length {
+                ArchBitLength::Arch32Bit => push_serialized(
+                    &mut content,
+                    &DynamicEntry32 {
+                        tag: tag as i32,
+                        value: value as u32,
+                    },
+                    self.endianness,
+                ),
+                ArchBitLength::Arch64Bit => push_serialized(
+                    &mut content,
+                    &DynamicEntry64 { tag, value },
+                    self.endianness,
+                ),
+            }
+        }
+        content
+    }
+
+    fn entry_size(&self) -> usize {
+        match self.bit_length {
+            ArchBitLength::Arch32Bit => DynamicEntry32::SERIALIZED_SIZE,
+            ArchBitLength::Arch64Bit => DynamicEntry64::SERIALIZED_SIZE,
+        }
+    }
+
+    /// replaces the file names of the version requirements of replaced needed libraries.
+    fn fix_version_requirements(&mut self) {
+        if self.replaced_needed.is_empty() {
+            return;
+        }
+        let Some(id) = self
+            .rewriter
+            .section_ids()
+            .find(|&id| *self.rewriter.section(id).header.ty() == SectionHeaderType::GnuVerneed)
+        else {
+            return;
+        };
+        let mut content = self.rewriter.section(id).content().to_vec();
+        let mut offset = 0usize;
+        while let Some(record) = content.get_mut(offset..offset + VersionNeed::SERIALIZED_SIZE) {
+            let Ok(mut version_need) = VersionNeed::binary_deserialize(record, self.endianness)
+            else {
+                break;
+            };
+            if let Some(&(_, new_offset)) = self
+                .replaced_needed
+                .iter()
+                .find(|(old_offset, _)| *old_offset == version_need.file_name_offset)
+            {
+                version_need.file_name_offset = new_offset;
+                version_need.binary_serialize(record, self.endianness);
+            }
+            if version_need.next_offset == 0 {
+                break;
+            }
+            offset += version_need.next_offset as usize;
+        }
+        self.rewriter.set_section_content(id, content);
+    }
+
+    /// writes the modified elf file.
+    pub fn write(mut self) -> Result<Vec<u8>> {
+        self.fix_version_requirements();
+
+        let mut moved_sections = Vec::new();
+
+        if let (true, Some(id), Some(interpreter)) = (
+            self.interpreter_changed,
+            self.interpreter_section,
+            &self.interpreter,
+        ) {
+            let original_size = self.rewriter.section(id).content().len();
+            let mut content = interpreter.clone();
+            content.push(0);
+            if content.len() <= original_size {
+                content.resize(original_size, 0);
+            } else {
+                moved_sections.push(id);
+            }
+            self.rewriter.set_section_content(id, content);
+        }
+
+        let original_strings_len = self
+            .rewriter
+            .section(self.string_table_section)
+            .content()
+            .len();
+        if self.strings.len() > original_strings_len {
+            moved_sections.push(self.string_table_section);
+        }
+        let strings_size = self.strings.len() as u64;
+        self.rewriter.set_section_content(
+            self.string_table_section,
+            core::mem::take(&mut self.strings).into_bytes(),
+        );
+        self.set_entry_value(DynamicTag::Strsz, strings_size);
+
+        let original_entries_amount =
+            self.rewriter.section(self.dynamic_section).content().len() / self.entry_size();
+        let entries_amount = if self.entries.len() < original_entries_amount {
+            original_entries_amount
+        } else {
+            moved_sections.push(self.dynamic_section);
+            self.entries.len() + 1
+        };
+        self.rewriter
+            .set_section_content(self.dynamic_section, self.serialize_entries(entries_amount));
+
+        if !moved_sections.is_empty() {
+            // the dynamic section must be writable, since the dynamic linker writes the `DT_DEBUG` entry.
+            self.rewriter.add_load_segment(
+                &moved_sections,
+                ProgramHeaderFlags::R | ProgramHeaderFlags::W,
+            )?;
+            let strings_address = self
+                .rewriter
+                .section(self.string_table_section)
+                .header
+                .address();
+            self.set_entry_value(DynamicTag::Strtab, strings_address);
+            self.rewriter
+                .set_section_content(self.dynamic_section, self.serialize_entries(entries_amount));
+        }
+
+        self.rewriter.write()
+    }
+
+    fn set_entry_value(&mut self, tag: DynamicTag, value: u64) {
+        let tag = tag as i64;
+        for entry in &mut self.entries {
+            if entry.0 == tag {
+                entry.1 = value;
+            }
+        }
+    }
+}
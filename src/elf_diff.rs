@@ -0,0 +1,547 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    DynamicTag, ElfParser, Result, SectionData, SectionHeaderRef, SectionHeaderType, SymbolRef,
+    SymbolTableKind,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// the names of the sections which hold the build id of a file.
+const BUILD_ID_SECTION_NAMES: &[&[u8]] = &[b".note.gnu.build-id"];
+
+/// the names of the sections which link a file to its separate debug information.
+const DEBUG_LINK_SECTION_NAMES: &[&[u8]] = &[b".gnu_debuglink", b".gnu_debugaltlink"];
+
+/// a field whose value differs between the two files. values are compared in their raw numeric form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldDifference {
+    pub field: &'static str,
+    pub a: u64,
+    pub b: u64,
+}
+
+/// how an item which can be matched between the two files differs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ItemDiff {
+    OnlyInA,
+    OnlyInB,
+
+    /// the item exists in both files, and the given fields differ.
+    Changed(Vec<FieldDifference>),
+}
+
+/// a difference in the content of a section which exists in both files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentDifference {
+    /// the fnv-1a hash of the content of the section in the first file.
+    pub a_hash: u64,
+
+    /// the fnv-1a hash of the content of the section in the second file.
+    pub b_hash: u64,
+
+    /// the offset, relative to the start of the section, of the first byte which differs. if one of the contents is
+    /// a prefix of the other, this is the length of the shorter one.
+    pub first_differing_offset: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProgramHeaderDiff {
+    pub index: usize,
+    pub diff: ItemDiff,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SectionDiff {
+    pub name: Vec<u8>,
+
+    /// the index of the section among the sections with the same name, for files which have several sections with
+    /// the same name.
+    pub occurrence: usize,
+
+    /// the differences in the section header. `Changed` with no fields if only the content differs.
+    pub header: ItemDiff,
+
+    /// the difference in the content of the section, if it exists in both files and its content differs.
+    pub content: Option<ContentDifference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolDiff {
+    pub table: SymbolTableKind,
+    pub name: Vec<u8>,
+
+    /// the index of the symbol among the symbols with the same name in the same table, for example local symbols of
+    /// different compilation units.
+    pub occurrence: usize,
+    pub diff: ItemDiff,
+}
+
+/// the value of a dynamic entry, with string values resolved using the dynamic string table, since the offsets of
+/// equal strings may differ between the files.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DynamicValue {
+    Integer(u64),
+    String(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicEntryDiff {
+    pub tag: i64,
+
+    /// the index of the entry among the entries with the same tag.
+    pub occurrence: usize,
+    pub a: Option<DynamicValue>,
+    pub b: Option<DynamicValue>,
+}
+
+/// the structural differences between two elf files.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ElfDiff {
+    pub header: Vec<FieldDifference>,
+    pub program_headers: Vec<ProgramHeaderDiff>,
+    pub sections: Vec<SectionDiff>,
+    pub symbols: Vec<SymbolDiff>,
+    pub dynamic: Vec<DynamicEntryDiff>,
+}
+impl ElfDiff {
+    /// are the files structurally identical, ignoring the differences which were configured to be ignored.
+    pub fn is_empty(&self) -> bool {
+        self.header.is_empty()
+            && self.program_headers.is_empty()
+            && self.sections.is_empty()
+            && self.symbols.is_empty()
+            && self.dynamic.is_empty()
+    }
+}
+
+/// options which control which differences are reported by `elf_diff_with_options`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ElfDiffOptions {
+    ignore_build_id: bool,
+    ignore_debug_link: bool,
+    ignored_sections: Vec<Vec<u8>>,
+}
+impl ElfDiffOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ignores the content of the build id note section.
+    pub fn ignore_build_id(mut self) -> Self {
+        self.ignore_build_id = true;
+        self
+    }
+
+    /// ignores the content of the `.gnu_debuglink` and `.gnu_debugaltlink` sections, whose checksums depend on the
+    /// separate debug files.
+    pub fn ignore_debug_link(mut self) -> Self {
+        self.ignore_debug_link = true;
+        self
+    }
+
+    /// ignores the section with the given name completely.
+    pub fn ignore_section(mut self, name: impl Into<Vec<u8>>) -> Self {
+        self.ignored_sections.push(name.into());
+        self
+    }
+
+    fn is_section_ignored(&self, name: &[u8]) -> bool {
+        self.ignored_sections.iter().any(|ignored| ignored == name)
+    }
+
+    fn is_section_content_ignored(&self, name: &[u8]) -> bool {
+        (self.ignore_build_id && BUILD_ID_SECTION_NAMES.contains(&name))
+            || (self.ignore_debug_link && DEBUG_LINK_SECTION_NAMES.contains(&name))
+    }
+}
+
+/// compares the structure of two elf files.
+pub fn elf_diff(a: &ElfParser, b: &ElfParser) -> Result<ElfDiff> {
+    elf_diff_with_options(a, b, &ElfDiffOptions::default())
+}
+
+/// compares the structure of two elf files, ignoring the differences which the given options ignore.
+pub fn elf_diff_with_options(
+    a: &ElfParser,
+    b: &ElfParser,
+    options: &ElfDiffOptions,
+) -> Result<ElfDiff> {
+    Ok(ElfDiff {
+        header: diff_headers(a, b)?,
+        program_headers: diff_program_headers(a, b)?,
+        sections: diff_sections(a, b, options)?,
+        symbols: diff_symbols(a, b)?,
+        dynamic: diff_dynamic(a, b)?,
+    })
+}
+
+/// collects the fields whose values differ.
+fn field_differences(fields: &[(&'static str, u64, u64)]) -> Vec<FieldDifference> {
+    fields
+        .iter()
+        .filter(|(_, a, b)| a != b)
+        .map(|&(field, a, b)| FieldDifference { field, a, b })
+        .collect()
+}
+
+fn diff_headers(a: &ElfParser, b: &ElfParser) -> Result<Vec<FieldDifference>> {
+    let (a_info, b_info) = (a.file_info(), b.file_info());
+    let (a, b) = (a.header()?, b.header()?);
+    Ok(field_differences(&[
+        (
+            "bit_length",
+            a_info.bit_length as u64,
+            b_info.bit_length as u64,
+        ),
+        (
+            "endianness",
+            a.ident().header.endianness as u64,
+            b.ident().header.endianness as u64,
+        ),
+        (
+            "os_abi",
+            a_info.os_abi.raw() as u64,
+            b_info.os_abi.raw() as u64,
+        ),
+        (
+            "abi_version",
+            a.ident().header.abi_version.raw() as u64,
+            b.ident().header.abi_version.raw() as u64,
+        ),
+        ("type", a.ty().raw() as u64, b.ty().raw() as u64),
+        ("arch", a.arch().raw() as u64, b.arch().raw() as u64),
+        (
+            "version",
+            a.version().raw() as u64,
+            b.version().raw() as u64,
+        ),
+        ("entry", a.entry(), b.entry()),
+        (
+            "program_headers_off",
+            a.program_headers_off(),
+            b.program_headers_off(),
+        ),
+        (
+            "section_headers_off",
+            a.section_headers_off(),
+            b.section_headers_off(),
+        ),
+        ("flags", a.flags().bits() as u64, b.flags().bits() as u64),
+        (
+            "header_size",
+            a.header_size() as u64,
+            b.header_size() as u64,
+        ),
+        (
+            "program_header_entry_size",
+            a.program_header_entry_size() as u64,
+            b.program_header_entry_size() as u64,
+        ),
+        (
+            "program_headers_amount",
+            a.program_headers_amount() as u64,
+            b.program_headers_amount() as u64,
+        ),
+        (
+            "section_header_entry_size",
+            a.section_header_entry_size() as u64,
+            b.section_header_entry_size() as u64,
+        ),
+        (
+            "section_headers_amount",
+            a.section_headers_amount() as u64,
+            b.section_headers_amount() as u64,
+        ),
+        (
+            "section_names_section_index",
+            a.section_names_section_index() as u64,
+            b.section_names_section_index() as u64,
+        ),
+    ]))
+}
+
+/// program headers are matched by their index, since their order is meaningful.
+fn diff_program_headers(a: &ElfParser, b: &ElfParser) -> Result<Vec<ProgramHeaderDiff>> {
+    let a_headers = a.program_headers()?;
+    let b_headers = b.program_headers()?;
+    let mut diffs = Vec::new();
+    for index in 0..a_headers.len().max(b_headers.len()) {
+        let diff = match (index < a_headers.len(), index < b_headers.len()) {
+            (true, false) => ItemDiff::OnlyInA,
+            (false, true) => ItemDiff::OnlyInB,
+            _ => {
+                let (a, b) = (a_headers.get(index)?, b_headers.get(index)?);
+                let fields = field_differences(&[
+                    ("type", a.ty().raw() as u64, b.ty().raw() as u64),
+                    ("flags", a.flags().bits() as u64, b.flags().bits() as u64),
+                    ("offset", a.offset(), b.offset()),
+                    ("virt_addr", a.virt_addr(), b.virt_addr()),
+                    ("phys_addr", a.phys_addr(), b.phys_addr()),
+                    ("size_in_file", a.size_in_file(), b.size_in_file()),
+                    ("size_in_memory", a.size_in_memory(), b.size_in_memory()),
+                    ("alignment", a.alignment(), b.alignment()),
+                ]);
+                if fields.is_empty() {
+                    continue;
+                }
+                ItemDiff::Changed(fields)
+            }
+        };
+        diffs.push(ProgramHeaderDiff { index, diff });
+    }
+    Ok(diffs)
+}
+
+/// items keyed by a key and by their index among the items with the same key.
+type ItemsByOccurrence<K, T> = BTreeMap<(K, usize), T>;
+
+/// groups items by a key, numbering the items with the same key by their order of occurrence.
+fn group_by_occurrence<K: Ord + Clone, T>(
+    items: impl IntoIterator<Item = (K, T)>,
+) -> ItemsByOccurrence<K, T> {
+    let mut counts: BTreeMap<K, usize> = BTreeMap::new();
+    let mut grouped = BTreeMap::new();
+    for (key, item) in items {
+        let count = counts.entry(key.clone()).or_default();
+        grouped.insert((key, *count), item);
+        *count += 1;
+    }
+    grouped
+}
+
+/// matches the items of the two files by key, and returns the keys along with the items of each file.
+fn match_items<K: Ord + Clone, T>(
+    a: BTreeMap<K, T>,
+    mut b: BTreeMap<K, T>,
+) -> Vec<(K, Option<T>, Option<T>)> {
+    let mut matched = Vec::new();
+    for (key, a_item) in a {
+        let b_item = b.remove(&key);
+        matched.push((key, Some(a_item), b_item));
+    }
+    matched.extend(b.into_iter().map(|(key, b_item)| (key, None, Some(b_item))));
+    matched.sort_by(|(a_key, _, _), (b_key, _, _)| a_key.cmp(b_key));
+    matched
+}
+
+fn sections_by_name<'a>(
+    parser: &ElfParser<'a>,
+    options: &ElfDiffOptions,
+) -> Result<ItemsByOccurrence<Vec<u8>, SectionHeaderRef<'a>>> {
+    let mut sections = Vec::new();
+    for section in parser.section_headers()? {
+        let section = section?;
+        let name = section.name_bytes()?;
+        if !options.is_section_ignored(name) {
+            sections.push((name.to_vec(), section));
+        }
+    }
+    Ok(group_by_occurrence(sections))
+}
+
+/// sections are matched by name, since adding a section changes the indexes of all of the following sections.
+fn diff_sections(
+    a: &ElfParser,
+    b: &ElfParser,
+    options: &ElfDiffOptions,
+) -> Result<Vec<SectionDiff>> {
+    let mut diffs = Vec::new();
+    for ((name, occurrence), a_section, b_section) in
+        match_items(sections_by_name(a, options)?, sections_by_name(b, options)?)
+    {
+        let (a_section, b_section) = match (a_section, b_section) {
+            (Some(a_section), Some(b_section)) => (a_section, b_section),
+            (a_section, _) => {
+                diffs.push(SectionDiff {
+                    name,
+                    occurrence,
+                    header: if a_section.is_some() {
+                        ItemDiff::OnlyInA
+                    } else {
+                        ItemDiff::OnlyInB
+                    },
+                    content: None,
+                });
+                continue;
+            }
+        };
+        let fields = field_differences(&[
+            (
+                "type",
+                a_section.ty().raw() as u64,
+                b_section.ty().raw() as u64,
+            ),
+            ("flags", a_section.flags().bits(), b_section.flags().bits()),
+            ("address", a_section.address(), b_section.address()),
+            ("offset", a_section.offset(), b_section.offset()),
+            ("size", a_section.size(), b_section.size()),
+            ("link", a_section.link() as u64, b_section.link() as u64),
+            ("info", a_section.info() as u64, b_section.info() as u64),
+            (
+                "address_alignment",
+                a_section.address_alignemnt(),
+                b_section.address_alignemnt(),
+            ),
+            ("entry_size", a_section.entry_size(), b_section.entry_size()),
+        ]);
+        let content = if options.is_section_content_ignored(&name) {
+            None
+        } else {
+            diff_content(&a_section, &b_section)?
+        };
+        if fields.is_empty() && content.is_none() {
+            continue;
+        }
+        diffs.push(SectionDiff {
+            name,
+            occurrence,
+            header: ItemDiff::Changed(fields),
+            content,
+        });
+    }
+    Ok(diffs)
+}
+
+fn diff_content(a: &SectionHeaderRef, b: &SectionHeaderRef) -> Result<Option<ContentDifference>> {
+    let (a, b) = (content_of(a)?, content_of(b)?);
+    if a == b {
+        return Ok(None);
+    }
+    let first_differing_offset = a
+        .iter()
+        .zip(b)
+        .position(|(a, b)| a != b)
+        .unwrap_or(a.len().min(b.len()));
+    Ok(Some(ContentDifference {
+        a_hash: fnv1a_hash(a),
+        b_hash: fnv1a_hash(b),
+        first_differing_offset: first_differing_offset as u64,
+    }))
+}
+
+/// the content of the section in the file. nobits sections occupy no space in the file, so they have no content.
+fn content_of<'a>(section: &SectionHeaderRef<'a>) -> Result<&'a [u8]> {
+    if *section.ty() == SectionHeaderType::Nobits {
+        Ok(&[])
+    } else {
+        section.content()
+    }
+}
+
+fn fnv1a_hash(data: &[u8]) -> u64 {
+    data.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+fn symbols_by_name<'a>(
+    parser: &ElfParser<'a>,
+) -> Result<ItemsByOccurrence<(SymbolTableKind, Vec<u8>), SymbolRef<'a>>> {
+    let mut symbols = Vec::new();
+    for section in parser.section_headers()? {
+        let (table, entries) = match section?.data()? {
+            SectionData::SymbolTable(entries) => (SymbolTableKind::Static, entries),
+            SectionData::DynamicSymbolTable(entries) => (SymbolTableKind::Dynamic, entries),
+            _ => continue,
+        };
+        // the first symbol is the null symbol.
+        for symbol in entries.iter().skip(1) {
+            let symbol = symbol?;
+            symbols.push(((table, symbol.name_bytes()?.to_vec()), symbol));
+        }
+    }
+    Ok(group_by_occurrence(symbols))
+}
+
+/// symbols are matched by their table and name.
+fn diff_symbols(a: &ElfParser, b: &ElfParser) -> Result<Vec<SymbolDiff>> {
+    let mut diffs = Vec::new();
+    for (((table, name), occurrence), a_symbol, b_symbol) in
+        match_items(symbols_by_name(a)?, symbols_by_name(b)?)
+    {
+        let diff = match (a_symbol, b_symbol) {
+            (Some(a), Some(b)) => {
+                let (a_info, b_info) = (a.info(), b.info());
+                let fields = field_differences(&[
+                    ("value", a.value(), b.value()),
+                    ("size", a.size(), b.size()),
+                    ("type", a_info.ty.raw() as u64, b_info.ty.raw() as u64),
+                    (
+                        "binding",
+                        a_info.binding.raw() as u64,
+                        b_info.binding.raw() as u64,
+                    ),
+                    (
+                        "visibility",
+                        a.other_info().visibility as u64,
+                        b.other_info().visibility as u64,
+                    ),
+                    (
+                        "section_index",
+                        a.related_section_index() as u64,
+                        b.related_section_index() as u64,
+                    ),
+                ]);
+                if fields.is_empty() {
+                    continue;
+                }
+                ItemDiff::Changed(fields)
+            }
+            (Some(_), None) => ItemDiff::OnlyInA,
+            _ => ItemDiff::OnlyInB,
+        };
+        diffs.push(SymbolDiff {
+            table,
+            name,
+            occurrence,
+            diff,
+        });
+    }
+    Ok(diffs)
+}
+
+/// dynamic entries are matched by their tag.
+fn diff_dynamic(a: &ElfParser, b: &ElfParser) -> Result<Vec<DynamicEntryDiff>> {
+    const STRING_TAGS: &[i64] = &[
+        DynamicTag::Needed as i64,
+        DynamicTag::Soname as i64,
+        DynamicTag::Rpath as i64,
+        DynamicTag::Runpath as i64,
+    ];
+    let entries_by_tag = |parser: &ElfParser<'_>| -> Result<_> {
+        let mut entries = Vec::new();
+        if let Some(dynamic) = parser.dynamic_section()? {
+            for entry in dynamic.live_entries() {
+                let entry = entry?;
+                let tag = entry.tag();
+                let value = if STRING_TAGS.contains(&tag) {
+                    DynamicValue::String(
+                        dynamic
+                            .string_table()
+                            .bytes_at_offset(entry.value() as usize, "dynamic entry string")?
+                            .to_vec(),
+                    )
+                } else {
+                    DynamicValue::Integer(entry.value())
+                };
+                entries.push((tag, value));
+            }
+        }
+        Ok(group_by_occurrence(entries))
+    };
+    let mut diffs = Vec::new();
+    for ((tag, occurrence), a_value, b_value) in match_items(entries_by_tag(a)?, entries_by_tag(b)?)
+    {
+        if a_value != b_value {
+            diffs.push(DynamicEntryDiff {
+                tag,
+                occurrence,
+                a: a_value,
+                b: b_value,
+            });
+        }
+    }
+    Ok(diffs)
+}
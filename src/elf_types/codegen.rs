@@ -1,3 +1,5 @@
+#![allow(clippy::tabs_in_doc_comments)]
+
 use binary_serde::{impl_binary_serde_for_bitflags_ty, BinarySerde};
 use bitflags::bitflags;
 
@@ -0,0 +1,3347 @@
+//! decoding of type values whose meaning depends on the architechture and os abi of the elf file.
+//!
+//! the processor specific and os specific ranges of many elf type fields are shared between architechtures and
+//! operating systems, which is why the generated enums for these types contain merged variants. the functions here use
+//! the information about the elf file to find the single meaning of such values.
+
+use super::{
+    Architechture, DynamicTag, ElfFileInfo, MaybeUnknown, OsAbi, ProgramHeaderType, RelocationType,
+    SectionHeaderType, SymbolType,
+};
+
+/// a type value decoded according to the architechture and os abi of the elf file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodedType {
+    /// the raw value of the type.
+    pub raw: u64,
+
+    /// the name of the constant that represents this value in the elf specification (for example `SHT_ARM_EXIDX`), or
+    /// `None` if the value is unknown in the context of the elf file.
+    pub name: Option<&'static str>,
+}
+impl DecodedType {
+    /// the name of the constant without its prefix (for example `ARM_EXIDX` instead of `SHT_ARM_EXIDX`).
+    pub fn short_name(&self) -> Option<&'static str> {
+        let name = self.name?;
+        Some(name.split_once('_').map_or(name, |(_prefix, rest)| rest))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TypeNameContext {
+    /// the name is used regardless of the architechture and os abi of the file.
+    Generic,
+
+    /// the name is only used in files with one of the given os abis.
+    Os(&'static [OsAbi]),
+
+    /// the name is only used in files with one of the given architechtures.
+    Arch(&'static [Architechture]),
+}
+use TypeNameContext::{Arch, Generic, Os};
+
+type TypeNameEntry = (u64, TypeNameContext, &'static str);
+
+fn decode_type(raw: u64, file_info: &ElfFileInfo, table: &[TypeNameEntry]) -> DecodedType {
+    let candidates = || table.iter().filter(move |(value, _, _)| *value == raw);
+
+    // architechture specific names take precedence over os specific names, which take precedence over generic names.
+    let arch_specific = candidates()
+        .find(|(_, context, _)| matches!(context, Arch(archs) if file_info.arch.known().is_some_and(|arch| archs.contains(&arch))));
+    let os_specific = || {
+        candidates().find(
+            |(_, context, _)| matches!(context, Os(os_abis) if file_info.os_abi.known().is_some_and(|os_abi| os_abis.contains(&os_abi))),
+        )
+    };
+    let generic = || candidates().find(|(_, context, _)| matches!(context, Generic));
+
+    DecodedType {
+        raw,
+        name: arch_specific
+            .or_else(os_specific)
+            .or_else(generic)
+            .map(|(_, _, name)| *name),
+    }
+}
+
+/// finds the value of the relocation type of the given architechture whose name ends with the given suffix, which
+/// follows the architechture specific prefix (for example `COPY` for `R_X86_64_COPY`).
+#[cfg(feature = "alloc")]
+pub(crate) fn find_relocation_type(arch: Architechture, suffix: &str) -> Option<u32> {
+    RELOCATION_TYPE_NAMES
+        .iter()
+        .find(|(_, context, name)| {
+            matches!(context, Arch(archs) if archs.contains(&arch))
+                && name
+                    .strip_suffix(suffix)
+                    .is_some_and(|prefix| prefix.ends_with('_'))
+        })
+        .map(|(value, _, _)| *value as u32)
+}
+
+impl ElfFileInfo {
+    pub fn decode_section_header_type(&self, raw: u32) -> DecodedType {
+        decode_type(raw as u64, self, SECTION_HEADER_TYPE_NAMES)
+    }
+
+    pub fn decode_program_header_type(&self, raw: u32) -> DecodedType {
+        decode_type(raw as u64, self, PROGRAM_HEADER_TYPE_NAMES)
+    }
+
+    pub fn decode_symbol_type(&self, raw: u8) -> DecodedType {
+        decode_type(raw as u64, self, SYMBOL_TYPE_NAMES)
+    }
+
+    /// decodes the tag of a dynamic section entry. the tag is signed, but all defined tags are positive.
+    pub fn decode_dynamic_tag(&self, raw: i64) -> DecodedType {
+        decode_type(raw as u64, self, DYNAMIC_TAG_NAMES)
+    }
+
+    /// decodes a relocation type. all relocation types are architechture specific, so only the relocation types of the
+    /// common architechtures are known.
+    pub fn decode_relocation_type(&self, raw: u32) -> DecodedType {
+        decode_type(raw as u64, self, RELOCATION_TYPE_NAMES)
+    }
+}
+
+impl SectionHeaderType {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_section_header_type(self as u32)
+    }
+}
+
+impl ProgramHeaderType {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_program_header_type(self as u32)
+    }
+}
+
+impl SymbolType {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_symbol_type(self as u8)
+    }
+}
+
+impl MaybeUnknown<SectionHeaderType> {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_section_header_type(self.raw())
+    }
+}
+
+impl MaybeUnknown<ProgramHeaderType> {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_program_header_type(self.raw())
+    }
+}
+
+impl MaybeUnknown<SymbolType> {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_symbol_type(self.raw())
+    }
+}
+
+impl RelocationType {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_relocation_type(self as u32)
+    }
+}
+
+impl MaybeUnknown<RelocationType> {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_relocation_type(self.raw())
+    }
+}
+
+impl DynamicTag {
+    pub fn decode(self, file_info: &ElfFileInfo) -> DecodedType {
+        file_info.decode_dynamic_tag(self as i64)
+    }
+}
+
+const SECTION_HEADER_TYPE_NAMES: &[TypeNameEntry] = &[
+    (0x0, Generic, "SHT_NULL"),
+    (0x1, Generic, "SHT_PROGBITS"),
+    (0x2, Generic, "SHT_SYMTAB"),
+    (0x3, Generic, "SHT_STRTAB"),
+    (0x4, Generic, "SHT_RELA"),
+    (0x5, Generic, "SHT_HASH"),
+    (0x6, Generic, "SHT_DYNAMIC"),
+    (0x7, Generic, "SHT_NOTE"),
+    (0x8, Generic, "SHT_NOBITS"),
+    (0x9, Generic, "SHT_REL"),
+    (0xa, Generic, "SHT_SHLIB"),
+    (0xb, Generic, "SHT_DYNSYM"),
+    (0xe, Generic, "SHT_INIT_ARRAY"),
+    (0xf, Generic, "SHT_FINI_ARRAY"),
+    (0x10, Generic, "SHT_PREINIT_ARRAY"),
+    (0x11, Generic, "SHT_GROUP"),
+    (0x12, Generic, "SHT_SYMTAB_SHNDX"),
+    (0x13, Generic, "SHT_RELR"),
+    (0x60000001, Generic, "SHT_ANDROID_REL"),
+    (0x60000002, Generic, "SHT_ANDROID_RELA"),
+    (0x6fff4700, Generic, "SHT_GNU_INCREMENTAL_INPUTS"),
+    (0x6fff4701, Generic, "SHT_GNU_INCREMENTAL_SYMTAB"),
+    (0x6fff4702, Generic, "SHT_GNU_INCREMENTAL_RELOCS"),
+    (0x6fff4703, Generic, "SHT_GNU_INCREMENTAL_GOT_PLT"),
+    (0x6fff4c00, Generic, "SHT_LLVM_ODRTAB"),
+    (0x6fff4c01, Generic, "SHT_LLVM_LINKER_OPTIONS"),
+    (0x6fff4c03, Generic, "SHT_LLVM_ADDRSIG"),
+    (0x6fff4c04, Generic, "SHT_LLVM_DEPENDENT_LIBRARIES"),
+    (0x6fff4c05, Generic, "SHT_LLVM_SYMPART"),
+    (0x6fff4c06, Generic, "SHT_LLVM_PART_EHDR"),
+    (0x6fff4c07, Generic, "SHT_LLVM_PART_PHDR"),
+    (0x6fff4c08, Generic, "SHT_LLVM_BB_ADDR_MAP_V0"),
+    (0x6fff4c09, Generic, "SHT_LLVM_CALL_GRAPH_PROFILE"),
+    (0x6fff4c0a, Generic, "SHT_LLVM_BB_ADDR_MAP"),
+    (0x6fff4c0b, Generic, "SHT_LLVM_OFFLOADING"),
+    (0x6fff4c0c, Generic, "SHT_LLVM_LTO"),
+    (0x6fffff00, Generic, "SHT_ANDROID_RELR"),
+    (0x6ffffff4, Generic, "SHT_GNU_SFRAME"),
+    (0x6ffffff5, Generic, "SHT_GNU_ATTRIBUTES"),
+    (0x6ffffff6, Generic, "SHT_GNU_HASH"),
+    (0x6ffffff7, Generic, "SHT_GNU_LIBLIST"),
+    (0x6ffffff8, Generic, "SHT_CHECKSUM"),
+    (0x6ffffffa, Generic, "SHT_SUNW_move"),
+    (0x6ffffffb, Generic, "SHT_SUNW_COMDAT"),
+    (0x6ffffffc, Generic, "SHT_SUNW_syminfo"),
+    (0x6ffffffd, Generic, "SHT_GNU_verdef"),
+    (0x6ffffffe, Generic, "SHT_GNU_verneed"),
+    (0x6fffffff, Generic, "SHT_GNU_versym"),
+    (0x6fffffee, Os(&[OsAbi::Solaris]), "SHT_SUNW_ancillary"),
+    (0x6fffffef, Os(&[OsAbi::Solaris]), "SHT_SUNW_capchain"),
+    (0x6ffffff0, Os(&[OsAbi::Solaris]), "SHT_SUNW_capinfo"),
+    (0x6ffffff1, Os(&[OsAbi::Solaris]), "SHT_SUNW_symsort"),
+    (0x6ffffff2, Os(&[OsAbi::Solaris]), "SHT_SUNW_tlssort"),
+    (0x6ffffff3, Os(&[OsAbi::Solaris]), "SHT_SUNW_LDYNSYM"),
+    (0x6ffffff4, Os(&[OsAbi::Solaris]), "SHT_SUNW_dof"),
+    (0x6ffffff5, Os(&[OsAbi::Solaris]), "SHT_SUNW_cap"),
+    (0x6ffffff6, Os(&[OsAbi::Solaris]), "SHT_SUNW_SIGNATURE"),
+    (0x6ffffff7, Os(&[OsAbi::Solaris]), "SHT_SUNW_ANNOTATE"),
+    (0x6ffffff8, Os(&[OsAbi::Solaris]), "SHT_SUNW_DEBUGSTR"),
+    (0x6ffffff9, Os(&[OsAbi::Solaris]), "SHT_SUNW_DEBUG"),
+    (0x6ffffffd, Os(&[OsAbi::Solaris]), "SHT_SUNW_verdef"),
+    (0x6ffffffe, Os(&[OsAbi::Solaris]), "SHT_SUNW_verneed"),
+    (0x6fffffff, Os(&[OsAbi::Solaris]), "SHT_SUNW_versym"),
+    (0x70000001, Arch(&[Architechture::Arm]), "SHT_ARM_EXIDX"),
+    (
+        0x70000002,
+        Arch(&[Architechture::Arm]),
+        "SHT_ARM_PREEMPTMAP",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Arm]),
+        "SHT_ARM_ATTRIBUTES",
+    ),
+    (
+        0x70000004,
+        Arch(&[Architechture::Arm]),
+        "SHT_ARM_DEBUGOVERLAY",
+    ),
+    (
+        0x70000005,
+        Arch(&[Architechture::Arm]),
+        "SHT_ARM_OVERLAYSECTION",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::AArch64]),
+        "SHT_AARCH64_ATTRIBUTES",
+    ),
+    (
+        0x70000004,
+        Arch(&[Architechture::AArch64]),
+        "SHT_AARCH64_AUTH_RELR",
+    ),
+    (
+        0x70000007,
+        Arch(&[Architechture::AArch64]),
+        "SHT_AARCH64_MEMTAG_GLOBALS_STATIC",
+    ),
+    (
+        0x70000008,
+        Arch(&[Architechture::AArch64]),
+        "SHT_AARCH64_MEMTAG_GLOBALS_DYNAMIC",
+    ),
+    (
+        0x70000000,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_LIBLIST",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_MSYM",
+    ),
+    (
+        0x70000002,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_CONFLICT",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_GPTAB",
+    ),
+    (
+        0x70000004,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_UCODE",
+    ),
+    (
+        0x70000005,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DEBUG",
+    ),
+    (
+        0x70000006,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_REGINFO",
+    ),
+    (
+        0x70000007,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_PACKAGE",
+    ),
+    (
+        0x70000008,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_PACKSYM",
+    ),
+    (
+        0x70000009,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_RELD",
+    ),
+    (
+        0x7000000b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_IFACE",
+    ),
+    (
+        0x7000000c,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_CONTENT",
+    ),
+    (
+        0x7000000d,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_OPTIONS",
+    ),
+    (
+        0x70000010,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_SHDR",
+    ),
+    (
+        0x70000011,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_FDESC",
+    ),
+    (
+        0x70000012,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_EXTSYM",
+    ),
+    (
+        0x70000013,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DENSE",
+    ),
+    (
+        0x70000014,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_PDESC",
+    ),
+    (
+        0x70000015,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_LOCSYM",
+    ),
+    (
+        0x70000016,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_AUXSYM",
+    ),
+    (
+        0x70000017,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_OPTSYM",
+    ),
+    (
+        0x70000018,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_LOCSTR",
+    ),
+    (
+        0x70000019,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_LINE",
+    ),
+    (
+        0x7000001a,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_RFDESC",
+    ),
+    (
+        0x7000001b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DELTASYM",
+    ),
+    (
+        0x7000001c,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DELTAINST",
+    ),
+    (
+        0x7000001d,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DELTACLASS",
+    ),
+    (
+        0x7000001e,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DWARF",
+    ),
+    (
+        0x7000001f,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_DELTADECL",
+    ),
+    (
+        0x70000020,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_SYMBOL_LIB",
+    ),
+    (
+        0x70000021,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_EVENTS",
+    ),
+    (
+        0x70000022,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_TRANSLATE",
+    ),
+    (
+        0x70000023,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_PIXIE",
+    ),
+    (
+        0x70000024,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_XLATE",
+    ),
+    (
+        0x70000025,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_XLATE_DEBUG",
+    ),
+    (
+        0x70000026,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_WHIRL",
+    ),
+    (
+        0x70000027,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_EH_REGION",
+    ),
+    (
+        0x70000028,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_XLATE_OLD",
+    ),
+    (
+        0x70000029,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_PDR_EXCEPTION",
+    ),
+    (
+        0x7000002a,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_ABIFLAGS",
+    ),
+    (
+        0x7000002b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "SHT_MIPS_XHASH",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::X8664]),
+        "SHT_X86_64_UNWIND",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Riscv]),
+        "SHT_RISCV_ATTRIBUTES",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Msp430]),
+        "SHT_MSP430_ATTRIBUTES",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Csky]),
+        "SHT_CSKY_ATTRIBUTES",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Arc]),
+        "SHT_ARC_ATTRIBUTES",
+    ),
+    (0x70000000, Arch(&[Architechture::Parisc]), "SHT_PARISC_EXT"),
+    (
+        0x70000001,
+        Arch(&[Architechture::Parisc]),
+        "SHT_PARISC_UNWIND",
+    ),
+    (0x70000002, Arch(&[Architechture::Parisc]), "SHT_PARISC_DOC"),
+    (
+        0x70000003,
+        Arch(&[Architechture::Parisc]),
+        "SHT_PARISC_ANNOT",
+    ),
+    (
+        0x70000004,
+        Arch(&[Architechture::Parisc]),
+        "SHT_PARISC_DLKM",
+    ),
+    (
+        0x70000008,
+        Arch(&[Architechture::Parisc]),
+        "SHT_PARISC_SYMEXTN",
+    ),
+    (
+        0x70000009,
+        Arch(&[Architechture::Parisc]),
+        "SHT_PARISC_STUBS",
+    ),
+    (0x70000000, Arch(&[Architechture::Ia64]), "SHT_IA_64_EXT"),
+    (0x70000001, Arch(&[Architechture::Ia64]), "SHT_IA_64_UNWIND"),
+    (0x70000001, Arch(&[Architechture::Alpha]), "SHT_ALPHA_DEBUG"),
+    (
+        0x70000002,
+        Arch(&[Architechture::Alpha]),
+        "SHT_ALPHA_REGINFO",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::TiC6000]),
+        "SHT_C6000_UNWIND",
+    ),
+    (
+        0x70000002,
+        Arch(&[Architechture::TiC6000]),
+        "SHT_C6000_PREEMPTMAP",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::TiC6000]),
+        "SHT_C6000_ATTRIBUTES",
+    ),
+    (
+        0x70000000,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "SHT_SPARC_GOTDATA",
+    ),
+];
+
+const PROGRAM_HEADER_TYPE_NAMES: &[TypeNameEntry] = &[
+    (0x0, Generic, "PT_NULL"),
+    (0x1, Generic, "PT_LOAD"),
+    (0x2, Generic, "PT_DYNAMIC"),
+    (0x3, Generic, "PT_INTERP"),
+    (0x4, Generic, "PT_NOTE"),
+    (0x5, Generic, "PT_SHLIB"),
+    (0x6, Generic, "PT_PHDR"),
+    (0x7, Generic, "PT_TLS"),
+    (0x6474e550, Generic, "PT_GNU_EH_FRAME"),
+    (0x6474e551, Generic, "PT_GNU_STACK"),
+    (0x6474e552, Generic, "PT_GNU_RELRO"),
+    (0x6474e553, Generic, "PT_GNU_PROPERTY"),
+    (0x6474e554, Generic, "PT_GNU_SFRAME"),
+    (0x65a3dbe5, Generic, "PT_OPENBSD_MUTABLE"),
+    (0x65a3dbe6, Generic, "PT_OPENBSD_RANDOMIZE"),
+    (0x65a3dbe7, Generic, "PT_OPENBSD_WXNEEDED"),
+    (0x65a3dbe8, Generic, "PT_OPENBSD_NOBTCFI"),
+    (0x65a41be6, Generic, "PT_OPENBSD_BOOTDATA"),
+    (0x6ffffffa, Generic, "PT_SUNWBSS"),
+    (0x6ffffffb, Generic, "PT_SUNWSTACK"),
+    (0x60000000, Os(&[OsAbi::Hpux]), "PT_HP_TLS"),
+    (0x60000001, Os(&[OsAbi::Hpux]), "PT_HP_CORE_NONE"),
+    (0x60000002, Os(&[OsAbi::Hpux]), "PT_HP_CORE_VERSION"),
+    (0x60000003, Os(&[OsAbi::Hpux]), "PT_HP_CORE_KERNEL"),
+    (0x60000004, Os(&[OsAbi::Hpux]), "PT_HP_CORE_COMM"),
+    (0x60000005, Os(&[OsAbi::Hpux]), "PT_HP_CORE_PROC"),
+    (0x60000006, Os(&[OsAbi::Hpux]), "PT_HP_CORE_LOADABLE"),
+    (0x60000007, Os(&[OsAbi::Hpux]), "PT_HP_CORE_STACK"),
+    (0x60000008, Os(&[OsAbi::Hpux]), "PT_HP_CORE_SHM"),
+    (0x60000009, Os(&[OsAbi::Hpux]), "PT_HP_CORE_MMF"),
+    (0x60000010, Os(&[OsAbi::Hpux]), "PT_HP_PARALLEL"),
+    (0x60000011, Os(&[OsAbi::Hpux]), "PT_HP_FASTBIND"),
+    (0x60000012, Os(&[OsAbi::Hpux]), "PT_HP_OPT_ANNOT"),
+    (0x60000013, Os(&[OsAbi::Hpux]), "PT_HP_HSL_ANNOT"),
+    (0x60000014, Os(&[OsAbi::Hpux]), "PT_HP_STACK"),
+    (0x70000000, Arch(&[Architechture::Arm]), "PT_ARM_ARCHEXT"),
+    (0x70000001, Arch(&[Architechture::Arm]), "PT_ARM_EXIDX"),
+    (
+        0x70000000,
+        Arch(&[Architechture::AArch64]),
+        "PT_AARCH64_ARCHEXT",
+    ),
+    (
+        0x70000002,
+        Arch(&[Architechture::AArch64]),
+        "PT_AARCH64_MEMTAG_MTE",
+    ),
+    (
+        0x70000000,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "PT_MIPS_REGINFO",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "PT_MIPS_RTPROC",
+    ),
+    (
+        0x70000002,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "PT_MIPS_OPTIONS",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "PT_MIPS_ABIFLAGS",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Riscv]),
+        "PT_RISCV_ATTRIBUTES",
+    ),
+    (0x70000000, Arch(&[Architechture::Ia64]), "PT_IA_64_ARCHEXT"),
+    (0x70000001, Arch(&[Architechture::Ia64]), "PT_IA_64_UNWIND"),
+    (
+        0x70000000,
+        Arch(&[Architechture::Parisc]),
+        "PT_PARISC_ARCHEXT",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Parisc]),
+        "PT_PARISC_UNWIND",
+    ),
+    (0x70000000, Arch(&[Architechture::S390]), "PT_S390_PGSTE"),
+    (
+        0x70000000,
+        Arch(&[Architechture::TiC6000]),
+        "PT_C6000_PHATTR",
+    ),
+];
+
+const SYMBOL_TYPE_NAMES: &[TypeNameEntry] = &[
+    (0x0, Generic, "STT_NOTYPE"),
+    (0x1, Generic, "STT_OBJECT"),
+    (0x2, Generic, "STT_FUNC"),
+    (0x3, Generic, "STT_SECTION"),
+    (0x4, Generic, "STT_FILE"),
+    (0x5, Generic, "STT_COMMON"),
+    (0x6, Generic, "STT_TLS"),
+    (0x8, Generic, "STT_RELC"),
+    (0x9, Generic, "STT_SRELC"),
+    (
+        0xa,
+        Os(&[OsAbi::Sysv, OsAbi::Gnu, OsAbi::Freebsd]),
+        "STT_GNU_IFUNC",
+    ),
+    (0xd, Arch(&[Architechture::Arm]), "STT_ARM_TFUNC"),
+    (0xf, Arch(&[Architechture::Arm]), "STT_ARM_16BIT"),
+    (
+        0xd,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "STT_SPARC_REGISTER",
+    ),
+    (0xd, Arch(&[Architechture::Parisc]), "STT_PARISC_MILLICODE"),
+];
+
+const DYNAMIC_TAG_NAMES: &[TypeNameEntry] = &[
+    (0x0, Generic, "DT_NULL"),
+    (0x1, Generic, "DT_NEEDED"),
+    (0x2, Generic, "DT_PLTRELSZ"),
+    (0x3, Generic, "DT_PLTGOT"),
+    (0x4, Generic, "DT_HASH"),
+    (0x5, Generic, "DT_STRTAB"),
+    (0x6, Generic, "DT_SYMTAB"),
+    (0x7, Generic, "DT_RELA"),
+    (0x8, Generic, "DT_RELASZ"),
+    (0x9, Generic, "DT_RELAENT"),
+    (0xa, Generic, "DT_STRSZ"),
+    (0xb, Generic, "DT_SYMENT"),
+    (0xc, Generic, "DT_INIT"),
+    (0xd, Generic, "DT_FINI"),
+    (0xe, Generic, "DT_SONAME"),
+    (0xf, Generic, "DT_RPATH"),
+    (0x10, Generic, "DT_SYMBOLIC"),
+    (0x11, Generic, "DT_REL"),
+    (0x12, Generic, "DT_RELSZ"),
+    (0x13, Generic, "DT_RELENT"),
+    (0x14, Generic, "DT_PLTREL"),
+    (0x15, Generic, "DT_DEBUG"),
+    (0x16, Generic, "DT_TEXTREL"),
+    (0x17, Generic, "DT_JMPREL"),
+    (0x18, Generic, "DT_BIND_NOW"),
+    (0x19, Generic, "DT_INIT_ARRAY"),
+    (0x1a, Generic, "DT_FINI_ARRAY"),
+    (0x1b, Generic, "DT_INIT_ARRAYSZ"),
+    (0x1c, Generic, "DT_FINI_ARRAYSZ"),
+    (0x1d, Generic, "DT_RUNPATH"),
+    (0x1e, Generic, "DT_FLAGS"),
+    (0x20, Generic, "DT_PREINIT_ARRAY"),
+    (0x21, Generic, "DT_PREINIT_ARRAYSZ"),
+    (0x22, Generic, "DT_SYMTAB_SHNDX"),
+    (0x23, Generic, "DT_RELRSZ"),
+    (0x24, Generic, "DT_RELR"),
+    (0x25, Generic, "DT_RELRENT"),
+    (0x6ffffdf4, Generic, "DT_GNU_FLAGS_1"),
+    (0x6ffffdf5, Generic, "DT_GNU_PRELINKED"),
+    (0x6ffffdf6, Generic, "DT_GNU_CONFLICTSZ"),
+    (0x6ffffdf7, Generic, "DT_GNU_LIBLISTSZ"),
+    (0x6ffffdf8, Generic, "DT_CHECKSUM"),
+    (0x6ffffdf9, Generic, "DT_PLTPADSZ"),
+    (0x6ffffdfa, Generic, "DT_MOVEENT"),
+    (0x6ffffdfb, Generic, "DT_MOVESZ"),
+    (0x6ffffdfc, Generic, "DT_FEATURE_1"),
+    (0x6ffffdfd, Generic, "DT_POSFLAG_1"),
+    (0x6ffffdfe, Generic, "DT_SYMINSZ"),
+    (0x6ffffdff, Generic, "DT_SYMINENT"),
+    (0x6ffffef5, Generic, "DT_GNU_HASH"),
+    (0x6ffffef6, Generic, "DT_TLSDESC_PLT"),
+    (0x6ffffef7, Generic, "DT_TLSDESC_GOT"),
+    (0x6ffffef8, Generic, "DT_GNU_CONFLICT"),
+    (0x6ffffef9, Generic, "DT_GNU_LIBLIST"),
+    (0x6ffffefa, Generic, "DT_CONFIG"),
+    (0x6ffffefb, Generic, "DT_DEPAUDIT"),
+    (0x6ffffefc, Generic, "DT_AUDIT"),
+    (0x6ffffefd, Generic, "DT_PLTPAD"),
+    (0x6ffffefe, Generic, "DT_MOVETAB"),
+    (0x6ffffeff, Generic, "DT_SYMINFO"),
+    (0x6ffffff0, Generic, "DT_VERSYM"),
+    (0x6ffffff9, Generic, "DT_RELACOUNT"),
+    (0x6ffffffa, Generic, "DT_RELCOUNT"),
+    (0x6ffffffb, Generic, "DT_FLAGS_1"),
+    (0x6ffffffc, Generic, "DT_VERDEF"),
+    (0x6ffffffd, Generic, "DT_VERDEFNUM"),
+    (0x6ffffffe, Generic, "DT_VERNEED"),
+    (0x6fffffff, Generic, "DT_VERNEEDNUM"),
+    (0x7ffffffd, Generic, "DT_AUXILIARY"),
+    (0x7ffffffe, Generic, "DT_USED"),
+    (0x7fffffff, Generic, "DT_FILTER"),
+    (0x70000000, Arch(&[Architechture::X8664]), "DT_X86_64_PLT"),
+    (0x70000001, Arch(&[Architechture::X8664]), "DT_X86_64_PLTSZ"),
+    (
+        0x70000003,
+        Arch(&[Architechture::X8664]),
+        "DT_X86_64_PLTENT",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_BTI_PLT",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_PAC_PLT",
+    ),
+    (
+        0x70000005,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_VARIANT_PCS",
+    ),
+    (
+        0x70000009,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_MEMTAG_MODE",
+    ),
+    (
+        0x7000000b,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_MEMTAG_HEAP",
+    ),
+    (
+        0x7000000c,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_MEMTAG_STACK",
+    ),
+    (
+        0x7000000d,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_MEMTAG_GLOBALS",
+    ),
+    (
+        0x7000000f,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_MEMTAG_GLOBALSSZ",
+    ),
+    (
+        0x70000011,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_AUTH_RELRSZ",
+    ),
+    (
+        0x70000012,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_AUTH_RELR",
+    ),
+    (
+        0x70000013,
+        Arch(&[Architechture::AArch64]),
+        "DT_AARCH64_AUTH_RELRENT",
+    ),
+    (
+        0x70000001,
+        Arch(&[Architechture::Riscv]),
+        "DT_RISCV_VARIANT_CC",
+    ),
+    (0x70000000, Arch(&[Architechture::Ppc]), "DT_PPC_GOT"),
+    (0x70000001, Arch(&[Architechture::Ppc]), "DT_PPC_OPT"),
+    (0x70000000, Arch(&[Architechture::Ppc64]), "DT_PPC64_GLINK"),
+    (0x70000001, Arch(&[Architechture::Ppc64]), "DT_PPC64_OPD"),
+    (0x70000002, Arch(&[Architechture::Ppc64]), "DT_PPC64_OPDSZ"),
+    (0x70000003, Arch(&[Architechture::Ppc64]), "DT_PPC64_OPT"),
+    (
+        0x70000001,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "DT_SPARC_REGISTER",
+    ),
+    (
+        0x70000000,
+        Arch(&[Architechture::Ia64]),
+        "DT_IA_64_PLT_RESERVE",
+    ),
+    (0x70000000, Arch(&[Architechture::Alpha]), "DT_ALPHA_PLTRO"),
+    (
+        0x70000001,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_RLD_VERSION",
+    ),
+    (
+        0x70000002,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_TIME_STAMP",
+    ),
+    (
+        0x70000003,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_ICHECKSUM",
+    ),
+    (
+        0x70000004,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_IVERSION",
+    ),
+    (
+        0x70000005,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_FLAGS",
+    ),
+    (
+        0x70000006,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_BASE_ADDRESS",
+    ),
+    (
+        0x70000007,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_MSYM",
+    ),
+    (
+        0x70000008,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_CONFLICT",
+    ),
+    (
+        0x70000009,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_LIBLIST",
+    ),
+    (
+        0x7000000a,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_LOCAL_GOTNO",
+    ),
+    (
+        0x7000000b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_CONFLICTNO",
+    ),
+    (
+        0x70000010,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_LIBLISTNO",
+    ),
+    (
+        0x70000011,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_SYMTABNO",
+    ),
+    (
+        0x70000012,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_UNREFEXTNO",
+    ),
+    (
+        0x70000013,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_GOTSYM",
+    ),
+    (
+        0x70000014,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_HIPAGENO",
+    ),
+    (
+        0x70000016,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_RLD_MAP",
+    ),
+    (
+        0x70000017,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_CLASS",
+    ),
+    (
+        0x70000018,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_CLASS_NO",
+    ),
+    (
+        0x70000019,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_INSTANCE",
+    ),
+    (
+        0x7000001a,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_INSTANCE_NO",
+    ),
+    (
+        0x7000001b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_RELOC",
+    ),
+    (
+        0x7000001c,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_RELOC_NO",
+    ),
+    (
+        0x7000001d,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_SYM",
+    ),
+    (
+        0x7000001e,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_SYM_NO",
+    ),
+    (
+        0x70000020,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_CLASSSYM",
+    ),
+    (
+        0x70000021,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DELTA_CLASSSYM_NO",
+    ),
+    (
+        0x70000022,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_CXX_FLAGS",
+    ),
+    (
+        0x70000023,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_PIXIE_INIT",
+    ),
+    (
+        0x70000024,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_SYMBOL_LIB",
+    ),
+    (
+        0x70000025,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_LOCALPAGE_GOTIDX",
+    ),
+    (
+        0x70000026,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_LOCAL_GOTIDX",
+    ),
+    (
+        0x70000027,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_HIDDEN_GOTIDX",
+    ),
+    (
+        0x70000028,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_PROTECTED_GOTIDX",
+    ),
+    (
+        0x70000029,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_OPTIONS",
+    ),
+    (
+        0x7000002a,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_INTERFACE",
+    ),
+    (
+        0x7000002b,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_DYNSTR_ALIGN",
+    ),
+    (
+        0x7000002c,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_INTERFACE_SIZE",
+    ),
+    (
+        0x7000002d,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_RLD_TEXT_RESOLVE_ADDR",
+    ),
+    (
+        0x7000002e,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_PERF_SUFFIX",
+    ),
+    (
+        0x7000002f,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_COMPACT_SIZE",
+    ),
+    (
+        0x70000030,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_GP_VALUE",
+    ),
+    (
+        0x70000031,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_AUX_DYNAMIC",
+    ),
+    (
+        0x70000032,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_PLTGOT",
+    ),
+    (
+        0x70000034,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_RWPLT",
+    ),
+    (
+        0x70000035,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_RLD_MAP_REL",
+    ),
+    (
+        0x70000036,
+        Arch(&[Architechture::Mips, Architechture::MipsRs3Le]),
+        "DT_MIPS_XHASH",
+    ),
+];
+
+const RELOCATION_TYPE_NAMES: &[TypeNameEntry] = &[
+    (0x0, Arch(&[Architechture::I386]), "R_386_NONE"),
+    (0x1, Arch(&[Architechture::I386]), "R_386_32"),
+    (0x2, Arch(&[Architechture::I386]), "R_386_PC32"),
+    (0x3, Arch(&[Architechture::I386]), "R_386_GOT32"),
+    (0x4, Arch(&[Architechture::I386]), "R_386_PLT32"),
+    (0x5, Arch(&[Architechture::I386]), "R_386_COPY"),
+    (0x6, Arch(&[Architechture::I386]), "R_386_GLOB_DAT"),
+    (0x7, Arch(&[Architechture::I386]), "R_386_JMP_SLOT"),
+    (0x8, Arch(&[Architechture::I386]), "R_386_RELATIVE"),
+    (0x9, Arch(&[Architechture::I386]), "R_386_GOTOFF"),
+    (0xa, Arch(&[Architechture::I386]), "R_386_GOTPC"),
+    (0xb, Arch(&[Architechture::I386]), "R_386_32PLT"),
+    (0xe, Arch(&[Architechture::I386]), "R_386_TLS_TPOFF"),
+    (0xf, Arch(&[Architechture::I386]), "R_386_TLS_IE"),
+    (0x10, Arch(&[Architechture::I386]), "R_386_TLS_GOTIE"),
+    (0x11, Arch(&[Architechture::I386]), "R_386_TLS_LE"),
+    (0x12, Arch(&[Architechture::I386]), "R_386_TLS_GD"),
+    (0x13, Arch(&[Architechture::I386]), "R_386_TLS_LDM"),
+    (0x14, Arch(&[Architechture::I386]), "R_386_16"),
+    (0x15, Arch(&[Architechture::I386]), "R_386_PC16"),
+    (0x16, Arch(&[Architechture::I386]), "R_386_8"),
+    (0x17, Arch(&[Architechture::I386]), "R_386_PC8"),
+    (0x18, Arch(&[Architechture::I386]), "R_386_TLS_GD_32"),
+    (0x19, Arch(&[Architechture::I386]), "R_386_TLS_GD_PUSH"),
+    (0x1a, Arch(&[Architechture::I386]), "R_386_TLS_GD_CALL"),
+    (0x1b, Arch(&[Architechture::I386]), "R_386_TLS_GD_POP"),
+    (0x1c, Arch(&[Architechture::I386]), "R_386_TLS_LDM_32"),
+    (0x1d, Arch(&[Architechture::I386]), "R_386_TLS_LDM_PUSH"),
+    (0x1e, Arch(&[Architechture::I386]), "R_386_TLS_LDM_CALL"),
+    (0x1f, Arch(&[Architechture::I386]), "R_386_TLS_LDM_POP"),
+    (0x20, Arch(&[Architechture::I386]), "R_386_TLS_LDO_32"),
+    (0x21, Arch(&[Architechture::I386]), "R_386_TLS_IE_32"),
+    (0x22, Arch(&[Architechture::I386]), "R_386_TLS_LE_32"),
+    (0x23, Arch(&[Architechture::I386]), "R_386_TLS_DTPMOD32"),
+    (0x24, Arch(&[Architechture::I386]), "R_386_TLS_DTPOFF32"),
+    (0x25, Arch(&[Architechture::I386]), "R_386_TLS_TPOFF32"),
+    (0x26, Arch(&[Architechture::I386]), "R_386_SIZE32"),
+    (0x27, Arch(&[Architechture::I386]), "R_386_TLS_GOTDESC"),
+    (0x28, Arch(&[Architechture::I386]), "R_386_TLS_DESC_CALL"),
+    (0x29, Arch(&[Architechture::I386]), "R_386_TLS_DESC"),
+    (0x2a, Arch(&[Architechture::I386]), "R_386_IRELATIVE"),
+    (0x2b, Arch(&[Architechture::I386]), "R_386_GOT32X"),
+    (0x0, Arch(&[Architechture::X8664]), "R_X86_64_NONE"),
+    (0x1, Arch(&[Architechture::X8664]), "R_X86_64_64"),
+    (0x2, Arch(&[Architechture::X8664]), "R_X86_64_PC32"),
+    (0x3, Arch(&[Architechture::X8664]), "R_X86_64_GOT32"),
+    (0x4, Arch(&[Architechture::X8664]), "R_X86_64_PLT32"),
+    (0x5, Arch(&[Architechture::X8664]), "R_X86_64_COPY"),
+    (0x6, Arch(&[Architechture::X8664]), "R_X86_64_GLOB_DAT"),
+    (0x7, Arch(&[Architechture::X8664]), "R_X86_64_JUMP_SLOT"),
+    (0x8, Arch(&[Architechture::X8664]), "R_X86_64_RELATIVE"),
+    (0x9, Arch(&[Architechture::X8664]), "R_X86_64_GOTPCREL"),
+    (0xa, Arch(&[Architechture::X8664]), "R_X86_64_32"),
+    (0xb, Arch(&[Architechture::X8664]), "R_X86_64_32S"),
+    (0xc, Arch(&[Architechture::X8664]), "R_X86_64_16"),
+    (0xd, Arch(&[Architechture::X8664]), "R_X86_64_PC16"),
+    (0xe, Arch(&[Architechture::X8664]), "R_X86_64_8"),
+    (0xf, Arch(&[Architechture::X8664]), "R_X86_64_PC8"),
+    (0x10, Arch(&[Architechture::X8664]), "R_X86_64_DTPMOD64"),
+    (0x11, Arch(&[Architechture::X8664]), "R_X86_64_DTPOFF64"),
+    (0x12, Arch(&[Architechture::X8664]), "R_X86_64_TPOFF64"),
+    (0x13, Arch(&[Architechture::X8664]), "R_X86_64_TLSGD"),
+    (0x14, Arch(&[Architechture::X8664]), "R_X86_64_TLSLD"),
+    (0x15, Arch(&[Architechture::X8664]), "R_X86_64_DTPOFF32"),
+    (0x16, Arch(&[Architechture::X8664]), "R_X86_64_GOTTPOFF"),
+    (0x17, Arch(&[Architechture::X8664]), "R_X86_64_TPOFF32"),
+    (0x18, Arch(&[Architechture::X8664]), "R_X86_64_PC64"),
+    (0x19, Arch(&[Architechture::X8664]), "R_X86_64_GOTOFF64"),
+    (0x1a, Arch(&[Architechture::X8664]), "R_X86_64_GOTPC32"),
+    (0x1b, Arch(&[Architechture::X8664]), "R_X86_64_GOT64"),
+    (0x1c, Arch(&[Architechture::X8664]), "R_X86_64_GOTPCREL64"),
+    (0x1d, Arch(&[Architechture::X8664]), "R_X86_64_GOTPC64"),
+    (0x1e, Arch(&[Architechture::X8664]), "R_X86_64_GOTPLT64"),
+    (0x1f, Arch(&[Architechture::X8664]), "R_X86_64_PLTOFF64"),
+    (0x20, Arch(&[Architechture::X8664]), "R_X86_64_SIZE32"),
+    (0x21, Arch(&[Architechture::X8664]), "R_X86_64_SIZE64"),
+    (
+        0x22,
+        Arch(&[Architechture::X8664]),
+        "R_X86_64_GOTPC32_TLSDESC",
+    ),
+    (0x23, Arch(&[Architechture::X8664]), "R_X86_64_TLSDESC_CALL"),
+    (0x24, Arch(&[Architechture::X8664]), "R_X86_64_TLSDESC"),
+    (0x25, Arch(&[Architechture::X8664]), "R_X86_64_IRELATIVE"),
+    (0x26, Arch(&[Architechture::X8664]), "R_X86_64_RELATIVE64"),
+    (0x29, Arch(&[Architechture::X8664]), "R_X86_64_GOTPCRELX"),
+    (
+        0x2a,
+        Arch(&[Architechture::X8664]),
+        "R_X86_64_REX_GOTPCRELX",
+    ),
+    (0x0, Arch(&[Architechture::AArch64]), "R_AARCH64_NONE"),
+    (0x101, Arch(&[Architechture::AArch64]), "R_AARCH64_ABS64"),
+    (0x102, Arch(&[Architechture::AArch64]), "R_AARCH64_ABS32"),
+    (0x103, Arch(&[Architechture::AArch64]), "R_AARCH64_ABS16"),
+    (0x104, Arch(&[Architechture::AArch64]), "R_AARCH64_PREL64"),
+    (0x105, Arch(&[Architechture::AArch64]), "R_AARCH64_PREL32"),
+    (0x106, Arch(&[Architechture::AArch64]), "R_AARCH64_PREL16"),
+    (
+        0x107,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G0",
+    ),
+    (
+        0x108,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G0_NC",
+    ),
+    (
+        0x109,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G1",
+    ),
+    (
+        0x10a,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G1_NC",
+    ),
+    (
+        0x10b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G2",
+    ),
+    (
+        0x10c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G2_NC",
+    ),
+    (
+        0x10d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_UABS_G3",
+    ),
+    (
+        0x10e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_SABS_G0",
+    ),
+    (
+        0x10f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_SABS_G1",
+    ),
+    (
+        0x110,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_SABS_G2",
+    ),
+    (
+        0x111,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LD_PREL_LO19",
+    ),
+    (
+        0x112,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_ADR_PREL_LO21",
+    ),
+    (
+        0x113,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_ADR_PREL_PG_HI21",
+    ),
+    (
+        0x114,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_ADR_PREL_PG_HI21_NC",
+    ),
+    (
+        0x115,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_ADD_ABS_LO12_NC",
+    ),
+    (
+        0x116,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LDST8_ABS_LO12_NC",
+    ),
+    (0x117, Arch(&[Architechture::AArch64]), "R_AARCH64_TSTBR14"),
+    (0x118, Arch(&[Architechture::AArch64]), "R_AARCH64_CONDBR19"),
+    (0x11a, Arch(&[Architechture::AArch64]), "R_AARCH64_JUMP26"),
+    (0x11b, Arch(&[Architechture::AArch64]), "R_AARCH64_CALL26"),
+    (
+        0x11c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LDST16_ABS_LO12_NC",
+    ),
+    (
+        0x11d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LDST32_ABS_LO12_NC",
+    ),
+    (
+        0x11e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LDST64_ABS_LO12_NC",
+    ),
+    (
+        0x11f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G0",
+    ),
+    (
+        0x120,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G0_NC",
+    ),
+    (
+        0x121,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G1",
+    ),
+    (
+        0x122,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G1_NC",
+    ),
+    (
+        0x123,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G2",
+    ),
+    (
+        0x124,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G2_NC",
+    ),
+    (
+        0x125,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_PREL_G3",
+    ),
+    (
+        0x12b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LDST128_ABS_LO12_NC",
+    ),
+    (
+        0x12c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G0",
+    ),
+    (
+        0x12d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G0_NC",
+    ),
+    (
+        0x12e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G1",
+    ),
+    (
+        0x12f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G1_NC",
+    ),
+    (
+        0x130,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G2",
+    ),
+    (
+        0x131,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G2_NC",
+    ),
+    (
+        0x132,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_MOVW_GOTOFF_G3",
+    ),
+    (0x133, Arch(&[Architechture::AArch64]), "R_AARCH64_GOTREL64"),
+    (0x134, Arch(&[Architechture::AArch64]), "R_AARCH64_GOTREL32"),
+    (
+        0x135,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_GOT_LD_PREL19",
+    ),
+    (
+        0x136,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LD64_GOTOFF_LO15",
+    ),
+    (
+        0x137,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_ADR_GOT_PAGE",
+    ),
+    (
+        0x138,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LD64_GOT_LO12_NC",
+    ),
+    (
+        0x139,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_LD64_GOTPAGE_LO15",
+    ),
+    (
+        0x200,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSGD_ADR_PREL21",
+    ),
+    (
+        0x201,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSGD_ADR_PAGE21",
+    ),
+    (
+        0x202,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSGD_ADD_LO12_NC",
+    ),
+    (
+        0x203,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSGD_MOVW_G1",
+    ),
+    (
+        0x204,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSGD_MOVW_G0_NC",
+    ),
+    (
+        0x205,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADR_PREL21",
+    ),
+    (
+        0x206,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADR_PAGE21",
+    ),
+    (
+        0x207,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADD_LO12_NC",
+    ),
+    (
+        0x208,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_G1",
+    ),
+    (
+        0x209,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_G0_NC",
+    ),
+    (
+        0x20a,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LD_PREL19",
+    ),
+    (
+        0x20b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_DTPREL_G2",
+    ),
+    (
+        0x20c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_DTPREL_G1",
+    ),
+    (
+        0x20d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC",
+    ),
+    (
+        0x20e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_DTPREL_G0",
+    ),
+    (
+        0x20f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC",
+    ),
+    (
+        0x210,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADD_DTPREL_HI12",
+    ),
+    (
+        0x211,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADD_DTPREL_LO12",
+    ),
+    (
+        0x212,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC",
+    ),
+    (
+        0x213,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST8_DTPREL_LO12",
+    ),
+    (
+        0x214,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC",
+    ),
+    (
+        0x215,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST16_DTPREL_LO12",
+    ),
+    (
+        0x216,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC",
+    ),
+    (
+        0x217,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST32_DTPREL_LO12",
+    ),
+    (
+        0x218,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC",
+    ),
+    (
+        0x219,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST64_DTPREL_LO12",
+    ),
+    (
+        0x21a,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC",
+    ),
+    (
+        0x21b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1",
+    ),
+    (
+        0x21c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
+    ),
+    (
+        0x21d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+    ),
+    (
+        0x21e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+    ),
+    (
+        0x21f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19",
+    ),
+    (
+        0x220,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_MOVW_TPREL_G2",
+    ),
+    (
+        0x221,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_MOVW_TPREL_G1",
+    ),
+    (
+        0x222,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC",
+    ),
+    (
+        0x223,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_MOVW_TPREL_G0",
+    ),
+    (
+        0x224,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC",
+    ),
+    (
+        0x225,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+    ),
+    (
+        0x226,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+    ),
+    (
+        0x227,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+    ),
+    (
+        0x228,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST8_TPREL_LO12",
+    ),
+    (
+        0x229,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
+    ),
+    (
+        0x22a,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST16_TPREL_LO12",
+    ),
+    (
+        0x22b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
+    ),
+    (
+        0x22c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST32_TPREL_LO12",
+    ),
+    (
+        0x22d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
+    ),
+    (
+        0x22e,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST64_TPREL_LO12",
+    ),
+    (
+        0x22f,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
+    ),
+    (
+        0x230,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_LD_PREL19",
+    ),
+    (
+        0x231,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_ADR_PREL21",
+    ),
+    (
+        0x232,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_ADR_PAGE21",
+    ),
+    (
+        0x233,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_LD64_LO12",
+    ),
+    (
+        0x234,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_ADD_LO12",
+    ),
+    (
+        0x235,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_OFF_G1",
+    ),
+    (
+        0x236,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_OFF_G0_NC",
+    ),
+    (
+        0x237,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_LDR",
+    ),
+    (
+        0x238,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_ADD",
+    ),
+    (
+        0x239,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSDESC_CALL",
+    ),
+    (
+        0x23a,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST128_TPREL_LO12",
+    ),
+    (
+        0x23b,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC",
+    ),
+    (
+        0x23c,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST128_DTPREL_LO12",
+    ),
+    (
+        0x23d,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC",
+    ),
+    (0x400, Arch(&[Architechture::AArch64]), "R_AARCH64_COPY"),
+    (0x401, Arch(&[Architechture::AArch64]), "R_AARCH64_GLOB_DAT"),
+    (
+        0x402,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_JUMP_SLOT",
+    ),
+    (0x403, Arch(&[Architechture::AArch64]), "R_AARCH64_RELATIVE"),
+    (
+        0x404,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLS_DTPMOD",
+    ),
+    (
+        0x405,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLS_DTPREL",
+    ),
+    (
+        0x406,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_TLS_TPREL",
+    ),
+    (0x407, Arch(&[Architechture::AArch64]), "R_AARCH64_TLSDESC"),
+    (
+        0x408,
+        Arch(&[Architechture::AArch64]),
+        "R_AARCH64_IRELATIVE",
+    ),
+    (0x0, Arch(&[Architechture::Arm]), "R_ARM_NONE"),
+    (0x1, Arch(&[Architechture::Arm]), "R_ARM_PC24"),
+    (0x2, Arch(&[Architechture::Arm]), "R_ARM_ABS32"),
+    (0x3, Arch(&[Architechture::Arm]), "R_ARM_REL32"),
+    (0x4, Arch(&[Architechture::Arm]), "R_ARM_PC13"),
+    (0x5, Arch(&[Architechture::Arm]), "R_ARM_ABS16"),
+    (0x6, Arch(&[Architechture::Arm]), "R_ARM_ABS12"),
+    (0x7, Arch(&[Architechture::Arm]), "R_ARM_THM_ABS5"),
+    (0x8, Arch(&[Architechture::Arm]), "R_ARM_ABS8"),
+    (0x9, Arch(&[Architechture::Arm]), "R_ARM_SBREL32"),
+    (0xa, Arch(&[Architechture::Arm]), "R_ARM_THM_PC22"),
+    (0xb, Arch(&[Architechture::Arm]), "R_ARM_THM_PC8"),
+    (0xc, Arch(&[Architechture::Arm]), "R_ARM_AMP_VCALL9"),
+    (0xd, Arch(&[Architechture::Arm]), "R_ARM_SWI24"),
+    (0xe, Arch(&[Architechture::Arm]), "R_ARM_THM_SWI8"),
+    (0xf, Arch(&[Architechture::Arm]), "R_ARM_XPC25"),
+    (0x10, Arch(&[Architechture::Arm]), "R_ARM_THM_XPC22"),
+    (0x11, Arch(&[Architechture::Arm]), "R_ARM_TLS_DTPMOD32"),
+    (0x12, Arch(&[Architechture::Arm]), "R_ARM_TLS_DTPOFF32"),
+    (0x13, Arch(&[Architechture::Arm]), "R_ARM_TLS_TPOFF32"),
+    (0x14, Arch(&[Architechture::Arm]), "R_ARM_COPY"),
+    (0x15, Arch(&[Architechture::Arm]), "R_ARM_GLOB_DAT"),
+    (0x16, Arch(&[Architechture::Arm]), "R_ARM_JUMP_SLOT"),
+    (0x17, Arch(&[Architechture::Arm]), "R_ARM_RELATIVE"),
+    (0x18, Arch(&[Architechture::Arm]), "R_ARM_GOTOFF"),
+    (0x19, Arch(&[Architechture::Arm]), "R_ARM_GOTPC"),
+    (0x1a, Arch(&[Architechture::Arm]), "R_ARM_GOT32"),
+    (0x1b, Arch(&[Architechture::Arm]), "R_ARM_PLT32"),
+    (0x1c, Arch(&[Architechture::Arm]), "R_ARM_CALL"),
+    (0x1d, Arch(&[Architechture::Arm]), "R_ARM_JUMP24"),
+    (0x1e, Arch(&[Architechture::Arm]), "R_ARM_THM_JUMP24"),
+    (0x1f, Arch(&[Architechture::Arm]), "R_ARM_BASE_ABS"),
+    (0x20, Arch(&[Architechture::Arm]), "R_ARM_ALU_PCREL_7_0"),
+    (0x21, Arch(&[Architechture::Arm]), "R_ARM_ALU_PCREL_15_8"),
+    (0x22, Arch(&[Architechture::Arm]), "R_ARM_ALU_PCREL_23_15"),
+    (0x23, Arch(&[Architechture::Arm]), "R_ARM_LDR_SBREL_11_0"),
+    (0x24, Arch(&[Architechture::Arm]), "R_ARM_ALU_SBREL_19_12"),
+    (0x25, Arch(&[Architechture::Arm]), "R_ARM_ALU_SBREL_27_20"),
+    (0x26, Arch(&[Architechture::Arm]), "R_ARM_TARGET1"),
+    (0x27, Arch(&[Architechture::Arm]), "R_ARM_SBREL31"),
+    (0x28, Arch(&[Architechture::Arm]), "R_ARM_V4BX"),
+    (0x29, Arch(&[Architechture::Arm]), "R_ARM_TARGET2"),
+    (0x2a, Arch(&[Architechture::Arm]), "R_ARM_PREL31"),
+    (0x2b, Arch(&[Architechture::Arm]), "R_ARM_MOVW_ABS_NC"),
+    (0x2c, Arch(&[Architechture::Arm]), "R_ARM_MOVT_ABS"),
+    (0x2d, Arch(&[Architechture::Arm]), "R_ARM_MOVW_PREL_NC"),
+    (0x2e, Arch(&[Architechture::Arm]), "R_ARM_MOVT_PREL"),
+    (0x2f, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVW_ABS_NC"),
+    (0x30, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVT_ABS"),
+    (0x31, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVW_PREL_NC"),
+    (0x32, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVT_PREL"),
+    (0x33, Arch(&[Architechture::Arm]), "R_ARM_THM_JUMP19"),
+    (0x34, Arch(&[Architechture::Arm]), "R_ARM_THM_JUMP6"),
+    (0x35, Arch(&[Architechture::Arm]), "R_ARM_THM_ALU_PREL_11_0"),
+    (0x36, Arch(&[Architechture::Arm]), "R_ARM_THM_PC12"),
+    (0x37, Arch(&[Architechture::Arm]), "R_ARM_ABS32_NOI"),
+    (0x38, Arch(&[Architechture::Arm]), "R_ARM_REL32_NOI"),
+    (0x39, Arch(&[Architechture::Arm]), "R_ARM_ALU_PC_G0_NC"),
+    (0x3a, Arch(&[Architechture::Arm]), "R_ARM_ALU_PC_G0"),
+    (0x3b, Arch(&[Architechture::Arm]), "R_ARM_ALU_PC_G1_NC"),
+    (0x3c, Arch(&[Architechture::Arm]), "R_ARM_ALU_PC_G1"),
+    (0x3d, Arch(&[Architechture::Arm]), "R_ARM_ALU_PC_G2"),
+    (0x3e, Arch(&[Architechture::Arm]), "R_ARM_LDR_PC_G1"),
+    (0x3f, Arch(&[Architechture::Arm]), "R_ARM_LDR_PC_G2"),
+    (0x40, Arch(&[Architechture::Arm]), "R_ARM_LDRS_PC_G0"),
+    (0x41, Arch(&[Architechture::Arm]), "R_ARM_LDRS_PC_G1"),
+    (0x42, Arch(&[Architechture::Arm]), "R_ARM_LDRS_PC_G2"),
+    (0x43, Arch(&[Architechture::Arm]), "R_ARM_LDC_PC_G0"),
+    (0x44, Arch(&[Architechture::Arm]), "R_ARM_LDC_PC_G1"),
+    (0x45, Arch(&[Architechture::Arm]), "R_ARM_LDC_PC_G2"),
+    (0x46, Arch(&[Architechture::Arm]), "R_ARM_ALU_SB_G0_NC"),
+    (0x47, Arch(&[Architechture::Arm]), "R_ARM_ALU_SB_G0"),
+    (0x48, Arch(&[Architechture::Arm]), "R_ARM_ALU_SB_G1_NC"),
+    (0x49, Arch(&[Architechture::Arm]), "R_ARM_ALU_SB_G1"),
+    (0x4a, Arch(&[Architechture::Arm]), "R_ARM_ALU_SB_G2"),
+    (0x4b, Arch(&[Architechture::Arm]), "R_ARM_LDR_SB_G0"),
+    (0x4c, Arch(&[Architechture::Arm]), "R_ARM_LDR_SB_G1"),
+    (0x4d, Arch(&[Architechture::Arm]), "R_ARM_LDR_SB_G2"),
+    (0x4e, Arch(&[Architechture::Arm]), "R_ARM_LDRS_SB_G0"),
+    (0x4f, Arch(&[Architechture::Arm]), "R_ARM_LDRS_SB_G1"),
+    (0x50, Arch(&[Architechture::Arm]), "R_ARM_LDRS_SB_G2"),
+    (0x51, Arch(&[Architechture::Arm]), "R_ARM_LDC_SB_G0"),
+    (0x52, Arch(&[Architechture::Arm]), "R_ARM_LDC_SB_G1"),
+    (0x53, Arch(&[Architechture::Arm]), "R_ARM_LDC_SB_G2"),
+    (0x54, Arch(&[Architechture::Arm]), "R_ARM_MOVW_BREL_NC"),
+    (0x55, Arch(&[Architechture::Arm]), "R_ARM_MOVT_BREL"),
+    (0x56, Arch(&[Architechture::Arm]), "R_ARM_MOVW_BREL"),
+    (0x57, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVW_BREL_NC"),
+    (0x58, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVT_BREL"),
+    (0x59, Arch(&[Architechture::Arm]), "R_ARM_THM_MOVW_BREL"),
+    (0x5a, Arch(&[Architechture::Arm]), "R_ARM_TLS_GOTDESC"),
+    (0x5b, Arch(&[Architechture::Arm]), "R_ARM_TLS_CALL"),
+    (0x5c, Arch(&[Architechture::Arm]), "R_ARM_TLS_DESCSEQ"),
+    (0x5d, Arch(&[Architechture::Arm]), "R_ARM_THM_TLS_CALL"),
+    (0x5e, Arch(&[Architechture::Arm]), "R_ARM_PLT32_ABS"),
+    (0x5f, Arch(&[Architechture::Arm]), "R_ARM_GOT_ABS"),
+    (0x60, Arch(&[Architechture::Arm]), "R_ARM_GOT_PREL"),
+    (0x61, Arch(&[Architechture::Arm]), "R_ARM_GOT_BREL12"),
+    (0x62, Arch(&[Architechture::Arm]), "R_ARM_GOTOFF12"),
+    (0x63, Arch(&[Architechture::Arm]), "R_ARM_GOTRELAX"),
+    (0x64, Arch(&[Architechture::Arm]), "R_ARM_GNU_VTENTRY"),
+    (0x65, Arch(&[Architechture::Arm]), "R_ARM_GNU_VTINHERIT"),
+    (0x66, Arch(&[Architechture::Arm]), "R_ARM_THM_PC11"),
+    (0x67, Arch(&[Architechture::Arm]), "R_ARM_THM_PC9"),
+    (0x68, Arch(&[Architechture::Arm]), "R_ARM_TLS_GD32"),
+    (0x69, Arch(&[Architechture::Arm]), "R_ARM_TLS_LDM32"),
+    (0x6a, Arch(&[Architechture::Arm]), "R_ARM_TLS_LDO32"),
+    (0x6b, Arch(&[Architechture::Arm]), "R_ARM_TLS_IE32"),
+    (0x6c, Arch(&[Architechture::Arm]), "R_ARM_TLS_LE32"),
+    (0x6d, Arch(&[Architechture::Arm]), "R_ARM_TLS_LDO12"),
+    (0x6e, Arch(&[Architechture::Arm]), "R_ARM_TLS_LE12"),
+    (0x6f, Arch(&[Architechture::Arm]), "R_ARM_TLS_IE12GP"),
+    (0x80, Arch(&[Architechture::Arm]), "R_ARM_ME_TOO"),
+    (0x81, Arch(&[Architechture::Arm]), "R_ARM_THM_TLS_DESCSEQ"),
+    (0x82, Arch(&[Architechture::Arm]), "R_ARM_THM_TLS_DESCSEQ32"),
+    (0x83, Arch(&[Architechture::Arm]), "R_ARM_THM_GOT_BREL12"),
+    (0xa0, Arch(&[Architechture::Arm]), "R_ARM_IRELATIVE"),
+    (0xf9, Arch(&[Architechture::Arm]), "R_ARM_RXPC25"),
+    (0xfa, Arch(&[Architechture::Arm]), "R_ARM_RSBREL32"),
+    (0xfb, Arch(&[Architechture::Arm]), "R_ARM_THM_RPC22"),
+    (0xfc, Arch(&[Architechture::Arm]), "R_ARM_RREL32"),
+    (0xfd, Arch(&[Architechture::Arm]), "R_ARM_RABS22"),
+    (0xfe, Arch(&[Architechture::Arm]), "R_ARM_RPC24"),
+    (0xff, Arch(&[Architechture::Arm]), "R_ARM_RBASE"),
+    (0x0, Arch(&[Architechture::Riscv]), "R_RISCV_NONE"),
+    (0x1, Arch(&[Architechture::Riscv]), "R_RISCV_32"),
+    (0x2, Arch(&[Architechture::Riscv]), "R_RISCV_64"),
+    (0x3, Arch(&[Architechture::Riscv]), "R_RISCV_RELATIVE"),
+    (0x4, Arch(&[Architechture::Riscv]), "R_RISCV_COPY"),
+    (0x5, Arch(&[Architechture::Riscv]), "R_RISCV_JUMP_SLOT"),
+    (0x6, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_DTPMOD32"),
+    (0x7, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_DTPMOD64"),
+    (0x8, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_DTPREL32"),
+    (0x9, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_DTPREL64"),
+    (0xa, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_TPREL32"),
+    (0xb, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_TPREL64"),
+    (0x10, Arch(&[Architechture::Riscv]), "R_RISCV_BRANCH"),
+    (0x11, Arch(&[Architechture::Riscv]), "R_RISCV_JAL"),
+    (0x12, Arch(&[Architechture::Riscv]), "R_RISCV_CALL"),
+    (0x13, Arch(&[Architechture::Riscv]), "R_RISCV_CALL_PLT"),
+    (0x14, Arch(&[Architechture::Riscv]), "R_RISCV_GOT_HI20"),
+    (0x15, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_GOT_HI20"),
+    (0x16, Arch(&[Architechture::Riscv]), "R_RISCV_TLS_GD_HI20"),
+    (0x17, Arch(&[Architechture::Riscv]), "R_RISCV_PCREL_HI20"),
+    (0x18, Arch(&[Architechture::Riscv]), "R_RISCV_PCREL_LO12_I"),
+    (0x19, Arch(&[Architechture::Riscv]), "R_RISCV_PCREL_LO12_S"),
+    (0x1a, Arch(&[Architechture::Riscv]), "R_RISCV_HI20"),
+    (0x1b, Arch(&[Architechture::Riscv]), "R_RISCV_LO12_I"),
+    (0x1c, Arch(&[Architechture::Riscv]), "R_RISCV_LO12_S"),
+    (0x1d, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_HI20"),
+    (0x1e, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_LO12_I"),
+    (0x1f, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_LO12_S"),
+    (0x20, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_ADD"),
+    (0x21, Arch(&[Architechture::Riscv]), "R_RISCV_ADD8"),
+    (0x22, Arch(&[Architechture::Riscv]), "R_RISCV_ADD16"),
+    (0x23, Arch(&[Architechture::Riscv]), "R_RISCV_ADD32"),
+    (0x24, Arch(&[Architechture::Riscv]), "R_RISCV_ADD64"),
+    (0x25, Arch(&[Architechture::Riscv]), "R_RISCV_SUB8"),
+    (0x26, Arch(&[Architechture::Riscv]), "R_RISCV_SUB16"),
+    (0x27, Arch(&[Architechture::Riscv]), "R_RISCV_SUB32"),
+    (0x28, Arch(&[Architechture::Riscv]), "R_RISCV_SUB64"),
+    (0x29, Arch(&[Architechture::Riscv]), "R_RISCV_GNU_VTINHERIT"),
+    (0x2a, Arch(&[Architechture::Riscv]), "R_RISCV_GNU_VTENTRY"),
+    (0x2b, Arch(&[Architechture::Riscv]), "R_RISCV_ALIGN"),
+    (0x2c, Arch(&[Architechture::Riscv]), "R_RISCV_RVC_BRANCH"),
+    (0x2d, Arch(&[Architechture::Riscv]), "R_RISCV_RVC_JUMP"),
+    (0x2e, Arch(&[Architechture::Riscv]), "R_RISCV_RVC_LUI"),
+    (0x2f, Arch(&[Architechture::Riscv]), "R_RISCV_GPREL_I"),
+    (0x30, Arch(&[Architechture::Riscv]), "R_RISCV_GPREL_S"),
+    (0x31, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_I"),
+    (0x32, Arch(&[Architechture::Riscv]), "R_RISCV_TPREL_S"),
+    (0x33, Arch(&[Architechture::Riscv]), "R_RISCV_RELAX"),
+    (0x34, Arch(&[Architechture::Riscv]), "R_RISCV_SUB6"),
+    (0x35, Arch(&[Architechture::Riscv]), "R_RISCV_SET6"),
+    (0x36, Arch(&[Architechture::Riscv]), "R_RISCV_SET8"),
+    (0x37, Arch(&[Architechture::Riscv]), "R_RISCV_SET16"),
+    (0x38, Arch(&[Architechture::Riscv]), "R_RISCV_SET32"),
+    (0x39, Arch(&[Architechture::Riscv]), "R_RISCV_32_PCREL"),
+    (0x3a, Arch(&[Architechture::Riscv]), "R_RISCV_IRELATIVE"),
+    (0x0, Arch(&[Architechture::Ppc]), "R_PPC_NONE"),
+    (0x1, Arch(&[Architechture::Ppc]), "R_PPC_ADDR32"),
+    (0x2, Arch(&[Architechture::Ppc]), "R_PPC_ADDR24"),
+    (0x3, Arch(&[Architechture::Ppc]), "R_PPC_ADDR16"),
+    (0x4, Arch(&[Architechture::Ppc]), "R_PPC_ADDR16_LO"),
+    (0x5, Arch(&[Architechture::Ppc]), "R_PPC_ADDR16_HI"),
+    (0x6, Arch(&[Architechture::Ppc]), "R_PPC_ADDR16_HA"),
+    (0x7, Arch(&[Architechture::Ppc]), "R_PPC_ADDR14"),
+    (0x8, Arch(&[Architechture::Ppc]), "R_PPC_ADDR14_BRTAKEN"),
+    (0x9, Arch(&[Architechture::Ppc]), "R_PPC_ADDR14_BRNTAKEN"),
+    (0xa, Arch(&[Architechture::Ppc]), "R_PPC_REL24"),
+    (0xb, Arch(&[Architechture::Ppc]), "R_PPC_REL14"),
+    (0xc, Arch(&[Architechture::Ppc]), "R_PPC_REL14_BRTAKEN"),
+    (0xd, Arch(&[Architechture::Ppc]), "R_PPC_REL14_BRNTAKEN"),
+    (0xe, Arch(&[Architechture::Ppc]), "R_PPC_GOT16"),
+    (0xf, Arch(&[Architechture::Ppc]), "R_PPC_GOT16_LO"),
+    (0x10, Arch(&[Architechture::Ppc]), "R_PPC_GOT16_HI"),
+    (0x11, Arch(&[Architechture::Ppc]), "R_PPC_GOT16_HA"),
+    (0x12, Arch(&[Architechture::Ppc]), "R_PPC_PLTREL24"),
+    (0x13, Arch(&[Architechture::Ppc]), "R_PPC_COPY"),
+    (0x14, Arch(&[Architechture::Ppc]), "R_PPC_GLOB_DAT"),
+    (0x15, Arch(&[Architechture::Ppc]), "R_PPC_JMP_SLOT"),
+    (0x16, Arch(&[Architechture::Ppc]), "R_PPC_RELATIVE"),
+    (0x17, Arch(&[Architechture::Ppc]), "R_PPC_LOCAL24PC"),
+    (0x18, Arch(&[Architechture::Ppc]), "R_PPC_UADDR32"),
+    (0x19, Arch(&[Architechture::Ppc]), "R_PPC_UADDR16"),
+    (0x1a, Arch(&[Architechture::Ppc]), "R_PPC_REL32"),
+    (0x1b, Arch(&[Architechture::Ppc]), "R_PPC_PLT32"),
+    (0x1c, Arch(&[Architechture::Ppc]), "R_PPC_PLTREL32"),
+    (0x1d, Arch(&[Architechture::Ppc]), "R_PPC_PLT16_LO"),
+    (0x1e, Arch(&[Architechture::Ppc]), "R_PPC_PLT16_HI"),
+    (0x1f, Arch(&[Architechture::Ppc]), "R_PPC_PLT16_HA"),
+    (0x20, Arch(&[Architechture::Ppc]), "R_PPC_SDAREL16"),
+    (0x21, Arch(&[Architechture::Ppc]), "R_PPC_SECTOFF"),
+    (0x22, Arch(&[Architechture::Ppc]), "R_PPC_SECTOFF_LO"),
+    (0x23, Arch(&[Architechture::Ppc]), "R_PPC_SECTOFF_HI"),
+    (0x24, Arch(&[Architechture::Ppc]), "R_PPC_SECTOFF_HA"),
+    (0x43, Arch(&[Architechture::Ppc]), "R_PPC_TLS"),
+    (0x44, Arch(&[Architechture::Ppc]), "R_PPC_DTPMOD32"),
+    (0x45, Arch(&[Architechture::Ppc]), "R_PPC_TPREL16"),
+    (0x46, Arch(&[Architechture::Ppc]), "R_PPC_TPREL16_LO"),
+    (0x47, Arch(&[Architechture::Ppc]), "R_PPC_TPREL16_HI"),
+    (0x48, Arch(&[Architechture::Ppc]), "R_PPC_TPREL16_HA"),
+    (0x49, Arch(&[Architechture::Ppc]), "R_PPC_TPREL32"),
+    (0x4a, Arch(&[Architechture::Ppc]), "R_PPC_DTPREL16"),
+    (0x4b, Arch(&[Architechture::Ppc]), "R_PPC_DTPREL16_LO"),
+    (0x4c, Arch(&[Architechture::Ppc]), "R_PPC_DTPREL16_HI"),
+    (0x4d, Arch(&[Architechture::Ppc]), "R_PPC_DTPREL16_HA"),
+    (0x4e, Arch(&[Architechture::Ppc]), "R_PPC_DTPREL32"),
+    (0x4f, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSGD16"),
+    (0x50, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSGD16_LO"),
+    (0x51, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSGD16_HI"),
+    (0x52, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSGD16_HA"),
+    (0x53, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSLD16"),
+    (0x54, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSLD16_LO"),
+    (0x55, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSLD16_HI"),
+    (0x56, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TLSLD16_HA"),
+    (0x57, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TPREL16"),
+    (0x58, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TPREL16_LO"),
+    (0x59, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TPREL16_HI"),
+    (0x5a, Arch(&[Architechture::Ppc]), "R_PPC_GOT_TPREL16_HA"),
+    (0x5b, Arch(&[Architechture::Ppc]), "R_PPC_GOT_DTPREL16"),
+    (0x5c, Arch(&[Architechture::Ppc]), "R_PPC_GOT_DTPREL16_LO"),
+    (0x5d, Arch(&[Architechture::Ppc]), "R_PPC_GOT_DTPREL16_HI"),
+    (0x5e, Arch(&[Architechture::Ppc]), "R_PPC_GOT_DTPREL16_HA"),
+    (0x5f, Arch(&[Architechture::Ppc]), "R_PPC_TLSGD"),
+    (0x60, Arch(&[Architechture::Ppc]), "R_PPC_TLSLD"),
+    (0x65, Arch(&[Architechture::Ppc]), "R_PPC_EMB_NADDR32"),
+    (0x66, Arch(&[Architechture::Ppc]), "R_PPC_EMB_NADDR16"),
+    (0x67, Arch(&[Architechture::Ppc]), "R_PPC_EMB_NADDR16_LO"),
+    (0x68, Arch(&[Architechture::Ppc]), "R_PPC_EMB_NADDR16_HI"),
+    (0x69, Arch(&[Architechture::Ppc]), "R_PPC_EMB_NADDR16_HA"),
+    (0x6a, Arch(&[Architechture::Ppc]), "R_PPC_EMB_SDAI16"),
+    (0x6b, Arch(&[Architechture::Ppc]), "R_PPC_EMB_SDA2I16"),
+    (0x6c, Arch(&[Architechture::Ppc]), "R_PPC_EMB_SDA2REL"),
+    (0x6d, Arch(&[Architechture::Ppc]), "R_PPC_EMB_SDA21"),
+    (0x6e, Arch(&[Architechture::Ppc]), "R_PPC_EMB_MRKREF"),
+    (0x6f, Arch(&[Architechture::Ppc]), "R_PPC_EMB_RELSEC16"),
+    (0x70, Arch(&[Architechture::Ppc]), "R_PPC_EMB_RELST_LO"),
+    (0x71, Arch(&[Architechture::Ppc]), "R_PPC_EMB_RELST_HI"),
+    (0x72, Arch(&[Architechture::Ppc]), "R_PPC_EMB_RELST_HA"),
+    (0x73, Arch(&[Architechture::Ppc]), "R_PPC_EMB_BIT_FLD"),
+    (0x74, Arch(&[Architechture::Ppc]), "R_PPC_EMB_RELSDA"),
+    (0xb4, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_SDA21_LO"),
+    (0xb5, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_SDA21_HI"),
+    (0xb6, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_SDA21_HA"),
+    (0xb7, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_RELSDA_LO"),
+    (0xb8, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_RELSDA_HI"),
+    (0xb9, Arch(&[Architechture::Ppc]), "R_PPC_DIAB_RELSDA_HA"),
+    (0xf8, Arch(&[Architechture::Ppc]), "R_PPC_IRELATIVE"),
+    (0xf9, Arch(&[Architechture::Ppc]), "R_PPC_REL16"),
+    (0xfa, Arch(&[Architechture::Ppc]), "R_PPC_REL16_LO"),
+    (0xfb, Arch(&[Architechture::Ppc]), "R_PPC_REL16_HI"),
+    (0xfc, Arch(&[Architechture::Ppc]), "R_PPC_REL16_HA"),
+    (0xff, Arch(&[Architechture::Ppc]), "R_PPC_TOC16"),
+    (0x0, Arch(&[Architechture::Ppc64]), "R_PPC64_NONE"),
+    (0x1, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR32"),
+    (0x2, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR24"),
+    (0x3, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16"),
+    (0x4, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_LO"),
+    (0x5, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_HI"),
+    (0x6, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_HA"),
+    (0x7, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR14"),
+    (0x8, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR14_BRTAKEN"),
+    (
+        0x9,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_ADDR14_BRNTAKEN",
+    ),
+    (0xa, Arch(&[Architechture::Ppc64]), "R_PPC64_REL24"),
+    (0xb, Arch(&[Architechture::Ppc64]), "R_PPC64_REL14"),
+    (0xc, Arch(&[Architechture::Ppc64]), "R_PPC64_REL14_BRTAKEN"),
+    (0xd, Arch(&[Architechture::Ppc64]), "R_PPC64_REL14_BRNTAKEN"),
+    (0xe, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16"),
+    (0xf, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16_LO"),
+    (0x10, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16_HI"),
+    (0x11, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16_HA"),
+    (0x13, Arch(&[Architechture::Ppc64]), "R_PPC64_COPY"),
+    (0x14, Arch(&[Architechture::Ppc64]), "R_PPC64_GLOB_DAT"),
+    (0x15, Arch(&[Architechture::Ppc64]), "R_PPC64_JMP_SLOT"),
+    (0x16, Arch(&[Architechture::Ppc64]), "R_PPC64_RELATIVE"),
+    (0x18, Arch(&[Architechture::Ppc64]), "R_PPC64_UADDR32"),
+    (0x19, Arch(&[Architechture::Ppc64]), "R_PPC64_UADDR16"),
+    (0x1a, Arch(&[Architechture::Ppc64]), "R_PPC64_REL32"),
+    (0x1b, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT32"),
+    (0x1c, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTREL32"),
+    (0x1d, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT16_LO"),
+    (0x1e, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT16_HI"),
+    (0x1f, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT16_HA"),
+    (0x21, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF"),
+    (0x22, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF_LO"),
+    (0x23, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF_HI"),
+    (0x24, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF_HA"),
+    (0x25, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR30"),
+    (0x26, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR64"),
+    (0x27, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_HIGHER"),
+    (
+        0x28,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_ADDR16_HIGHERA",
+    ),
+    (
+        0x29,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_ADDR16_HIGHEST",
+    ),
+    (
+        0x2a,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_ADDR16_HIGHESTA",
+    ),
+    (0x2b, Arch(&[Architechture::Ppc64]), "R_PPC64_UADDR64"),
+    (0x2c, Arch(&[Architechture::Ppc64]), "R_PPC64_REL64"),
+    (0x2d, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT64"),
+    (0x2e, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTREL64"),
+    (0x2f, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16"),
+    (0x30, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16_LO"),
+    (0x31, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16_HI"),
+    (0x32, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16_HA"),
+    (0x33, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC"),
+    (0x34, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTGOT16"),
+    (0x35, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTGOT16_LO"),
+    (0x36, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTGOT16_HI"),
+    (0x37, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTGOT16_HA"),
+    (0x38, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_DS"),
+    (0x39, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_LO_DS"),
+    (0x3a, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16_DS"),
+    (0x3b, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT16_LO_DS"),
+    (0x3c, Arch(&[Architechture::Ppc64]), "R_PPC64_PLT16_LO_DS"),
+    (0x3d, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF_DS"),
+    (0x3e, Arch(&[Architechture::Ppc64]), "R_PPC64_SECTOFF_LO_DS"),
+    (0x3f, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16_DS"),
+    (0x40, Arch(&[Architechture::Ppc64]), "R_PPC64_TOC16_LO_DS"),
+    (0x41, Arch(&[Architechture::Ppc64]), "R_PPC64_PLTGOT16_DS"),
+    (
+        0x42,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_PLTGOT16_LO_DS",
+    ),
+    (0x43, Arch(&[Architechture::Ppc64]), "R_PPC64_TLS"),
+    (0x44, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPMOD64"),
+    (0x45, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16"),
+    (0x46, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_LO"),
+    (0x47, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_HI"),
+    (0x48, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_HA"),
+    (0x49, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL64"),
+    (0x4a, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16"),
+    (0x4b, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16_LO"),
+    (0x4c, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16_HI"),
+    (0x4d, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16_HA"),
+    (0x4e, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL64"),
+    (0x4f, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT_TLSGD16"),
+    (
+        0x50,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSGD16_LO",
+    ),
+    (
+        0x51,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSGD16_HI",
+    ),
+    (
+        0x52,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSGD16_HA",
+    ),
+    (0x53, Arch(&[Architechture::Ppc64]), "R_PPC64_GOT_TLSLD16"),
+    (
+        0x54,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSLD16_LO",
+    ),
+    (
+        0x55,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSLD16_HI",
+    ),
+    (
+        0x56,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TLSLD16_HA",
+    ),
+    (
+        0x57,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TPREL16_DS",
+    ),
+    (
+        0x58,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TPREL16_LO_DS",
+    ),
+    (
+        0x59,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TPREL16_HI",
+    ),
+    (
+        0x5a,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_TPREL16_HA",
+    ),
+    (
+        0x5b,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_DTPREL16_DS",
+    ),
+    (
+        0x5c,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_DTPREL16_LO_DS",
+    ),
+    (
+        0x5d,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_DTPREL16_HI",
+    ),
+    (
+        0x5e,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_GOT_DTPREL16_HA",
+    ),
+    (0x5f, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_DS"),
+    (0x60, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_LO_DS"),
+    (
+        0x61,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_TPREL16_HIGHER",
+    ),
+    (
+        0x62,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_TPREL16_HIGHERA",
+    ),
+    (
+        0x63,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_TPREL16_HIGHEST",
+    ),
+    (
+        0x64,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_TPREL16_HIGHESTA",
+    ),
+    (0x65, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16_DS"),
+    (
+        0x66,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_LO_DS",
+    ),
+    (
+        0x67,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_HIGHER",
+    ),
+    (
+        0x68,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_HIGHERA",
+    ),
+    (
+        0x69,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_HIGHEST",
+    ),
+    (
+        0x6a,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_HIGHESTA",
+    ),
+    (0x6b, Arch(&[Architechture::Ppc64]), "R_PPC64_TLSGD"),
+    (0x6c, Arch(&[Architechture::Ppc64]), "R_PPC64_TLSLD"),
+    (0x6d, Arch(&[Architechture::Ppc64]), "R_PPC64_TOCSAVE"),
+    (0x6e, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_HIGH"),
+    (0x6f, Arch(&[Architechture::Ppc64]), "R_PPC64_ADDR16_HIGHA"),
+    (0x70, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_HIGH"),
+    (0x71, Arch(&[Architechture::Ppc64]), "R_PPC64_TPREL16_HIGHA"),
+    (0x72, Arch(&[Architechture::Ppc64]), "R_PPC64_DTPREL16_HIGH"),
+    (
+        0x73,
+        Arch(&[Architechture::Ppc64]),
+        "R_PPC64_DTPREL16_HIGHA",
+    ),
+    (0xf7, Arch(&[Architechture::Ppc64]), "R_PPC64_JMP_IREL"),
+    (0xf8, Arch(&[Architechture::Ppc64]), "R_PPC64_IRELATIVE"),
+    (0xf9, Arch(&[Architechture::Ppc64]), "R_PPC64_REL16"),
+    (0xfa, Arch(&[Architechture::Ppc64]), "R_PPC64_REL16_LO"),
+    (0xfb, Arch(&[Architechture::Ppc64]), "R_PPC64_REL16_HI"),
+    (0xfc, Arch(&[Architechture::Ppc64]), "R_PPC64_REL16_HA"),
+    (0x0, Arch(&[Architechture::Mips]), "R_MIPS_NONE"),
+    (0x1, Arch(&[Architechture::Mips]), "R_MIPS_16"),
+    (0x2, Arch(&[Architechture::Mips]), "R_MIPS_32"),
+    (0x3, Arch(&[Architechture::Mips]), "R_MIPS_REL32"),
+    (0x4, Arch(&[Architechture::Mips]), "R_MIPS_26"),
+    (0x5, Arch(&[Architechture::Mips]), "R_MIPS_HI16"),
+    (0x6, Arch(&[Architechture::Mips]), "R_MIPS_LO16"),
+    (0x7, Arch(&[Architechture::Mips]), "R_MIPS_GPREL16"),
+    (0x8, Arch(&[Architechture::Mips]), "R_MIPS_LITERAL"),
+    (0x9, Arch(&[Architechture::Mips]), "R_MIPS_GOT16"),
+    (0xa, Arch(&[Architechture::Mips]), "R_MIPS_PC16"),
+    (0xb, Arch(&[Architechture::Mips]), "R_MIPS_CALL16"),
+    (0xc, Arch(&[Architechture::Mips]), "R_MIPS_GPREL32"),
+    (0x10, Arch(&[Architechture::Mips]), "R_MIPS_SHIFT5"),
+    (0x11, Arch(&[Architechture::Mips]), "R_MIPS_SHIFT6"),
+    (0x12, Arch(&[Architechture::Mips]), "R_MIPS_64"),
+    (0x13, Arch(&[Architechture::Mips]), "R_MIPS_GOT_DISP"),
+    (0x14, Arch(&[Architechture::Mips]), "R_MIPS_GOT_PAGE"),
+    (0x15, Arch(&[Architechture::Mips]), "R_MIPS_GOT_OFST"),
+    (0x16, Arch(&[Architechture::Mips]), "R_MIPS_GOT_HI16"),
+    (0x17, Arch(&[Architechture::Mips]), "R_MIPS_GOT_LO16"),
+    (0x18, Arch(&[Architechture::Mips]), "R_MIPS_SUB"),
+    (0x19, Arch(&[Architechture::Mips]), "R_MIPS_INSERT_A"),
+    (0x1a, Arch(&[Architechture::Mips]), "R_MIPS_INSERT_B"),
+    (0x1b, Arch(&[Architechture::Mips]), "R_MIPS_DELETE"),
+    (0x1c, Arch(&[Architechture::Mips]), "R_MIPS_HIGHER"),
+    (0x1d, Arch(&[Architechture::Mips]), "R_MIPS_HIGHEST"),
+    (0x1e, Arch(&[Architechture::Mips]), "R_MIPS_CALL_HI16"),
+    (0x1f, Arch(&[Architechture::Mips]), "R_MIPS_CALL_LO16"),
+    (0x20, Arch(&[Architechture::Mips]), "R_MIPS_SCN_DISP"),
+    (0x21, Arch(&[Architechture::Mips]), "R_MIPS_REL16"),
+    (0x22, Arch(&[Architechture::Mips]), "R_MIPS_ADD_IMMEDIATE"),
+    (0x23, Arch(&[Architechture::Mips]), "R_MIPS_PJUMP"),
+    (0x24, Arch(&[Architechture::Mips]), "R_MIPS_RELGOT"),
+    (0x25, Arch(&[Architechture::Mips]), "R_MIPS_JALR"),
+    (0x26, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPMOD32"),
+    (0x27, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPREL32"),
+    (0x28, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPMOD64"),
+    (0x29, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPREL64"),
+    (0x2a, Arch(&[Architechture::Mips]), "R_MIPS_TLS_GD"),
+    (0x2b, Arch(&[Architechture::Mips]), "R_MIPS_TLS_LDM"),
+    (0x2c, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPREL_HI16"),
+    (0x2d, Arch(&[Architechture::Mips]), "R_MIPS_TLS_DTPREL_LO16"),
+    (0x2e, Arch(&[Architechture::Mips]), "R_MIPS_TLS_GOTTPREL"),
+    (0x2f, Arch(&[Architechture::Mips]), "R_MIPS_TLS_TPREL32"),
+    (0x30, Arch(&[Architechture::Mips]), "R_MIPS_TLS_TPREL64"),
+    (0x31, Arch(&[Architechture::Mips]), "R_MIPS_TLS_TPREL_HI16"),
+    (0x32, Arch(&[Architechture::Mips]), "R_MIPS_TLS_TPREL_LO16"),
+    (0x33, Arch(&[Architechture::Mips]), "R_MIPS_GLOB_DAT"),
+    (0x7e, Arch(&[Architechture::Mips]), "R_MIPS_COPY"),
+    (0x7f, Arch(&[Architechture::Mips]), "R_MIPS_JUMP_SLOT"),
+    (0x0, Arch(&[Architechture::S390]), "R_390_NONE"),
+    (0x1, Arch(&[Architechture::S390]), "R_390_8"),
+    (0x2, Arch(&[Architechture::S390]), "R_390_12"),
+    (0x3, Arch(&[Architechture::S390]), "R_390_16"),
+    (0x4, Arch(&[Architechture::S390]), "R_390_32"),
+    (0x5, Arch(&[Architechture::S390]), "R_390_PC32"),
+    (0x6, Arch(&[Architechture::S390]), "R_390_GOT12"),
+    (0x7, Arch(&[Architechture::S390]), "R_390_GOT32"),
+    (0x8, Arch(&[Architechture::S390]), "R_390_PLT32"),
+    (0x9, Arch(&[Architechture::S390]), "R_390_COPY"),
+    (0xa, Arch(&[Architechture::S390]), "R_390_GLOB_DAT"),
+    (0xb, Arch(&[Architechture::S390]), "R_390_JMP_SLOT"),
+    (0xc, Arch(&[Architechture::S390]), "R_390_RELATIVE"),
+    (0xd, Arch(&[Architechture::S390]), "R_390_GOTOFF32"),
+    (0xe, Arch(&[Architechture::S390]), "R_390_GOTPC"),
+    (0xf, Arch(&[Architechture::S390]), "R_390_GOT16"),
+    (0x10, Arch(&[Architechture::S390]), "R_390_PC16"),
+    (0x11, Arch(&[Architechture::S390]), "R_390_PC16DBL"),
+    (0x12, Arch(&[Architechture::S390]), "R_390_PLT16DBL"),
+    (0x13, Arch(&[Architechture::S390]), "R_390_PC32DBL"),
+    (0x14, Arch(&[Architechture::S390]), "R_390_PLT32DBL"),
+    (0x15, Arch(&[Architechture::S390]), "R_390_GOTPCDBL"),
+    (0x16, Arch(&[Architechture::S390]), "R_390_64"),
+    (0x17, Arch(&[Architechture::S390]), "R_390_PC64"),
+    (0x18, Arch(&[Architechture::S390]), "R_390_GOT64"),
+    (0x19, Arch(&[Architechture::S390]), "R_390_PLT64"),
+    (0x1a, Arch(&[Architechture::S390]), "R_390_GOTENT"),
+    (0x1b, Arch(&[Architechture::S390]), "R_390_GOTOFF16"),
+    (0x1c, Arch(&[Architechture::S390]), "R_390_GOTOFF64"),
+    (0x1d, Arch(&[Architechture::S390]), "R_390_GOTPLT12"),
+    (0x1e, Arch(&[Architechture::S390]), "R_390_GOTPLT16"),
+    (0x1f, Arch(&[Architechture::S390]), "R_390_GOTPLT32"),
+    (0x20, Arch(&[Architechture::S390]), "R_390_GOTPLT64"),
+    (0x21, Arch(&[Architechture::S390]), "R_390_GOTPLTENT"),
+    (0x22, Arch(&[Architechture::S390]), "R_390_PLTOFF16"),
+    (0x23, Arch(&[Architechture::S390]), "R_390_PLTOFF32"),
+    (0x24, Arch(&[Architechture::S390]), "R_390_PLTOFF64"),
+    (0x25, Arch(&[Architechture::S390]), "R_390_TLS_LOAD"),
+    (0x26, Arch(&[Architechture::S390]), "R_390_TLS_GDCALL"),
+    (0x27, Arch(&[Architechture::S390]), "R_390_TLS_LDCALL"),
+    (0x28, Arch(&[Architechture::S390]), "R_390_TLS_GD32"),
+    (0x29, Arch(&[Architechture::S390]), "R_390_TLS_GD64"),
+    (0x2a, Arch(&[Architechture::S390]), "R_390_TLS_GOTIE12"),
+    (0x2b, Arch(&[Architechture::S390]), "R_390_TLS_GOTIE32"),
+    (0x2c, Arch(&[Architechture::S390]), "R_390_TLS_GOTIE64"),
+    (0x2d, Arch(&[Architechture::S390]), "R_390_TLS_LDM32"),
+    (0x2e, Arch(&[Architechture::S390]), "R_390_TLS_LDM64"),
+    (0x2f, Arch(&[Architechture::S390]), "R_390_TLS_IE32"),
+    (0x30, Arch(&[Architechture::S390]), "R_390_TLS_IE64"),
+    (0x31, Arch(&[Architechture::S390]), "R_390_TLS_IEENT"),
+    (0x32, Arch(&[Architechture::S390]), "R_390_TLS_LE32"),
+    (0x33, Arch(&[Architechture::S390]), "R_390_TLS_LE64"),
+    (0x34, Arch(&[Architechture::S390]), "R_390_TLS_LDO32"),
+    (0x35, Arch(&[Architechture::S390]), "R_390_TLS_LDO64"),
+    (0x36, Arch(&[Architechture::S390]), "R_390_TLS_DTPMOD"),
+    (0x37, Arch(&[Architechture::S390]), "R_390_TLS_DTPOFF"),
+    (0x38, Arch(&[Architechture::S390]), "R_390_TLS_TPOFF"),
+    (0x39, Arch(&[Architechture::S390]), "R_390_20"),
+    (0x3a, Arch(&[Architechture::S390]), "R_390_GOT20"),
+    (0x3b, Arch(&[Architechture::S390]), "R_390_GOTPLT20"),
+    (0x3c, Arch(&[Architechture::S390]), "R_390_TLS_GOTIE20"),
+    (0x3d, Arch(&[Architechture::S390]), "R_390_IRELATIVE"),
+    (
+        0x0,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_NONE",
+    ),
+    (
+        0x1,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_8",
+    ),
+    (
+        0x2,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_16",
+    ),
+    (
+        0x3,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_32",
+    ),
+    (
+        0x4,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_DISP8",
+    ),
+    (
+        0x5,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_DISP16",
+    ),
+    (
+        0x6,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_DISP32",
+    ),
+    (
+        0x7,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WDISP30",
+    ),
+    (
+        0x8,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WDISP22",
+    ),
+    (
+        0x9,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_HI22",
+    ),
+    (
+        0xa,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_22",
+    ),
+    (
+        0xb,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_13",
+    ),
+    (
+        0xc,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_LO10",
+    ),
+    (
+        0xd,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOT10",
+    ),
+    (
+        0xe,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOT13",
+    ),
+    (
+        0xf,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOT22",
+    ),
+    (
+        0x10,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PC10",
+    ),
+    (
+        0x11,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PC22",
+    ),
+    (
+        0x12,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WPLT30",
+    ),
+    (
+        0x13,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_COPY",
+    ),
+    (
+        0x14,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GLOB_DAT",
+    ),
+    (
+        0x15,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_JMP_SLOT",
+    ),
+    (
+        0x16,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_RELATIVE",
+    ),
+    (
+        0x17,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_UA32",
+    ),
+    (
+        0x18,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PLT32",
+    ),
+    (
+        0x19,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_HIPLT22",
+    ),
+    (
+        0x1a,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_LOPLT10",
+    ),
+    (
+        0x1b,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PCPLT32",
+    ),
+    (
+        0x1c,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PCPLT22",
+    ),
+    (
+        0x1d,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PCPLT10",
+    ),
+    (
+        0x1e,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_10",
+    ),
+    (
+        0x1f,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_11",
+    ),
+    (
+        0x20,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_64",
+    ),
+    (
+        0x21,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_OLO10",
+    ),
+    (
+        0x22,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_HH22",
+    ),
+    (
+        0x23,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_HM10",
+    ),
+    (
+        0x24,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_LM22",
+    ),
+    (
+        0x25,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PC_HH22",
+    ),
+    (
+        0x26,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PC_HM10",
+    ),
+    (
+        0x27,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PC_LM22",
+    ),
+    (
+        0x28,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WDISP16",
+    ),
+    (
+        0x29,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WDISP19",
+    ),
+    (
+        0x2a,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GLOB_JMP",
+    ),
+    (
+        0x2b,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_7",
+    ),
+    (
+        0x2c,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_5",
+    ),
+    (
+        0x2d,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_6",
+    ),
+    (
+        0x2e,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_DISP64",
+    ),
+    (
+        0x2f,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_PLT64",
+    ),
+    (
+        0x30,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_HIX22",
+    ),
+    (
+        0x31,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_LOX10",
+    ),
+    (
+        0x32,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_H44",
+    ),
+    (
+        0x33,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_M44",
+    ),
+    (
+        0x34,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_L44",
+    ),
+    (
+        0x35,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_REGISTER",
+    ),
+    (
+        0x36,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_UA64",
+    ),
+    (
+        0x37,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_UA16",
+    ),
+    (
+        0x38,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_GD_HI22",
+    ),
+    (
+        0x39,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_GD_LO10",
+    ),
+    (
+        0x3a,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_GD_ADD",
+    ),
+    (
+        0x3b,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_GD_CALL",
+    ),
+    (
+        0x3c,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDM_HI22",
+    ),
+    (
+        0x3d,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDM_LO10",
+    ),
+    (
+        0x3e,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDM_ADD",
+    ),
+    (
+        0x3f,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDM_CALL",
+    ),
+    (
+        0x40,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDO_HIX22",
+    ),
+    (
+        0x41,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDO_LOX10",
+    ),
+    (
+        0x42,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LDO_ADD",
+    ),
+    (
+        0x43,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_IE_HI22",
+    ),
+    (
+        0x44,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_IE_LO10",
+    ),
+    (
+        0x45,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_IE_LD",
+    ),
+    (
+        0x46,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_IE_LDX",
+    ),
+    (
+        0x47,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_IE_ADD",
+    ),
+    (
+        0x48,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LE_HIX22",
+    ),
+    (
+        0x49,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_LE_LOX10",
+    ),
+    (
+        0x4a,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_DTPMOD32",
+    ),
+    (
+        0x4b,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_DTPMOD64",
+    ),
+    (
+        0x4c,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_DTPOFF32",
+    ),
+    (
+        0x4d,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_DTPOFF64",
+    ),
+    (
+        0x4e,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_TPOFF32",
+    ),
+    (
+        0x4f,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_TLS_TPOFF64",
+    ),
+    (
+        0x50,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOTDATA_HIX22",
+    ),
+    (
+        0x51,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOTDATA_LOX10",
+    ),
+    (
+        0x52,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOTDATA_OP_HIX22",
+    ),
+    (
+        0x53,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOTDATA_OP_LOX10",
+    ),
+    (
+        0x54,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GOTDATA_OP",
+    ),
+    (
+        0x55,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_H34",
+    ),
+    (
+        0x56,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_SIZE32",
+    ),
+    (
+        0x57,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_SIZE64",
+    ),
+    (
+        0x58,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_WDISP10",
+    ),
+    (
+        0xf8,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_JMP_IREL",
+    ),
+    (
+        0xf9,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_IRELATIVE",
+    ),
+    (
+        0xfa,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GNU_VTINHERIT",
+    ),
+    (
+        0xfb,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_GNU_VTENTRY",
+    ),
+    (
+        0xfc,
+        Arch(&[
+            Architechture::Sparc,
+            Architechture::Sparc32Plus,
+            Architechture::Sparcv9,
+        ]),
+        "R_SPARC_REV32",
+    ),
+    (0x0, Arch(&[Architechture::Loongarch]), "R_LARCH_NONE"),
+    (0x1, Arch(&[Architechture::Loongarch]), "R_LARCH_32"),
+    (0x2, Arch(&[Architechture::Loongarch]), "R_LARCH_64"),
+    (0x3, Arch(&[Architechture::Loongarch]), "R_LARCH_RELATIVE"),
+    (0x4, Arch(&[Architechture::Loongarch]), "R_LARCH_COPY"),
+    (0x5, Arch(&[Architechture::Loongarch]), "R_LARCH_JUMP_SLOT"),
+    (
+        0x6,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_DTPMOD32",
+    ),
+    (
+        0x7,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_DTPMOD64",
+    ),
+    (
+        0x8,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_DTPREL32",
+    ),
+    (
+        0x9,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_DTPREL64",
+    ),
+    (
+        0xa,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_TPREL32",
+    ),
+    (
+        0xb,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_TLS_TPREL64",
+    ),
+    (0xc, Arch(&[Architechture::Loongarch]), "R_LARCH_IRELATIVE"),
+    (0x14, Arch(&[Architechture::Loongarch]), "R_LARCH_MARK_LA"),
+    (
+        0x15,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_MARK_PCREL",
+    ),
+    (
+        0x16,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_PCREL",
+    ),
+    (
+        0x17,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_ABSOLUTE",
+    ),
+    (
+        0x18,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_DUP",
+    ),
+    (
+        0x19,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_GPREL",
+    ),
+    (
+        0x1a,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_TLS_TPREL",
+    ),
+    (
+        0x1b,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_TLS_GOT",
+    ),
+    (
+        0x1c,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_TLS_GD",
+    ),
+    (
+        0x1d,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_PUSH_PLT_PCREL",
+    ),
+    (
+        0x1e,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_ASSERT",
+    ),
+    (0x1f, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_NOT"),
+    (0x20, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_SUB"),
+    (0x21, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_SL"),
+    (0x22, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_SR"),
+    (0x23, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_ADD"),
+    (0x24, Arch(&[Architechture::Loongarch]), "R_LARCH_SOP_AND"),
+    (
+        0x25,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_IF_ELSE",
+    ),
+    (
+        0x26,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_10_5",
+    ),
+    (
+        0x27,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_U_10_12",
+    ),
+    (
+        0x28,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_10_12",
+    ),
+    (
+        0x29,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_10_16",
+    ),
+    (
+        0x2a,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_10_16_S2",
+    ),
+    (
+        0x2b,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_5_20",
+    ),
+    (
+        0x2c,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_0_5_10_16_S2",
+    ),
+    (
+        0x2d,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_S_0_10_10_16_S2",
+    ),
+    (
+        0x2e,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_SOP_POP_32_U",
+    ),
+    (0x2f, Arch(&[Architechture::Loongarch]), "R_LARCH_ADD8"),
+    (0x30, Arch(&[Architechture::Loongarch]), "R_LARCH_ADD16"),
+    (0x31, Arch(&[Architechture::Loongarch]), "R_LARCH_ADD24"),
+    (0x32, Arch(&[Architechture::Loongarch]), "R_LARCH_ADD32"),
+    (0x33, Arch(&[Architechture::Loongarch]), "R_LARCH_ADD64"),
+    (0x34, Arch(&[Architechture::Loongarch]), "R_LARCH_SUB8"),
+    (0x35, Arch(&[Architechture::Loongarch]), "R_LARCH_SUB16"),
+    (0x36, Arch(&[Architechture::Loongarch]), "R_LARCH_SUB24"),
+    (0x37, Arch(&[Architechture::Loongarch]), "R_LARCH_SUB32"),
+    (0x38, Arch(&[Architechture::Loongarch]), "R_LARCH_SUB64"),
+    (
+        0x39,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_GNU_VTINHERIT",
+    ),
+    (
+        0x3a,
+        Arch(&[Architechture::Loongarch]),
+        "R_LARCH_GNU_VTENTRY",
+    ),
+];
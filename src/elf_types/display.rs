@@ -0,0 +1,563 @@
+//! readelf style display of elf types.
+//!
+//! the meaning of some values depends on the architechture and os abi of the elf file, so the `Display`
+//! implementations of these types only use the names which are valid in all files, and `display_for` should be used to
+//! display them in the context of a specific file.
+
+use core::fmt;
+
+use super::{
+    Architechture, ElfFileInfo, MaybeUnknown, OsAbi, ProgramHeaderFlags, SectionHeaderFlags,
+    SectionHeaderFlagsU32, SymbolBinding, SymbolType, SymbolVisibility,
+};
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_MERGE: u64 = 0x10;
+const SHF_STRINGS: u64 = 0x20;
+const SHF_INFO_LINK: u64 = 0x40;
+const SHF_LINK_ORDER: u64 = 0x80;
+const SHF_OS_NONCONFORMING: u64 = 0x100;
+const SHF_GROUP: u64 = 0x200;
+const SHF_TLS: u64 = 0x400;
+const SHF_COMPRESSED: u64 = 0x800;
+const SHF_GNU_RETAIN: u64 = 0x200000;
+const SHF_GNU_MBIND: u64 = 0x1000000;
+const SHF_X86_64_LARGE: u64 = 0x10000000;
+const SHF_PPC_VLE: u64 = 0x10000000;
+const SHF_ARM_PURECODE: u64 = 0x20000000;
+const SHF_EXCLUDE: u64 = 0x80000000;
+const SHF_MASKOS: u64 = 0x0ff00000;
+const SHF_MASKPROC: u64 = 0xf0000000;
+
+const STT_LOOS: u8 = 10;
+const STT_HIOS: u8 = 12;
+const STT_LOPROC: u8 = 13;
+const STT_HIPROC: u8 = 15;
+const STB_LOOS: u8 = 10;
+const STB_HIOS: u8 = 12;
+const STB_LOPROC: u8 = 13;
+const STB_HIPROC: u8 = 15;
+
+/// a type whose readelf style display depends on the architechture and os abi of the elf file.
+pub trait DisplayForFile {
+    /// formats the value in the context of the given file, or using only the names which are valid in all files if no
+    /// file is given.
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result;
+
+    /// returns a wrapper which displays the value in the context of the given file.
+    fn display_for<'a>(&'a self, file_info: &'a ElfFileInfo) -> DisplayFor<'a, Self> {
+        DisplayFor {
+            value: self,
+            file_info,
+        }
+    }
+}
+
+/// displays a value in the context of an elf file. created using `DisplayForFile::display_for`.
+#[derive(Clone, Copy)]
+pub struct DisplayFor<'a, T: ?Sized> {
+    value: &'a T,
+    file_info: &'a ElfFileInfo,
+}
+impl<'a, T: DisplayForFile + ?Sized> fmt::Display for DisplayFor<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_for_file(f, Some(self.file_info))
+    }
+}
+
+macro_rules! impl_display_using_display_for_file {
+    {$($ty: ty),+ $(,)?} => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.fmt_for_file(f, None)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_display_for_file_of_known_value {
+    {$($ty: ty),+ $(,)?} => {
+        $(
+            impl DisplayForFile for $ty {
+                fn fmt_for_file(&self, f: &mut fmt::Formatter<'_>, file_info: Option<&ElfFileInfo>) -> fmt::Result {
+                    MaybeUnknown::Known(*self).fmt_for_file(f, file_info)
+                }
+            }
+        )+
+    };
+}
+
+fn arch_of(file_info: Option<&ElfFileInfo>) -> Option<Architechture> {
+    file_info.and_then(|file_info| file_info.arch.known())
+}
+
+fn os_abi_of(file_info: Option<&ElfFileInfo>) -> Option<OsAbi> {
+    file_info.and_then(|file_info| file_info.os_abi.known())
+}
+
+/// displayed as the flag letters used by `readelf -S`, for example `WAX`.
+impl DisplayForFile for SectionHeaderFlags {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let arch = arch_of(file_info);
+        let os_abi = os_abi_of(file_info).unwrap_or(OsAbi::Sysv);
+        let mut remaining = self.bits();
+        while remaining != 0 {
+            let flag = remaining & remaining.wrapping_neg();
+            remaining &= !flag;
+            let letter = match flag {
+                SHF_WRITE => 'W',
+                SHF_ALLOC => 'A',
+                SHF_EXECINSTR => 'X',
+                SHF_MERGE => 'M',
+                SHF_STRINGS => 'S',
+                SHF_INFO_LINK => 'I',
+                SHF_LINK_ORDER => 'L',
+                SHF_OS_NONCONFORMING => 'O',
+                SHF_GROUP => 'G',
+                SHF_TLS => 'T',
+                SHF_EXCLUDE => 'E',
+                SHF_COMPRESSED => 'C',
+                SHF_X86_64_LARGE
+                    if matches!(
+                        arch,
+                        Some(Architechture::X8664 | Architechture::L1Om | Architechture::K1Om)
+                    ) =>
+                {
+                    'l'
+                }
+                SHF_ARM_PURECODE if arch == Some(Architechture::Arm) => 'y',
+                SHF_PPC_VLE if arch == Some(Architechture::Ppc) => 'v',
+                SHF_GNU_RETAIN if matches!(os_abi, OsAbi::Gnu | OsAbi::Freebsd) => 'R',
+                SHF_GNU_MBIND if matches!(os_abi, OsAbi::Gnu | OsAbi::Freebsd | OsAbi::Sysv) => 'D',
+                _ if flag & SHF_MASKOS != 0 => {
+                    // all other os specific flags are displayed as a single letter.
+                    remaining &= !SHF_MASKOS;
+                    'o'
+                }
+                _ if flag & SHF_MASKPROC != 0 => {
+                    remaining &= !SHF_MASKPROC;
+                    'p'
+                }
+                _ => 'x',
+            };
+            write!(f, "{}", letter)?;
+        }
+        Ok(())
+    }
+}
+
+impl DisplayForFile for SectionHeaderFlagsU32 {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        SectionHeaderFlags::from(*self).fmt_for_file(f, file_info)
+    }
+}
+
+/// displayed as the flags column of `readelf -l`, for example `R E`.
+impl DisplayForFile for ProgramHeaderFlags {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        _file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let letter = |flag, letter| if self.contains(flag) { letter } else { ' ' };
+        write!(
+            f,
+            "{}{}{}",
+            letter(ProgramHeaderFlags::R, 'R'),
+            letter(ProgramHeaderFlags::W, 'W'),
+            letter(ProgramHeaderFlags::X, 'E')
+        )
+    }
+}
+
+impl DisplayForFile for MaybeUnknown<SymbolType> {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let arch = arch_of(file_info);
+        let os_abi = os_abi_of(file_info);
+        let raw = self.raw();
+        let name = match raw {
+            0 => "NOTYPE",
+            1 => "OBJECT",
+            2 => "FUNC",
+            3 => "SECTION",
+            4 => "FILE",
+            5 => "COMMON",
+            6 => "TLS",
+            8 => "RELC",
+            9 => "SRELC",
+            10 if matches!(os_abi, Some(OsAbi::Gnu | OsAbi::Freebsd)) => "IFUNC",
+            11 if arch == Some(Architechture::Parisc) => "HP_OPAQUE",
+            12 if arch == Some(Architechture::Parisc) => "HP_STUB",
+            13 if arch == Some(Architechture::Arm) => "THUMB_FUNC",
+            13 if arch == Some(Architechture::Sparcv9) => "REGISTER",
+            13 if arch == Some(Architechture::Parisc) => "PARISC_MILLI",
+            STT_LOOS..=STT_HIOS => return write!(f, "<OS specific>: {}", raw),
+            STT_LOPROC..=STT_HIPROC => return write!(f, "<processor specific>: {}", raw),
+            _ => return write!(f, "<unknown>: {}", raw),
+        };
+        f.write_str(name)
+    }
+}
+
+impl DisplayForFile for MaybeUnknown<SymbolBinding> {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let raw = self.raw();
+        let name = match raw {
+            0 => "LOCAL",
+            1 => "GLOBAL",
+            2 => "WEAK",
+            10 if os_abi_of(file_info) == Some(OsAbi::Gnu) => "UNIQUE",
+            STB_LOOS..=STB_HIOS => return write!(f, "<OS specific>: {}", raw),
+            STB_LOPROC..=STB_HIPROC => return write!(f, "<processor specific>: {}", raw),
+            _ => return write!(f, "<unknown>: {}", raw),
+        };
+        f.write_str(name)
+    }
+}
+
+impl DisplayForFile for SymbolVisibility {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        _file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        f.write_str(match self {
+            SymbolVisibility::Default => "DEFAULT",
+            SymbolVisibility::Internal => "INTERNAL",
+            SymbolVisibility::Hidden => "HIDDEN",
+            SymbolVisibility::Protected => "PROTECTED",
+        })
+    }
+}
+
+/// displayed as the machine name used by `readelf -h`, for example `Advanced Micro Devices X86-64`.
+impl DisplayForFile for MaybeUnknown<Architechture> {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        _file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let raw = self.raw();
+        match MACHINE_NAMES.iter().find(|(value, _)| *value == raw) {
+            Some((_, name)) => f.write_str(name),
+            None => write!(f, "<unknown>: {:#x}", raw),
+        }
+    }
+}
+
+/// displayed as the os/abi name used by `readelf -h`, for example `UNIX - GNU`.
+impl DisplayForFile for MaybeUnknown<OsAbi> {
+    fn fmt_for_file(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        file_info: Option<&ElfFileInfo>,
+    ) -> fmt::Result {
+        let raw = self.raw();
+        let name = match (raw, arch_of(file_info)) {
+            (0, _) => "UNIX - System V",
+            (1, _) => "UNIX - HP-UX",
+            (2, _) => "UNIX - NetBSD",
+            (3, _) => "UNIX - GNU",
+            (6, _) => "UNIX - Solaris",
+            (7, _) => "UNIX - AIX",
+            (8, _) => "UNIX - IRIX",
+            (9, _) => "UNIX - FreeBSD",
+            (10, _) => "UNIX - TRU64",
+            (11, _) => "Novell - Modesto",
+            (12, _) => "UNIX - OpenBSD",
+            (13, _) => "VMS - OpenVMS",
+            (14, _) => "HP - Non-Stop Kernel",
+            (15, _) => "AROS",
+            (16, _) => "FenixOS",
+            (17, _) => "Nuxi CloudABI",
+            (18, _) => "Stratus Technologies OpenVOS",
+            (65, Some(Architechture::Arm)) => "ARM FDPIC",
+            (97, Some(Architechture::Arm)) => "ARM",
+            (64, Some(Architechture::TiC6000)) => "Bare-metal C6000",
+            (65, Some(Architechture::TiC6000)) => "Linux C6000",
+            (64, Some(Architechture::Amdgpu)) => "AMD HSA",
+            (65, Some(Architechture::Amdgpu)) => "AMD PAL",
+            (66, Some(Architechture::Amdgpu)) => "AMD Mesa3D",
+            (
+                255,
+                Some(Architechture::Msp430 | Architechture::Msp430Old | Architechture::Visium),
+            ) => "Standalone App",
+            _ => return write!(f, "<unknown: {:x}>", raw),
+        };
+        f.write_str(name)
+    }
+}
+
+impl_display_for_file_of_known_value! {SymbolType, SymbolBinding, Architechture, OsAbi}
+
+impl_display_using_display_for_file! {
+    SectionHeaderFlags,
+    SectionHeaderFlagsU32,
+    ProgramHeaderFlags,
+    MaybeUnknown<SymbolType>,
+    MaybeUnknown<SymbolBinding>,
+    MaybeUnknown<Architechture>,
+    MaybeUnknown<OsAbi>,
+    SymbolType,
+    SymbolBinding,
+    SymbolVisibility,
+    Architechture,
+    OsAbi,
+}
+
+/// the machine names used by readelf.
+const MACHINE_NAMES: &[(u16, &str)] = &[
+    (0x0, "None"),
+    (0x1, "WE32100"),
+    (0x2, "Sparc"),
+    (0x3, "Intel 80386"),
+    (0x4, "MC68000"),
+    (0x5, "MC88000"),
+    (0x6, "Intel MCU"),
+    (0x7, "Intel 80860"),
+    (0x8, "MIPS R3000"),
+    (0x9, "IBM System/370"),
+    (0xa, "MIPS R4000 big-endian"),
+    (0xb, "Sparc v9 (old)"),
+    (0xf, "HPPA"),
+    (0x11, "Fujitsu VPP500"),
+    (0x12, "Sparc v8+"),
+    (0x13, "Intel 80960"),
+    (0x14, "PowerPC"),
+    (0x15, "PowerPC64"),
+    (0x16, "IBM S/390"),
+    (0x17, "SPU"),
+    (0x24, "Renesas V850 (using RH850 ABI)"),
+    (0x25, "Fujitsu FR20"),
+    (0x26, "TRW RH32"),
+    (0x27, "MCORE"),
+    (0x28, "ARM"),
+    (0x29, "Digital Alpha (old)"),
+    (0x2a, "Renesas / SuperH SH"),
+    (0x2b, "Sparc v9"),
+    (0x2c, "Siemens Tricore"),
+    (0x2d, "ARC"),
+    (0x2e, "Renesas H8/300"),
+    (0x2f, "Renesas H8/300H"),
+    (0x30, "Renesas H8S"),
+    (0x31, "Renesas H8/500"),
+    (0x32, "Intel IA-64"),
+    (0x33, "Stanford MIPS-X"),
+    (0x34, "Motorola Coldfire"),
+    (0x35, "Motorola MC68HC12 Microcontroller"),
+    (0x36, "Fujitsu Multimedia Accelerator"),
+    (0x37, "Siemens PCP"),
+    (0x38, "Sony nCPU embedded RISC processor"),
+    (0x39, "Denso NDR1 microprocesspr"),
+    (0x3a, "Motorola Star*Core processor"),
+    (0x3b, "Toyota ME16 processor"),
+    (0x3c, "STMicroelectronics ST100 processor"),
+    (0x3d, "Advanced Logic Corp. TinyJ embedded processor"),
+    (0x3e, "Advanced Micro Devices X86-64"),
+    (0x3f, "Sony DSP processor"),
+    (0x40, "Digital Equipment Corp. PDP-10"),
+    (0x41, "Digital Equipment Corp. PDP-11"),
+    (0x42, "Siemens FX66 microcontroller"),
+    (0x43, "STMicroelectronics ST9+ 8/16 bit microcontroller"),
+    (0x44, "STMicroelectronics ST7 8-bit microcontroller"),
+    (0x45, "Motorola MC68HC16 Microcontroller"),
+    (0x46, "Motorola MC68HC11 Microcontroller"),
+    (0x47, "Motorola MC68HC08 Microcontroller"),
+    (0x48, "Motorola MC68HC05 Microcontroller"),
+    (0x49, "Silicon Graphics SVx"),
+    (0x4a, "STMicroelectronics ST19 8-bit microcontroller"),
+    (0x4b, "Digital VAX"),
+    (0x4c, "Axis Communications 32-bit embedded processor"),
+    (0x4d, "Infineon Technologies 32-bit embedded cpu"),
+    (0x4e, "Element 14 64-bit DSP processor"),
+    (0x4f, "LSI Logic's 16-bit DSP processor"),
+    (0x50, "Donald Knuth's educational 64-bit processor"),
+    (
+        0x51,
+        "Harvard Universitys's machine-independent object format",
+    ),
+    (0x52, "Vitesse Prism"),
+    (0x53, "Atmel AVR 8-bit microcontroller"),
+    (0x54, "Fujitsu FR30"),
+    (0x55, "d10v"),
+    (0x56, "d30v"),
+    (0x57, "Renesas V850"),
+    (0x58, "Renesas M32R (formerly Mitsubishi M32r)"),
+    (0x59, "mn10300"),
+    (0x5a, "mn10200"),
+    (0x5b, "picoJava"),
+    (0x5c, "OpenRISC 1000"),
+    (0x5d, "ARCompact"),
+    (0x5e, "Tensilica Xtensa Processor"),
+    (0x5f, "Alphamosaic VideoCore processor"),
+    (0x60, "Thompson Multimedia General Purpose Processor"),
+    (0x61, "National Semiconductor 32000 series"),
+    (0x62, "Tenor Network TPC processor"),
+    (0x63, "Trebia SNP 1000 processor"),
+    (0x64, "STMicroelectronics ST200 microcontroller"),
+    (0x65, "Ubicom IP2xxx 8-bit microcontrollers"),
+    (0x66, "MAX Processor"),
+    (0x67, "National Semiconductor CompactRISC"),
+    (0x68, "Fujitsu F2MC16"),
+    (0x69, "Texas Instruments msp430 microcontroller"),
+    (0x6a, "Analog Devices Blackfin"),
+    (0x6b, "S1C33 Family of Seiko Epson processors"),
+    (0x6c, "Sharp embedded microprocessor"),
+    (0x6d, "Arca RISC microprocessor"),
+    (0x6e, "Unicore"),
+    (0x6f, "eXcess 16/32/64-bit configurable embedded CPU"),
+    (0x70, "Icera Semiconductor Inc. Deep Execution Processor"),
+    (0x71, "Altera Nios II"),
+    (0x72, "National Semiconductor CRX microprocessor"),
+    (0x73, "Motorola XGATE embedded processor"),
+    (0x74, "Infineon Technologies xc16x"),
+    (0x75, "Renesas M16C series microprocessors"),
+    (
+        0x76,
+        "Microchip Technology dsPIC30F Digital Signal Controller",
+    ),
+    (0x77, "Freescale Communication Engine RISC core"),
+    (0x78, "Renesas M32c"),
+    (0x83, "Altium TSK3000 core"),
+    (0x84, "Freescale RS08 embedded processor"),
+    (0x86, "Cyan Technology eCOG2 microprocessor"),
+    (0x87, "SUNPLUS S+Core"),
+    (0x88, "New Japan Radio (NJR) 24-bit DSP Processor"),
+    (0x89, "Broadcom VideoCore III processor"),
+    (0x8a, "Lattice Mico32"),
+    (0x8b, "Seiko Epson C17 family"),
+    (0x8c, "Texas Instruments TMS320C6000 DSP family"),
+    (0x8d, "Texas Instruments TMS320C2000 DSP family"),
+    (0x8e, "Texas Instruments TMS320C55x DSP family"),
+    (0x90, "TI PRU I/O processor"),
+    (0xa0, "STMicroelectronics 64bit VLIW Data Signal Processor"),
+    (0xa1, "Cypress M8C microprocessor"),
+    (0xa2, "Renesas R32C series microprocessors"),
+    (0xa3, "NXP Semiconductors TriMedia architecture family"),
+    (0xa4, "QUALCOMM DSP6 Processor"),
+    (0xa5, "Intel 8051 and variants"),
+    (0xa6, "STMicroelectronics STxP7x family"),
+    (
+        0xa7,
+        "Andes Technology compact code size embedded RISC processor family",
+    ),
+    (0xa8, "Cyan Technology eCOG1X family"),
+    (0xa9, "Dallas Semiconductor MAXQ30 Core microcontrollers"),
+    (0xaa, "New Japan Radio (NJR) 16-bit DSP Processor"),
+    (0xab, "M2000 Reconfigurable RISC Microprocessor"),
+    (0xac, "Cray Inc. NV2 vector architecture"),
+    (0xad, "Renesas RX"),
+    (0xae, "Imagination Technologies Meta processor architecture"),
+    (0xaf, "MCST Elbrus general purpose hardware architecture"),
+    (0xb0, "Cyan Technology eCOG16 family"),
+    (0xb1, "National Semiconductor's CR16"),
+    (0xb2, "Freescale Extended Time Processing Unit"),
+    (0xb3, "Infineon Technologies SLE9X core"),
+    (0xb4, "Intel L1OM"),
+    (0xb5, "Intel K1OM"),
+    (0xb6, "Intel (reserved)"),
+    (0xb7, "AArch64"),
+    (0xb8, "ARM (reserved)"),
+    (0xb9, "Atmel Corporation 32-bit microprocessor"),
+    (0xba, "STMicroeletronics STM8 8-bit microcontroller"),
+    (0xbb, "Tilera TILE64 multicore architecture family"),
+    (0xbc, "Tilera TILEPro multicore architecture family"),
+    (0xbd, "Xilinx MicroBlaze"),
+    (0xbe, "NVIDIA CUDA architecture"),
+    (0xbf, "Tilera TILE-Gx multicore architecture family"),
+    (0xc0, "CloudShield architecture family"),
+    (0xc1, "KIPO-KAIST Core-A 1st generation processor family"),
+    (0xc2, "KIPO-KAIST Core-A 2nd generation processor family"),
+    (0xc3, "ARCv2"),
+    (0xc4, "Open8 8-bit RISC soft processor core"),
+    (0xc5, "Renesas RL78"),
+    (0xc6, "Broadcom VideoCore V processor"),
+    (0xc7, "Renesas 78K0R"),
+    (0xc8, "Freescale 56800EX Digital Signal Controller (DSC)"),
+    (0xc9, "Beyond BA1 CPU architecture"),
+    (0xca, "Beyond BA2 CPU architecture"),
+    (0xcb, "XMOS xCORE processor family"),
+    (0xcc, "Microchip 8-bit PIC(r) family"),
+    (0xcd, "Intel Graphics Technology"),
+    (0xd2, "KM211 KM32 32-bit processor"),
+    (0xd3, "KM211 KMX32 32-bit processor"),
+    (0xd4, "KM211 KMX16 16-bit processor"),
+    (0xd5, "KM211 KMX8 8-bit processor"),
+    (0xd6, "KM211 KVARC processor"),
+    (0xd7, "Paneve CDP architecture family"),
+    (0xd8, "Cognitive Smart Memory Processor"),
+    (0xd9, "Bluechip Systems CoolEngine"),
+    (0xda, "Nanoradio Optimized RISC"),
+    (0xdb, "CSR Kalimba architecture family"),
+    (0xdc, "Zilog Z80"),
+    (0xdd, "CDS VISIUMcore processor"),
+    (0xde, "FTDI Chip FT32"),
+    (0xdf, "Moxie"),
+    (0xe0, "AMD GPU"),
+    (0xf3, "RISC-V"),
+    (0xf4, "Lanai 32-bit processor"),
+    (0xf5, "CEVA Processor Architecture Family"),
+    (0xf6, "CEVA X2 Processor Family"),
+    (0xf7, "Linux BPF"),
+    (0xf8, "Graphcore Intelligent Processing Unit"),
+    (0xf9, "Imagination Technologies"),
+    (0xfa, "Netronome Flow Processor"),
+    (0xfb, "NEC Vector Engine"),
+    (0xfc, "C-SKY"),
+    (0xfd, "Synopsys ARCv2.3 64-bit"),
+    (0xfe, "MOS Technology MCS 6502 processor"),
+    (0xff, "Synopsys ARCv2.3 32-bit"),
+    (0x100, "Kalray VLIW core of the MPPA processor family"),
+    (0x101, "WDC 65816/65C816"),
+    (0x102, "LoongArch"),
+    (0x103, "ChipON KungFu32"),
+    (0x1057, "Atmel AVR 8-bit microcontroller"),
+    (0x1223, "Adapteva EPIPHANY"),
+    (0x2530, "Morpho Techologies MT processor"),
+    (0x3330, "Fujitsu FR30"),
+    (0x4157, "Web Assembly"),
+    (0x4688, "Infineon Technologies xc16x"),
+    (0x4def, "Freescale S12Z"),
+    (0x5441, "Fujitsu FR-V"),
+    (0x5aa5, "OpenDLX"),
+    (0x7650, "d10v"),
+    (0x7676, "d30v"),
+    (0x8217, "Ubicom IP2xxx 8-bit microcontrollers"),
+    (0x9026, "Alpha"),
+    (0x9041, "Renesas M32R (formerly Mitsubishi M32r)"),
+    (0x9080, "Renesas V850"),
+    (0xa390, "IBM S/390"),
+    (0xabc7, "Tensilica Xtensa Processor"),
+    (0xad45, "Sanyo XStormy16 CPU core"),
+    (0xbaab, "Xilinx MicroBlaze"),
+    (0xbeef, "mn10300"),
+    (0xdead, "mn10200"),
+    (0xf00d, "Toshiba MeP Media Engine"),
+    (0xfeb0, "Renesas M32c"),
+    (0xfeba, "Vitesse IQ2000"),
+    (0xfebb, "Altera Nios"),
+];
@@ -0,0 +1,317 @@
+use super::{ArchBitLength, Architechture, ElfFlags, ElfHeader, MaybeUnknown};
+
+/// the processor specific flags of an elf file, decoded according to the architechture of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodedElfFlags {
+    Arm(ArmElfFlags),
+    Mips(MipsElfFlags),
+    Riscv(RiscvElfFlags),
+    Ppc64(Ppc64ElfFlags),
+    Loongarch(LoongarchElfFlags),
+
+    /// the flags of an architechture for which there is no specialized decoding.
+    Other(ElfFlags),
+}
+impl DecodedElfFlags {
+    pub fn decode(
+        flags: ElfFlags,
+        arch: MaybeUnknown<Architechture>,
+        bit_length: ArchBitLength,
+    ) -> Self {
+        let raw = flags.bits();
+        let Some(arch) = arch.known() else {
+            return Self::Other(flags);
+        };
+        match arch {
+            Architechture::Arm => Self::Arm(ArmElfFlags::decode(raw)),
+            Architechture::Mips | Architechture::MipsRs3Le => {
+                Self::Mips(MipsElfFlags::decode(raw, bit_length))
+            }
+            Architechture::Riscv => Self::Riscv(RiscvElfFlags::decode(raw)),
+            Architechture::Ppc64 => Self::Ppc64(Ppc64ElfFlags::decode(raw)),
+            Architechture::Loongarch => Self::Loongarch(LoongarchElfFlags::decode(raw)),
+            _ => Self::Other(flags),
+        }
+    }
+}
+
+impl ElfHeader {
+    /// decodes the processor specific flags of the elf file according to its architechture.
+    pub fn decoded_flags(&self) -> DecodedElfFlags {
+        DecodedElfFlags::decode(*self.flags(), *self.arch(), self.ident().header.bit_size)
+    }
+}
+
+const EF_ARM_EABIMASK: u32 = 0xff000000;
+const EF_ARM_BE8: u32 = 0x00800000;
+const EF_ARM_LE8: u32 = 0x00400000;
+const EF_ARM_ABI_FLOAT_SOFT: u32 = 0x200;
+const EF_ARM_ABI_FLOAT_HARD: u32 = 0x400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArmFloatAbi {
+    /// the object doesn't specify which floating point calling convention it uses.
+    Unspecified,
+    Soft,
+    Hard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArmElfFlags {
+    /// the version of the arm eabi that the object conforms to, or 0 for objects which don't conform to the eabi.
+    pub eabi_version: u8,
+    pub float_abi: ArmFloatAbi,
+
+    /// the object contains be8 code, which means that its code is little endian while its data is big endian.
+    pub be8: bool,
+    pub le8: bool,
+    pub raw: u32,
+}
+impl ArmElfFlags {
+    pub fn decode(raw: u32) -> Self {
+        // before version 5 of the eabi, these bits have different meanings.
+        let eabi_version = ((raw & EF_ARM_EABIMASK) >> 24) as u8;
+        let float_abi = if eabi_version < 5 {
+            ArmFloatAbi::Unspecified
+        } else if raw & EF_ARM_ABI_FLOAT_HARD != 0 {
+            ArmFloatAbi::Hard
+        } else if raw & EF_ARM_ABI_FLOAT_SOFT != 0 {
+            ArmFloatAbi::Soft
+        } else {
+            ArmFloatAbi::Unspecified
+        };
+        Self {
+            eabi_version,
+            float_abi,
+            be8: raw & EF_ARM_BE8 != 0,
+            le8: raw & EF_ARM_LE8 != 0,
+            raw,
+        }
+    }
+}
+
+const EF_MIPS_NOREORDER: u32 = 0x1;
+const EF_MIPS_PIC: u32 = 0x2;
+const EF_MIPS_CPIC: u32 = 0x4;
+const EF_MIPS_XGOT: u32 = 0x8;
+const EF_MIPS_ABI2: u32 = 0x20;
+const EF_MIPS_32BITMODE: u32 = 0x100;
+const EF_MIPS_FP64: u32 = 0x200;
+const EF_MIPS_NAN2008: u32 = 0x400;
+const EF_MIPS_ABI: u32 = 0x0000f000;
+const EF_MIPS_MACH: u32 = 0x00ff0000;
+const EF_MIPS_ARCH_ASE_MICROMIPS: u32 = 0x02000000;
+const EF_MIPS_ARCH: u32 = 0xf0000000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MipsAbi {
+    O32,
+    O64,
+    N32,
+    N64,
+    Eabi32,
+    Eabi64,
+
+    /// an unknown value of the abi field.
+    Unknown(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MipsIsa {
+    Mips1,
+    Mips2,
+    Mips3,
+    Mips4,
+    Mips5,
+    Mips32,
+    Mips64,
+    Mips32R2,
+    Mips64R2,
+    Mips32R6,
+    Mips64R6,
+
+    /// an unknown value of the architechture field.
+    Unknown(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MipsElfFlags {
+    pub abi: MipsAbi,
+    pub isa: MipsIsa,
+
+    /// the value of the machine field, which identifies the specific cpu that the object targets.
+    pub mach: u8,
+    pub noreorder: bool,
+    pub pic: bool,
+    pub cpic: bool,
+    pub xgot: bool,
+    pub bit32_mode: bool,
+    pub fp64: bool,
+    pub nan2008: bool,
+    pub micromips: bool,
+    pub raw: u32,
+}
+impl MipsElfFlags {
+    pub fn decode(raw: u32, bit_length: ArchBitLength) -> Self {
+        let abi = if raw & EF_MIPS_ABI2 != 0 {
+            MipsAbi::N32
+        } else {
+            match (raw & EF_MIPS_ABI) >> 12 {
+                // objects which don't specify an abi use the default abi of their class.
+                0 => match bit_length {
+                    ArchBitLength::Arch32Bit => MipsAbi::O32,
+                    ArchBitLength::Arch64Bit => MipsAbi::N64,
+                },
+                1 => MipsAbi::O32,
+                2 => MipsAbi::O64,
+                3 => MipsAbi::Eabi32,
+                4 => MipsAbi::Eabi64,
+                value => MipsAbi::Unknown(value as u8),
+            }
+        };
+        let isa = match (raw & EF_MIPS_ARCH) >> 28 {
+            0x0 => MipsIsa::Mips1,
+            0x1 => MipsIsa::Mips2,
+            0x2 => MipsIsa::Mips3,
+            0x3 => MipsIsa::Mips4,
+            0x4 => MipsIsa::Mips5,
+            0x5 => MipsIsa::Mips32,
+            0x6 => MipsIsa::Mips64,
+            0x7 => MipsIsa::Mips32R2,
+            0x8 => MipsIsa::Mips64R2,
+            0x9 => MipsIsa::Mips32R6,
+            0xa => MipsIsa::Mips64R6,
+            value => MipsIsa::Unknown(value as u8),
+        };
+        Self {
+            abi,
+            isa,
+            mach: ((raw & EF_MIPS_MACH) >> 16) as u8,
+            noreorder: raw & EF_MIPS_NOREORDER != 0,
+            pic: raw & EF_MIPS_PIC != 0,
+            cpic: raw & EF_MIPS_CPIC != 0,
+            xgot: raw & EF_MIPS_XGOT != 0,
+            bit32_mode: raw & EF_MIPS_32BITMODE != 0,
+            fp64: raw & EF_MIPS_FP64 != 0,
+            nan2008: raw & EF_MIPS_NAN2008 != 0,
+            micromips: raw & EF_MIPS_ARCH_ASE_MICROMIPS != 0,
+            raw,
+        }
+    }
+}
+
+const EF_RISCV_RVC: u32 = 0x1;
+const EF_RISCV_FLOAT_ABI: u32 = 0x6;
+const EF_RISCV_RVE: u32 = 0x8;
+const EF_RISCV_TSO: u32 = 0x10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RiscvFloatAbi {
+    Soft,
+    Single,
+    Double,
+    Quad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RiscvElfFlags {
+    pub float_abi: RiscvFloatAbi,
+
+    /// the object uses the compressed instructions extension.
+    pub rvc: bool,
+
+    /// the object uses the embedded abi, which uses a reduced set of registers.
+    pub rve: bool,
+
+    /// the object requires the total store ordering memory model.
+    pub tso: bool,
+    pub raw: u32,
+}
+impl RiscvElfFlags {
+    pub fn decode(raw: u32) -> Self {
+        let float_abi = match (raw & EF_RISCV_FLOAT_ABI) >> 1 {
+            0 => RiscvFloatAbi::Soft,
+            1 => RiscvFloatAbi::Single,
+            2 => RiscvFloatAbi::Double,
+            _ => RiscvFloatAbi::Quad,
+        };
+        Self {
+            float_abi,
+            rvc: raw & EF_RISCV_RVC != 0,
+            rve: raw & EF_RISCV_RVE != 0,
+            tso: raw & EF_RISCV_TSO != 0,
+            raw,
+        }
+    }
+}
+
+const EF_PPC64_ABI: u32 = 0x3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ppc64Abi {
+    /// the object doesn't specify its abi, which usually means that it is compatible with both abis.
+    Unspecified,
+
+    /// the original abi, which uses function descriptors.
+    ElfV1,
+    ElfV2,
+
+    /// an unknown value of the abi field.
+    Unknown(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ppc64ElfFlags {
+    pub abi: Ppc64Abi,
+    pub raw: u32,
+}
+impl Ppc64ElfFlags {
+    pub fn decode(raw: u32) -> Self {
+        let abi = match raw & EF_PPC64_ABI {
+            0 => Ppc64Abi::Unspecified,
+            1 => Ppc64Abi::ElfV1,
+            2 => Ppc64Abi::ElfV2,
+            value => Ppc64Abi::Unknown(value as u8),
+        };
+        Self { abi, raw }
+    }
+}
+
+const EF_LOONGARCH_ABI_MODIFIER_MASK: u32 = 0x7;
+const EF_LOONGARCH_OBJABI_MASK: u32 = 0xc0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoongarchFloatAbi {
+    Soft,
+    Single,
+    Double,
+
+    /// an unknown value of the abi modifier field.
+    Unknown(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoongarchElfFlags {
+    /// the floating point abi modifier of the base abi. the base abi itself (`lp64` or `ilp32`) is determined by the
+    /// class of the file.
+    pub float_abi: LoongarchFloatAbi,
+
+    /// the version of the object file abi.
+    pub object_abi_version: u8,
+    pub raw: u32,
+}
+impl LoongarchElfFlags {
+    pub fn decode(raw: u32) -> Self {
+        let float_abi = match raw & EF_LOONGARCH_ABI_MODIFIER_MASK {
+            1 => LoongarchFloatAbi::Soft,
+            2 => LoongarchFloatAbi::Single,
+            3 => LoongarchFloatAbi::Double,
+            value => LoongarchFloatAbi::Unknown(value as u8),
+        };
+        Self {
+            float_abi,
+            object_abi_version: ((raw & EF_LOONGARCH_OBJABI_MASK) >> 6) as u8,
+            raw,
+        }
+    }
+}
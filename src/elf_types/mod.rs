@@ -1,7 +1,13 @@
 mod codegen;
+mod decoded_types;
+mod display;
+mod elf_flags;
 mod relocations;
 
 pub use codegen::*;
+pub use decoded_types::*;
+pub use display::*;
+pub use elf_flags::*;
 pub use relocations::*;
 
 use crate::{ElfParser, StringTable, VariantStructBinarySerde};
@@ -41,29 +47,32 @@ impl<T> core::ops::DerefMut for DebugIgnore<T> {
 pub struct ElfFileInfo {
     pub endianness: Endianness,
     pub bit_length: ArchBitLength,
-    pub os_abi: OsAbi,
-    pub arch: Architechture,
+    pub os_abi: MaybeUnknown<OsAbi>,
+    pub arch: MaybeUnknown<Architechture>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ElfIdentHeader {
     pub magic: [u8; ELF_MAGIC.len()],
     pub bit_size: ArchBitLength,
     pub endianness: ElfEndianness,
     pub elf_version: ElfVersionInIdent,
-    pub os_abi: OsAbi,
-    pub abi_version: AbiVersion,
+    pub os_abi: MaybeUnknown<OsAbi>,
+    pub abi_version: MaybeUnknown<AbiVersion>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ElfIdent {
     pub header: ElfIdentHeader,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub padding: [u8; ELF_IDENT_PADDING_SIZE],
 }
 
 define_raw_struct_by_variants! {
     struct ProgramHeader32 {
-        ty: ProgramHeaderType,
+        ty: MaybeUnknown<ProgramHeaderType>,
         offset: u32,
         virt_addr: u32,
         phys_addr: u32,
@@ -73,7 +82,7 @@ define_raw_struct_by_variants! {
         alignment: u32,
     }
     struct ProgramHeader64 {
-        ty: ProgramHeaderType,
+        ty: MaybeUnknown<ProgramHeaderType>,
         flags: ProgramHeaderFlags,
         offset: u64,
         virt_addr: u64,
@@ -88,7 +97,7 @@ define_raw_struct_by_variants! {
 define_raw_struct_by_variants! {
     struct SectionHeader64 {
         name_offset: u32,
-        ty: SectionHeaderType,
+        ty: MaybeUnknown<SectionHeaderType>,
         flags: SectionHeaderFlags,
         address: u64,
         offset: u64,
@@ -100,7 +109,7 @@ define_raw_struct_by_variants! {
     }
     struct SectionHeader32 {
         name_offset: u32,
-        ty: SectionHeaderType,
+        ty: MaybeUnknown<SectionHeaderType>,
         flags: SectionHeaderFlagsU32,
         address: u32,
         offset: u32,
@@ -116,9 +125,9 @@ define_raw_struct_by_variants! {
 define_raw_struct_generic_bitlen! {
     struct ElfHeader {
         ident: ElfIdent,
-        ty: ElfFileType,
-        arch: Architechture,
-        version: ElfVersion,
+        ty: MaybeUnknown<ElfFileType>,
+        arch: MaybeUnknown<Architechture>,
+        version: MaybeUnknown<ElfVersion>,
         entry: U,
         program_headers_off: U,
         section_headers_off: U,
@@ -134,16 +143,18 @@ define_raw_struct_generic_bitlen! {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[binary_serde_bitfield(order = BitfieldBitOrder::LsbFirst)]
 pub struct SymbolInfo {
     #[bits(4)]
-    pub ty: SymbolType,
+    pub ty: MaybeUnknown<SymbolType>,
 
     #[bits(4)]
-    pub binding: SymbolBinding,
+    pub binding: MaybeUnknown<SymbolBinding>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[binary_serde_bitfield(order = BitfieldBitOrder::LsbFirst)]
 pub struct SymbolOtherInfo {
     #[bits(2)]
@@ -178,6 +189,87 @@ define_raw_struct_by_variants! {
     => SymbolRefContext<'a>
 }
 
+define_raw_struct_by_variants! {
+    struct DynamicEntry32 {
+        tag: i32,
+        value: u32,
+    }
+    struct DynamicEntry64 {
+        tag: i64,
+        value: u64,
+    }
+    => ()
+}
+
+/// the version index of local symbols in the `.gnu.version` section.
+pub const VER_NDX_LOCAL: u16 = 0;
+
+/// the version index of global symbols which don't have a specific version in the `.gnu.version` section.
+pub const VER_NDX_GLOBAL: u16 = 1;
+
+/// the bit of a `.gnu.version` entry which marks the symbol as hidden, meaning that it can only be referenced using
+/// an explicit version.
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+
+/// the flag of the version definition which describes the file itself rather than a version.
+pub const VER_FLG_BASE: u16 = 0x1;
+
+/// the flag of a weak version definition or requirement.
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+/// an entry of the `.gnu.version_r` section, which describes the versions needed from a single library.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+pub struct VersionNeed {
+    pub version: u16,
+    pub aux_amount: u16,
+    pub file_name_offset: u32,
+    pub aux_offset: u32,
+    pub next_offset: u32,
+}
+
+/// an auxiliary entry of a `.gnu.version_r` entry, which describes a single needed version.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+pub struct VersionNeedAux {
+    pub hash: u32,
+    pub flags: u16,
+
+    /// the version index which the `.gnu.version` entries of the symbols which need this version use.
+    pub index: u16,
+    pub name_offset: u32,
+    pub next_offset: u32,
+}
+
+/// an entry of the `.gnu.version_d` section, which describes a single version defined by the file.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+pub struct VersionDefinition {
+    pub version: u16,
+    pub flags: u16,
+    pub index: u16,
+    pub aux_amount: u16,
+    pub hash: u32,
+    pub aux_offset: u32,
+    pub next_offset: u32,
+}
+
+/// an auxiliary entry of a `.gnu.version_d` entry. the first one holds the name of the version, and the following
+/// ones hold the names of its parent versions.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+pub struct VersionDefinitionAux {
+    pub name_offset: u32,
+    pub next_offset: u32,
+}
+
+/// the owner name of gnu notes.
+pub const GNU_NOTE_NAME: &[u8] = b"GNU";
+
+/// the header of a note in a notes section or segment.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, BinarySerde)]
+pub struct NoteHeader {
+    pub name_size: u32,
+    pub desc_size: u32,
+    pub ty: u32,
+}
+
 #[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum AbiVersion {
@@ -186,6 +278,7 @@ pub enum AbiVersion {
 }
 
 #[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum ElfVersionInIdent {
     Current = 1,
@@ -198,6 +291,7 @@ pub enum ElfVersion {
 }
 
 #[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum ElfEndianness {
     Little = 1,
@@ -221,12 +315,154 @@ impl From<binary_serde::Endianness> for ElfEndianness {
 }
 
 #[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum ArchBitLength {
     Arch32Bit = 1,
     Arch64Bit = 2,
 }
 
+/// an enum which is stored in the elf file as a raw integer value.
+pub trait RawEnum: Sized + Copy {
+    type Raw: BinarySerde
+        + Copy
+        + core::fmt::Debug
+        + core::fmt::LowerHex
+        + PartialEq
+        + Eq
+        + core::hash::Hash;
+
+    fn to_raw(self) -> Self::Raw;
+
+    /// converts the given raw value to an enum value, or returns `None` if the value doesn't match any of the enum's
+    /// variants.
+    fn from_raw(raw: Self::Raw) -> Option<Self>;
+}
+
+macro_rules! impl_raw_enum {
+    {$($enum_ty: ty => $raw_ty: ty),+ $(,)?} => {
+        $(
+            impl RawEnum for $enum_ty {
+                type Raw = $raw_ty;
+
+                fn to_raw(self) -> $raw_ty {
+                    self as $raw_ty
+                }
+
+                fn from_raw(raw: $raw_ty) -> Option<Self> {
+                    let bytes = raw.binary_serialize_to_array(Endianness::Little);
+                    Self::binary_deserialize(bytes.as_ref(), Endianness::Little).ok()
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for $enum_ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serde_support::serialize_enum_name(self, serializer)
+                }
+            }
+        )+
+    };
+}
+
+impl_raw_enum! {
+    OsAbi => u8,
+    AbiVersion => u8,
+    ElfVersion => u32,
+    ElfFileType => u16,
+    Architechture => u16,
+    ProgramHeaderType => u32,
+    SectionHeaderType => u32,
+    SymbolType => u8,
+    SymbolBinding => u8,
+    SymbolVisibility => u8,
+    RelocationType => u32,
+}
+
+/// an enum value read from the elf file, which may not match any of the enum's variants.
+///
+/// elf files often contain vendor specific values which are unknown to us, and such values should not prevent us from
+/// parsing the rest of the file, so they are kept as raw values.
+#[derive(Clone, Copy)]
+pub enum MaybeUnknown<T: RawEnum> {
+    Known(T),
+    Unknown(T::Raw),
+}
+impl<T: RawEnum> MaybeUnknown<T> {
+    pub fn from_raw(raw: T::Raw) -> Self {
+        match T::from_raw(raw) {
+            Some(value) => Self::Known(value),
+            None => Self::Unknown(raw),
+        }
+    }
+
+    pub fn raw(&self) -> T::Raw {
+        match self {
+            MaybeUnknown::Known(value) => value.to_raw(),
+            MaybeUnknown::Unknown(raw) => *raw,
+        }
+    }
+
+    pub fn known(&self) -> Option<T> {
+        match self {
+            MaybeUnknown::Known(value) => Some(*value),
+            MaybeUnknown::Unknown(_) => None,
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        matches!(self, MaybeUnknown::Known(_))
+    }
+}
+impl<T: RawEnum> From<T> for MaybeUnknown<T> {
+    fn from(value: T) -> Self {
+        Self::Known(value)
+    }
+}
+impl<T: RawEnum + core::fmt::Debug> core::fmt::Debug for MaybeUnknown<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MaybeUnknown::Known(value) => value.fmt(f),
+            MaybeUnknown::Unknown(raw) => write!(f, "Unknown({:#x})", raw),
+        }
+    }
+}
+impl<T: RawEnum> PartialEq for MaybeUnknown<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw() == other.raw()
+    }
+}
+impl<T: RawEnum> Eq for MaybeUnknown<T> {}
+impl<T: RawEnum> PartialEq<T> for MaybeUnknown<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.raw() == other.to_raw()
+    }
+}
+impl<T: RawEnum> core::hash::Hash for MaybeUnknown<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.raw().hash(state)
+    }
+}
+impl<T: RawEnum> BinarySerde for MaybeUnknown<T> {
+    const SERIALIZED_SIZE: usize = <T::Raw as BinarySerde>::SERIALIZED_SIZE;
+
+    type RecursiveArray = <T::Raw as BinarySerde>::RecursiveArray;
+
+    fn binary_serialize(&self, buf: &mut [u8], endianness: Endianness) {
+        self.raw().binary_serialize(buf, endianness)
+    }
+
+    fn binary_deserialize(
+        buf: &[u8],
+        endianness: Endianness,
+    ) -> Result<Self, binary_serde::DeserializeError> {
+        Ok(Self::from_raw(T::Raw::binary_deserialize(buf, endianness)?))
+    }
+}
+
+/// a relocation type which may be unknown.
+pub type RelocationTypeOrUnknown = MaybeUnknown<RelocationType>;
+
 macro_rules! gen_enum_size_truncating_wrapper {
     {$wrapper_name: ident, $inner_ty: ty, $truncated_uint: ty, $original_uint: ty, $convert_inner_to_bits_input_var_name: ident, $convert_inner_to_bits_body: block} => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -237,11 +473,11 @@ macro_rules! gen_enum_size_truncating_wrapper {
             type RecursiveArray = <$truncated_uint as BinarySerde>::RecursiveArray;
 
             fn binary_serialize(&self, buf: &mut [u8], endianness: Endianness) {
-                let converted = {
+                let converted: $original_uint = {
                     let $convert_inner_to_bits_input_var_name = &self.0;
                     $convert_inner_to_bits_body
                 };
-                converted.binary_serialize(buf, endianness)
+                (converted as $truncated_uint).binary_serialize(buf, endianness)
             }
 
             fn binary_deserialize(
@@ -276,5 +512,5 @@ macro_rules! gen_enum_size_truncating_wrapper {
     };
 }
 
-gen_enum_size_truncating_wrapper! {RelocationTypeU8, RelocationType, u8, u32, x, {*x as u32}}
+gen_enum_size_truncating_wrapper! {RelocationTypeOrUnknownU8, RelocationTypeOrUnknown, u8, u32, x, {x.raw()}}
 gen_enum_size_truncating_wrapper! {SectionHeaderFlagsU32, SectionHeaderFlags, u32, u64, x, {x.bits()}}
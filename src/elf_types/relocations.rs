@@ -3,14 +3,16 @@ use elflib_macros::define_raw_struct_by_variants;
 
 use crate::{ElfParser, VariantStructBinarySerde};
 
-use super::{ArchBitLength, Architechture, ElfFileInfo, RelocationType, RelocationTypeU8};
+use super::{
+    ArchBitLength, Architechture, ElfFileInfo, RelocationTypeOrUnknown, RelocationTypeOrUnknownU8,
+};
 
 define_raw_struct_by_variants! {
     #[binary_serde_bitfield(order = BitfieldBitOrder::LsbFirst)]
     #[derive(Copy)]
     struct RelInfoRegular32 {
         #[bits(8)]
-        ty: RelocationTypeU8,
+        ty: RelocationTypeOrUnknownU8,
 
         #[bits(24)]
         symbol_index: u32,
@@ -18,14 +20,14 @@ define_raw_struct_by_variants! {
 
     #[derive(Copy)]
     struct RelInfoRegular64 {
-        ty: RelocationType,
+        ty: RelocationTypeOrUnknown,
         symbol_index: u32,
     }
     => ()
 }
 
 impl TryFrom<RelInfoRegular64> for RelInfoRegular32 {
-    type Error = <RelocationTypeU8 as TryFrom<RelocationType>>::Error;
+    type Error = <RelocationTypeOrUnknownU8 as TryFrom<RelocationTypeOrUnknown>>::Error;
 
     fn try_from(value: RelInfoRegular64) -> Result<Self, Self::Error> {
         Ok(Self {
@@ -133,23 +135,33 @@ impl RelaRegular {
 }
 
 #[derive(Debug, BinarySerde, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RelMips64 {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_support::serialize_hex")
+    )]
     pub offset: u64,
     pub symbol_index: u32,
     pub special_symbol: u8,
-    pub ty3: RelocationTypeU8,
-    pub ty2: RelocationTypeU8,
-    pub ty: RelocationTypeU8,
+    pub ty3: RelocationTypeOrUnknownU8,
+    pub ty2: RelocationTypeOrUnknownU8,
+    pub ty: RelocationTypeOrUnknownU8,
 }
 
 #[derive(Debug, BinarySerde, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RelaMips64 {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_support::serialize_hex")
+    )]
     pub offset: u64,
     pub symbol_index: u32,
     pub special_symbol: u8,
-    pub ty3: RelocationTypeU8,
-    pub ty2: RelocationTypeU8,
-    pub ty: RelocationTypeU8,
+    pub ty3: RelocationTypeOrUnknownU8,
+    pub ty2: RelocationTypeOrUnknownU8,
+    pub ty: RelocationTypeOrUnknownU8,
     pub addend: i64,
 }
 impl RelaMips64 {
@@ -179,7 +191,251 @@ impl RelaMips64 {
     }
 }
 
+impl RelMips64 {
+    /// the relocation types of this relocation, in the order in which they should be applied.
+    pub fn types(&self) -> [RelocationTypeOrUnknown; 3] {
+        [self.ty.into(), self.ty2.into(), self.ty3.into()]
+    }
+
+    /// the relocation types of this relocation, decoded as mips relocation types.
+    pub fn mips_types(&self) -> [Option<MipsRelocationType>; 3] {
+        self.types().map(|ty| ty.try_into().ok())
+    }
+
+    /// the special symbol of this relocation, or `None` if its value is unknown.
+    pub fn decoded_special_symbol(&self) -> Option<RelocationSpecialSymbolMips64> {
+        RelocationSpecialSymbolMips64::from_raw(self.special_symbol)
+    }
+
+    /// the value of the `r_info` field of this relocation, as defined by the mips64 elf abi.
+    pub fn r_info(&self) -> u64 {
+        let types = self.types();
+        ((self.symbol_index as u64) << 32)
+            | ((self.special_symbol as u64) << 24)
+            | ((types[2].raw() as u64 & 0xff) << 16)
+            | ((types[1].raw() as u64 & 0xff) << 8)
+            | (types[0].raw() as u64 & 0xff)
+    }
+
+    /// returns the sequence of relocation operations that are composed together to form this relocation.
+    ///
+    /// the first operation uses the symbol of the relocation and its addend, while the second and third operations use
+    /// the special symbol and the result of the previous operation as their addend. the sequence ends at the first
+    /// `R_MIPS_NONE` operation following the first one.
+    pub fn operations(&self) -> Mips64RelocationOperations {
+        Mips64RelocationOperations {
+            types: self.types(),
+            symbol_index: self.symbol_index,
+            special_symbol: self.special_symbol,
+            cur_index: 0,
+        }
+    }
+}
+
+/// the symbol used by a single operation of a mips64 relocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mips64RelocationOperationSymbol {
+    /// an index into the symbol table linked to the relocation section.
+    Symbol(u32),
+
+    /// one of the mips64 special symbols.
+    Special(RelocationSpecialSymbolMips64),
+
+    /// an unknown special symbol value.
+    UnknownSpecial(u8),
+}
+
+/// a single operation of a mips64 relocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mips64RelocationOperation {
+    pub ty: RelocationTypeOrUnknown,
+    pub mips_ty: Option<MipsRelocationType>,
+    pub symbol: Mips64RelocationOperationSymbol,
+}
+
+/// an iterator over the operations that are composed together to form a mips64 relocation.
+#[derive(Debug, Clone)]
+pub struct Mips64RelocationOperations {
+    types: [RelocationTypeOrUnknown; 3],
+    symbol_index: u32,
+    special_symbol: u8,
+    cur_index: usize,
+}
+impl Iterator for Mips64RelocationOperations {
+    type Item = Mips64RelocationOperation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ty = *self.types.get(self.cur_index)?;
+        let mips_ty = MipsRelocationType::try_from(ty).ok();
+        if self.cur_index > 0 && mips_ty == Some(MipsRelocationType::MipsNone) {
+            self.cur_index = self.types.len();
+            return None;
+        }
+        let symbol = if self.cur_index == 0 {
+            Mips64RelocationOperationSymbol::Symbol(self.symbol_index)
+        } else {
+            match RelocationSpecialSymbolMips64::from_raw(self.special_symbol) {
+                Some(special_symbol) => Mips64RelocationOperationSymbol::Special(special_symbol),
+                None => Mips64RelocationOperationSymbol::UnknownSpecial(self.special_symbol),
+            }
+        };
+        self.cur_index += 1;
+        Some(Mips64RelocationOperation {
+            ty,
+            mips_ty,
+            symbol,
+        })
+    }
+}
+
+/// the relocation types of the mips architechture.
+#[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[repr(u8)]
+pub enum MipsRelocationType {
+    MipsNone = 0,
+    Mips16 = 1,
+    Mips32 = 2,
+    MipsRel32 = 3,
+    Mips26 = 4,
+    MipsHi16 = 5,
+    MipsLo16 = 6,
+    MipsGprel16 = 7,
+    MipsLiteral = 8,
+    MipsGot16 = 9,
+    MipsPc16 = 10,
+    MipsCall16 = 11,
+    MipsGprel32 = 12,
+    MipsShift5 = 16,
+    MipsShift6 = 17,
+    Mips64 = 18,
+    MipsGotDisp = 19,
+    MipsGotPage = 20,
+    MipsGotOfst = 21,
+    MipsGotHi16 = 22,
+    MipsGotLo16 = 23,
+    MipsSub = 24,
+    MipsInsertA = 25,
+    MipsInsertB = 26,
+    MipsDelete = 27,
+    MipsHigher = 28,
+    MipsHighest = 29,
+    MipsCallHi16 = 30,
+    MipsCallLo16 = 31,
+    MipsScnDisp = 32,
+    MipsRel16 = 33,
+    MipsAddImmediate = 34,
+    MipsPjump = 35,
+    MipsRelgot = 36,
+    MipsJalr = 37,
+    MipsTlsDtpmod32 = 38,
+    MipsTlsDtprel32 = 39,
+    MipsTlsDtpmod64 = 40,
+    MipsTlsDtprel64 = 41,
+    MipsTlsGd = 42,
+    MipsTlsLdm = 43,
+    MipsTlsDtprelHi16 = 44,
+    MipsTlsDtprelLo16 = 45,
+    MipsTlsGottprel = 46,
+    MipsTlsTprel32 = 47,
+    MipsTlsTprel64 = 48,
+    MipsTlsTprelHi16 = 49,
+    MipsTlsTprelLo16 = 50,
+    MipsGlobDat = 51,
+    MipsPc21S2 = 60,
+    MipsPc26S2 = 61,
+    MipsPc18S3 = 62,
+    MipsPc19S2 = 63,
+    MipsPchi16 = 64,
+    MipsPclo16 = 65,
+    MipsCopy = 126,
+    MipsJumpSlot = 127,
+    MipsPc32 = 248,
+    MipsEh = 249,
+}
+impl MipsRelocationType {
+    pub fn from_raw(raw: u8) -> Option<Self> {
+        Self::binary_deserialize(&[raw], Endianness::Little).ok()
+    }
+
+    /// the name of the relocation type as it appears in the mips elf abi, for example `R_MIPS_REL32`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MipsNone => "R_MIPS_NONE",
+            Self::Mips16 => "R_MIPS_16",
+            Self::Mips32 => "R_MIPS_32",
+            Self::MipsRel32 => "R_MIPS_REL32",
+            Self::Mips26 => "R_MIPS_26",
+            Self::MipsHi16 => "R_MIPS_HI16",
+            Self::MipsLo16 => "R_MIPS_LO16",
+            Self::MipsGprel16 => "R_MIPS_GPREL16",
+            Self::MipsLiteral => "R_MIPS_LITERAL",
+            Self::MipsGot16 => "R_MIPS_GOT16",
+            Self::MipsPc16 => "R_MIPS_PC16",
+            Self::MipsCall16 => "R_MIPS_CALL16",
+            Self::MipsGprel32 => "R_MIPS_GPREL32",
+            Self::MipsShift5 => "R_MIPS_SHIFT5",
+            Self::MipsShift6 => "R_MIPS_SHIFT6",
+            Self::Mips64 => "R_MIPS_64",
+            Self::MipsGotDisp => "R_MIPS_GOT_DISP",
+            Self::MipsGotPage => "R_MIPS_GOT_PAGE",
+            Self::MipsGotOfst => "R_MIPS_GOT_OFST",
+            Self::MipsGotHi16 => "R_MIPS_GOT_HI16",
+            Self::MipsGotLo16 => "R_MIPS_GOT_LO16",
+            Self::MipsSub => "R_MIPS_SUB",
+            Self::MipsInsertA => "R_MIPS_INSERT_A",
+            Self::MipsInsertB => "R_MIPS_INSERT_B",
+            Self::MipsDelete => "R_MIPS_DELETE",
+            Self::MipsHigher => "R_MIPS_HIGHER",
+            Self::MipsHighest => "R_MIPS_HIGHEST",
+            Self::MipsCallHi16 => "R_MIPS_CALL_HI16",
+            Self::MipsCallLo16 => "R_MIPS_CALL_LO16",
+            Self::MipsScnDisp => "R_MIPS_SCN_DISP",
+            Self::MipsRel16 => "R_MIPS_REL16",
+            Self::MipsAddImmediate => "R_MIPS_ADD_IMMEDIATE",
+            Self::MipsPjump => "R_MIPS_PJUMP",
+            Self::MipsRelgot => "R_MIPS_RELGOT",
+            Self::MipsJalr => "R_MIPS_JALR",
+            Self::MipsTlsDtpmod32 => "R_MIPS_TLS_DTPMOD32",
+            Self::MipsTlsDtprel32 => "R_MIPS_TLS_DTPREL32",
+            Self::MipsTlsDtpmod64 => "R_MIPS_TLS_DTPMOD64",
+            Self::MipsTlsDtprel64 => "R_MIPS_TLS_DTPREL64",
+            Self::MipsTlsGd => "R_MIPS_TLS_GD",
+            Self::MipsTlsLdm => "R_MIPS_TLS_LDM",
+            Self::MipsTlsDtprelHi16 => "R_MIPS_TLS_DTPREL_HI16",
+            Self::MipsTlsDtprelLo16 => "R_MIPS_TLS_DTPREL_LO16",
+            Self::MipsTlsGottprel => "R_MIPS_TLS_GOTTPREL",
+            Self::MipsTlsTprel32 => "R_MIPS_TLS_TPREL32",
+            Self::MipsTlsTprel64 => "R_MIPS_TLS_TPREL64",
+            Self::MipsTlsTprelHi16 => "R_MIPS_TLS_TPREL_HI16",
+            Self::MipsTlsTprelLo16 => "R_MIPS_TLS_TPREL_LO16",
+            Self::MipsGlobDat => "R_MIPS_GLOB_DAT",
+            Self::MipsPc21S2 => "R_MIPS_PC21_S2",
+            Self::MipsPc26S2 => "R_MIPS_PC26_S2",
+            Self::MipsPc18S3 => "R_MIPS_PC18_S3",
+            Self::MipsPc19S2 => "R_MIPS_PC19_S2",
+            Self::MipsPchi16 => "R_MIPS_PCHI16",
+            Self::MipsPclo16 => "R_MIPS_PCLO16",
+            Self::MipsCopy => "R_MIPS_COPY",
+            Self::MipsJumpSlot => "R_MIPS_JUMP_SLOT",
+            Self::MipsPc32 => "R_MIPS_PC32",
+            Self::MipsEh => "R_MIPS_EH",
+        }
+    }
+}
+impl TryFrom<RelocationTypeOrUnknown> for MipsRelocationType {
+    type Error = RelocationTypeOrUnknown;
+
+    fn try_from(value: RelocationTypeOrUnknown) -> Result<Self, Self::Error> {
+        u8::try_from(value.raw())
+            .ok()
+            .and_then(Self::from_raw)
+            .ok_or(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
 pub enum Rel {
     RelMips64(RelMips64),
     RelRegular(RelRegular),
@@ -191,8 +447,8 @@ impl<'a> VariantStructBinarySerde<'a> for Rel {
         parser: &ElfParser<'a>,
         _context: (),
     ) -> core::result::Result<Self, binary_serde::BinarySerdeBufSafeError> {
-        match (parser.file_info.arch, parser.file_info.bit_length) {
-            (Architechture::Mips, ArchBitLength::Arch64Bit) => {
+        match (parser.file_info.arch.known(), parser.file_info.bit_length) {
+            (Some(Architechture::Mips), ArchBitLength::Arch64Bit) => {
                 Ok(Self::RelMips64(deserializer.deserialize()?))
             }
             _ => Ok(Self::RelRegular(RelRegular::deserialize(
@@ -204,8 +460,8 @@ impl<'a> VariantStructBinarySerde<'a> for Rel {
     }
 
     fn record_len(file_info: &ElfFileInfo) -> usize {
-        match (file_info.arch, file_info.bit_length) {
-            (Architechture::Mips, ArchBitLength::Arch64Bit) => RelMips64::SERIALIZED_SIZE,
+        match (file_info.arch.known(), file_info.bit_length) {
+            (Some(Architechture::Mips), ArchBitLength::Arch64Bit) => RelMips64::SERIALIZED_SIZE,
             _ => RelRegular::record_len(file_info),
         }
     }
@@ -234,6 +490,48 @@ impl Rel {
             Rel::RelRegular(x) => x.set_offset(new_value),
         }
     }
+    pub fn as_mips64(&self) -> Option<&RelMips64> {
+        match self {
+            Rel::RelMips64(x) => Some(x),
+            Rel::RelRegular(_) => None,
+        }
+    }
+
+    pub fn symbol_index(&self) -> u32 {
+        match self {
+            Rel::RelMips64(x) => x.symbol_index,
+            Rel::RelRegular(x) => x.info().symbol_index,
+        }
+    }
+
+    /// the raw relocation type. for mips64 relocations, which have several types, this is the first type.
+    pub fn raw_type(&self) -> u32 {
+        match self {
+            Rel::RelMips64(x) => x.types()[0].raw(),
+            Rel::RelRegular(x) => x.info().ty.raw(),
+        }
+    }
+
+    pub fn set_symbol_index(&mut self, new_value: u32) {
+        match self {
+            Rel::RelMips64(x) => x.symbol_index = new_value,
+            Rel::RelRegular(x) => {
+                let mut info = x.info();
+                info.symbol_index = new_value;
+                x.set_info(info);
+            }
+        }
+    }
+
+    /// the special symbol of a mips64 relocation, or `None` if this is not a mips64 relocation or if the special
+    /// symbol value is unknown.
+    pub fn mips64_special_symbol(&self) -> Option<RelocationSpecialSymbolMips64> {
+        match self {
+            Rel::RelMips64(x) => x.decoded_special_symbol(),
+            Rel::RelRegular(_) => None,
+        }
+    }
+
     pub fn to_generic_rel(self) -> GenericRel {
         GenericRel {
             rel: self,
@@ -248,6 +546,7 @@ impl From<Rel> for GenericRel {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
 pub enum Rela {
     RelaMips64(RelaMips64),
     RelaRegular(RelaRegular),
@@ -266,6 +565,24 @@ impl Rela {
             Rela::RelaRegular(x) => x.set_offset(new_value),
         }
     }
+
+    pub fn symbol_index(&self) -> u32 {
+        match self {
+            Rela::RelaMips64(x) => x.symbol_index,
+            Rela::RelaRegular(x) => x.info().symbol_index,
+        }
+    }
+
+    pub fn set_symbol_index(&mut self, new_value: u32) {
+        match self {
+            Rela::RelaMips64(x) => x.symbol_index = new_value,
+            Rela::RelaRegular(x) => {
+                let mut info = x.info();
+                info.symbol_index = new_value;
+                x.set_info(info);
+            }
+        }
+    }
     pub fn to_rel_and_addend(self) -> (Rel, i64) {
         match self {
             Rela::RelaMips64(rela_mips64) => {
@@ -304,8 +621,8 @@ impl<'a> VariantStructBinarySerde<'a> for Rela {
         parser: &ElfParser<'a>,
         _context: (),
     ) -> core::result::Result<Self, binary_serde::BinarySerdeBufSafeError> {
-        match (parser.file_info.arch, parser.file_info.bit_length) {
-            (Architechture::Mips, ArchBitLength::Arch64Bit) => {
+        match (parser.file_info.arch.known(), parser.file_info.bit_length) {
+            (Some(Architechture::Mips), ArchBitLength::Arch64Bit) => {
                 Ok(Self::RelaMips64(deserializer.deserialize()?))
             }
             _ => Ok(Self::RelaRegular(RelaRegular::deserialize(
@@ -317,8 +634,8 @@ impl<'a> VariantStructBinarySerde<'a> for Rela {
     }
 
     fn record_len(file_info: &ElfFileInfo) -> usize {
-        match (file_info.arch, file_info.bit_length) {
-            (Architechture::Mips, ArchBitLength::Arch64Bit) => RelaMips64::SERIALIZED_SIZE,
+        match (file_info.arch.known(), file_info.bit_length) {
+            (Some(Architechture::Mips), ArchBitLength::Arch64Bit) => RelaMips64::SERIALIZED_SIZE,
             _ => RelaRegular::record_len(file_info),
         }
     }
@@ -335,6 +652,7 @@ impl<'a> VariantStructBinarySerde<'a> for Rela {
 }
 
 #[derive(Debug, BinarySerde, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum RelocationSpecialSymbolMips64 {
     /// none
@@ -346,13 +664,38 @@ pub enum RelocationSpecialSymbolMips64 {
     /// address of location being relocated
     Loc = 3,
 }
+impl RelocationSpecialSymbolMips64 {
+    pub fn from_raw(raw: u8) -> Option<Self> {
+        Self::binary_deserialize(&[raw], Endianness::Little).ok()
+    }
+}
 
+/// a relocation, along with its addend if it was read from a `SHT_RELA` section.
+///
+/// when serialized on its own, the type of the relocation is emitted as its raw value (for example `"ty": "0x8"`),
+/// since its name depends on the architechture of the file. only the summary returned by
+/// `ElfParser::to_json_summary` also includes the name of the type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GenericRel {
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub rel: Rel,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub addend: Option<i64>,
 }
 impl GenericRel {
+    pub fn offset(&self) -> u64 {
+        self.rel.offset()
+    }
+
+    pub fn symbol_index(&self) -> u32 {
+        self.rel.symbol_index()
+    }
+
+    pub fn raw_type(&self) -> u32 {
+        self.rel.raw_type()
+    }
+
     pub fn as_rel(self) -> Option<Rel> {
         if self.addend.is_none() {
             Some(self.rel)
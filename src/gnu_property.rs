@@ -0,0 +1,277 @@
+use binary_serde::Endianness;
+use bitflags::bitflags;
+
+use crate::{
+    ArchBitLength, Architechture, ElfParser, Error, MaybeUnknown, Note, NoteType,
+    ProgramHeaderType, Result,
+};
+
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+
+bitflags! {
+    /// the x86 control flow enforcement features which all of the objects linked into the file support.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct X86Feature1: u32 {
+        /// indirect branch tracking.
+        const IBT = 0x1;
+        /// shadow stack.
+        const SHSTK = 0x2;
+        const LAM_U48 = 0x4;
+        const LAM_U57 = 0x8;
+    }
+}
+
+bitflags! {
+    /// x86 isa levels, as defined by the x86-64 psabi.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct X86Isa1: u32 {
+        const BASELINE = 0x1;
+        const V2 = 0x2;
+        const V3 = 0x4;
+        const V4 = 0x8;
+    }
+}
+impl X86Isa1 {
+    /// the highest isa level in the set.
+    pub fn highest_level(&self) -> Option<X86IsaLevel> {
+        [
+            (Self::V4, X86IsaLevel::V4),
+            (Self::V3, X86IsaLevel::V3),
+            (Self::V2, X86IsaLevel::V2),
+            (Self::BASELINE, X86IsaLevel::Baseline),
+        ]
+        .into_iter()
+        .find(|(flag, _)| self.contains(*flag))
+        .map(|(_, level)| level)
+    }
+}
+
+/// an x86 isa level. the levels are ordered, and each level includes all of the lower levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X86IsaLevel {
+    Baseline,
+    V2,
+    V3,
+    V4,
+}
+impl X86IsaLevel {
+    /// the name of the level, as used by compilers, for example `x86-64-v2`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Baseline => "x86-64-baseline",
+            Self::V2 => "x86-64-v2",
+            Self::V3 => "x86-64-v3",
+            Self::V4 => "x86-64-v4",
+        }
+    }
+}
+
+bitflags! {
+    /// the aarch64 features which all of the objects linked into the file support.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AArch64Feature1: u32 {
+        /// branch target identification.
+        const BTI = 0x1;
+        /// pointer authentication.
+        const PAC = 0x2;
+        /// guarded control stack.
+        const GCS = 0x4;
+    }
+}
+
+/// a single property of a gnu property note, in its raw form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GnuProperty<'a> {
+    pub ty: u32,
+
+    /// the data of the property, without its padding.
+    pub data: &'a [u8],
+}
+
+/// a gnu property, decoded according to its type and the architechture of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodedGnuProperty<'a> {
+    /// the stack size which the file needs.
+    StackSize(u64),
+    NoCopyOnProtected,
+    X86Feature1And(X86Feature1),
+    X86Isa1Needed(X86Isa1),
+    X86Isa1Used(X86Isa1),
+    AArch64Feature1And(AArch64Feature1),
+
+    /// a property which is not known, or whose data is malformed.
+    Unknown(GnuProperty<'a>),
+}
+
+impl<'a> GnuProperty<'a> {
+    /// decodes the property. the architechture is needed since the meaning of processor specific property types
+    /// depends on it.
+    pub fn decode(
+        &self,
+        arch: MaybeUnknown<Architechture>,
+        endianness: Endianness,
+    ) -> DecodedGnuProperty<'a> {
+        let as_u32 = || -> Option<u32> {
+            let bytes: [u8; 4] = self.data.try_into().ok()?;
+            Some(match endianness {
+                Endianness::Big => u32::from_be_bytes(bytes),
+                Endianness::Little => u32::from_le_bytes(bytes),
+            })
+        };
+        let is_x86 = matches!(
+            arch.known(),
+            Some(Architechture::X8664 | Architechture::I386)
+        );
+        let is_aarch64 = arch.known() == Some(Architechture::AArch64);
+        let decoded = match self.ty {
+            GNU_PROPERTY_STACK_SIZE => match self.data.len() {
+                4 => as_u32().map(|size| DecodedGnuProperty::StackSize(size as u64)),
+                8 => {
+                    let bytes: [u8; 8] = self.data.try_into().unwrap();
+                    Some(DecodedGnuProperty::StackSize(match endianness {
+                        Endianness::Big => u64::from_be_bytes(bytes),
+                        Endianness::Little => u64::from_le_bytes(bytes),
+                    }))
+                }
+                _ => None,
+            },
+            GNU_PROPERTY_NO_COPY_ON_PROTECTED if self.data.is_empty() => {
+                Some(DecodedGnuProperty::NoCopyOnProtected)
+            }
+            GNU_PROPERTY_X86_FEATURE_1_AND if is_x86 => as_u32().map(|bits| {
+                DecodedGnuProperty::X86Feature1And(X86Feature1::from_bits_retain(bits))
+            }),
+            GNU_PROPERTY_X86_ISA_1_NEEDED if is_x86 => as_u32()
+                .map(|bits| DecodedGnuProperty::X86Isa1Needed(X86Isa1::from_bits_retain(bits))),
+            GNU_PROPERTY_X86_ISA_1_USED if is_x86 => as_u32()
+                .map(|bits| DecodedGnuProperty::X86Isa1Used(X86Isa1::from_bits_retain(bits))),
+            GNU_PROPERTY_AARCH64_FEATURE_1_AND if is_aarch64 => as_u32().map(|bits| {
+                DecodedGnuProperty::AArch64Feature1And(AArch64Feature1::from_bits_retain(bits))
+            }),
+            _ => None,
+        };
+        decoded.unwrap_or(DecodedGnuProperty::Unknown(*self))
+    }
+}
+
+/// an iterator over the properties in the descriptor of a `NT_GNU_PROPERTY_TYPE_0` note.
+#[derive(Debug, Clone)]
+pub struct GnuPropertiesIter<'a> {
+    desc: &'a [u8],
+    offset: usize,
+    endianness: Endianness,
+    alignment: usize,
+}
+impl<'a> GnuPropertiesIter<'a> {
+    /// creates an iterator over the properties in the given note descriptor. the properties are padded to 8 bytes in
+    /// 64 bit files, and to 4 bytes in 32 bit files.
+    pub fn new(desc: &'a [u8], endianness: Endianness, bit_length: ArchBitLength) -> Self {
+        Self {
+            desc,
+            offset: 0,
+            endianness,
+            alignment: match bit_length {
+                ArchBitLength::Arch32Bit => 4,
+                ArchBitLength::Arch64Bit => 8,
+            },
+        }
+    }
+}
+impl<'a> Iterator for GnuPropertiesIter<'a> {
+    type Item = Result<GnuProperty<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.desc.len() {
+            return None;
+        }
+        let property_offset = self.offset;
+        // stop iterating after a truncated property, since the following properties can't be located.
+        self.offset = self.desc.len();
+
+        let read_u32 = |offset: usize| -> Option<u32> {
+            let bytes: [u8; 4] = self
+                .desc
+                .get(offset..offset.checked_add(4)?)?
+                .try_into()
+                .unwrap();
+            Some(match self.endianness {
+                Endianness::Big => u32::from_be_bytes(bytes),
+                Endianness::Little => u32::from_le_bytes(bytes),
+            })
+        };
+        let truncated = || {
+            Some(Err(Error::GnuPropertyIsTruncated {
+                offset: property_offset,
+            }))
+        };
+        let (Some(ty), Some(data_size)) =
+            (read_u32(property_offset), read_u32(property_offset + 4))
+        else {
+            return truncated();
+        };
+        let data_start = property_offset + 8;
+        let Some(data) = data_start
+            .checked_add(data_size as usize)
+            .and_then(|data_end| self.desc.get(data_start..data_end))
+        else {
+            return truncated();
+        };
+        self.offset = (data_start + data.len()).next_multiple_of(self.alignment);
+        Some(Ok(GnuProperty { ty, data }))
+    }
+}
+
+impl<'a> Note<'a> {
+    /// returns an iterator over the properties of a `NT_GNU_PROPERTY_TYPE_0` note.
+    pub fn gnu_properties(
+        &self,
+        endianness: Endianness,
+        bit_length: ArchBitLength,
+    ) -> GnuPropertiesIter<'a> {
+        GnuPropertiesIter::new(self.desc, endianness, bit_length)
+    }
+}
+
+impl<'a> ElfParser<'a> {
+    /// returns the gnu property note of the file. the note is found using the `PT_GNU_PROPERTY` segment, or in the
+    /// notes of the file if it has no such segment.
+    pub fn gnu_property_note(&self) -> Result<Option<Note<'a>>> {
+        for program_header in self.program_headers()? {
+            let program_header = program_header?;
+            if *program_header.ty() != ProgramHeaderType::GnuProperty {
+                continue;
+            }
+            for note in program_header.notes()? {
+                let note = note?;
+                if note.is_gnu(NoteType::GnuPropertyType0) {
+                    return Ok(Some(note));
+                }
+            }
+        }
+        self.find_note(|note| note.is_gnu(NoteType::GnuPropertyType0))
+    }
+
+    /// returns an iterator over the gnu properties of the file, or `None` if the file has no gnu property note.
+    pub fn gnu_properties(&self) -> Result<Option<GnuPropertiesIter<'a>>> {
+        let file_info = self.file_info();
+        Ok(self
+            .gnu_property_note()?
+            .map(|note| note.gnu_properties(file_info.endianness, file_info.bit_length)))
+    }
+
+    /// returns the decoded gnu properties of the file, or `None` if the file has no gnu property note.
+    pub fn decoded_gnu_properties(
+        &self,
+    ) -> Result<Option<impl Iterator<Item = Result<DecodedGnuProperty<'a>>> + 'a>> {
+        let arch = *self.header()?.arch();
+        let endianness = self.file_info().endianness;
+        Ok(self.gnu_properties()?.map(move |properties| {
+            properties.map(move |property| Ok(property?.decode(arch, endianness)))
+        }))
+    }
+}
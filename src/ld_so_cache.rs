@@ -0,0 +1,482 @@
+use binary_serde::{BinarySerde, Endianness};
+
+use crate::{
+    deserialize_at, elf_types::DebugIgnore, ArchBitLength, Architechture, ElfFileInfo, Error,
+    Result,
+};
+
+/// the magic of the legacy cache format, which is followed by a padding byte.
+pub const LD_SO_CACHE_OLD_MAGIC: &[u8] = b"ld.so-1.7.0";
+
+/// the magic and version of the new cache format.
+pub const LD_SO_CACHE_NEW_MAGIC: &[u8] = b"glibc-ld.so.cache1.1";
+
+const CACHE_EXTENSION_MAGIC: u32 = 0xeaa42174;
+const CACHE_EXTENSION_TAG_GENERATOR: u32 = 0;
+const CACHE_EXTENSION_TAG_GLIBC_HWCAPS: u32 = 1;
+
+/// the bit of the hwcap field of an entry which indicates that the entry is in a `glibc-hwcaps` subdirectory, whose
+/// index in the hwcaps extension is stored in the low 32 bits of the field.
+pub const DL_CACHE_HWCAP_EXTENSION: u64 = 1 << 62;
+
+pub const LD_SO_CACHE_FLAG_TYPE_MASK: i32 = 0x00ff;
+pub const LD_SO_CACHE_FLAG_LIBC4: i32 = 0x0000;
+pub const LD_SO_CACHE_FLAG_ELF: i32 = 0x0001;
+pub const LD_SO_CACHE_FLAG_ELF_LIBC5: i32 = 0x0002;
+pub const LD_SO_CACHE_FLAG_ELF_LIBC6: i32 = 0x0003;
+pub const LD_SO_CACHE_FLAG_REQUIRED_MASK: i32 = 0xff00;
+pub const LD_SO_CACHE_FLAG_SPARC_LIB64: i32 = 0x0100;
+pub const LD_SO_CACHE_FLAG_IA64_LIB64: i32 = 0x0200;
+pub const LD_SO_CACHE_FLAG_X8664_LIB64: i32 = 0x0300;
+pub const LD_SO_CACHE_FLAG_S390_LIB64: i32 = 0x0400;
+pub const LD_SO_CACHE_FLAG_POWERPC_LIB64: i32 = 0x0500;
+pub const LD_SO_CACHE_FLAG_MIPS64_LIBN32: i32 = 0x0600;
+pub const LD_SO_CACHE_FLAG_MIPS64_LIBN64: i32 = 0x0700;
+pub const LD_SO_CACHE_FLAG_X8664_LIBX32: i32 = 0x0800;
+pub const LD_SO_CACHE_FLAG_ARM_LIBHF: i32 = 0x0900;
+pub const LD_SO_CACHE_FLAG_AARCH64_LIB64: i32 = 0x0a00;
+pub const LD_SO_CACHE_FLAG_ARM_LIBSF: i32 = 0x0b00;
+pub const LD_SO_CACHE_FLAG_MIPS_LIB32_NAN2008: i32 = 0x0c00;
+pub const LD_SO_CACHE_FLAG_MIPS64_LIBN32_NAN2008: i32 = 0x0d00;
+pub const LD_SO_CACHE_FLAG_MIPS64_LIBN64_NAN2008: i32 = 0x0e00;
+pub const LD_SO_CACHE_FLAG_RISCV_FLOAT_ABI_SOFT: i32 = 0x0f00;
+pub const LD_SO_CACHE_FLAG_RISCV_FLOAT_ABI_DOUBLE: i32 = 0x1000;
+pub const LD_SO_CACHE_FLAG_LARCH_FLOAT_ABI_SOFT: i32 = 0x1100;
+pub const LD_SO_CACHE_FLAG_LARCH_FLOAT_ABI_DOUBLE: i32 = 0x1200;
+
+/// the header of the legacy cache format, after its magic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BinarySerde)]
+struct OldCacheHeader {
+    magic_padding: u8,
+    entries_amount: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BinarySerde)]
+struct OldCacheEntry {
+    flags: i32,
+    key: u32,
+    value: u32,
+}
+
+/// the header of the new cache format, after its magic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BinarySerde)]
+struct NewCacheHeader {
+    entries_amount: u32,
+    strings_len: u32,
+    flags: u8,
+    padding: [u8; 3],
+    extension_offset: u32,
+    unused: [u32; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BinarySerde)]
+struct NewCacheEntry {
+    flags: i32,
+    key: u32,
+    value: u32,
+    os_version: u32,
+    hwcap: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BinarySerde)]
+struct CacheExtensionSection {
+    tag: u32,
+    flags: u32,
+    offset: u32,
+    size: u32,
+}
+
+/// the layout of an ld.so cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LdSoCacheFormat {
+    /// the legacy `ld.so-1.7.0` format.
+    Old,
+
+    /// the `glibc-ld.so.cache1.1` format, possibly following a legacy cache for compatibility.
+    New,
+}
+
+/// a library in the ld.so cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LdSoCacheEntry<'a> {
+    /// the type of the library and the abi which it requires.
+    pub flags: i32,
+
+    /// the name of the library, usually its soname.
+    pub name: &'a [u8],
+
+    /// the path of the library.
+    pub path: &'a [u8],
+
+    /// the hwcap requirements of the library, which are always 0 in the legacy format.
+    pub hwcap: u64,
+
+    /// the name of the `glibc-hwcaps` subdirectory which contains the library, if it is in such a subdirectory.
+    pub hwcaps_subdirectory: Option<&'a [u8]>,
+}
+impl<'a> LdSoCacheEntry<'a> {
+    /// the type of the library, which is one of the `LD_SO_CACHE_FLAG_LIBC4`, `LD_SO_CACHE_FLAG_ELF*` values.
+    pub fn file_type(&self) -> i32 {
+        self.flags & LD_SO_CACHE_FLAG_TYPE_MASK
+    }
+
+    /// the abi which the library requires, which is one of the `LD_SO_CACHE_FLAG_*` values in the required mask, or 0
+    /// if it has no special requirements.
+    pub fn required_abi(&self) -> i32 {
+        self.flags & LD_SO_CACHE_FLAG_REQUIRED_MASK
+    }
+
+    pub fn is_elf(&self) -> bool {
+        matches!(
+            self.file_type(),
+            LD_SO_CACHE_FLAG_ELF | LD_SO_CACHE_FLAG_ELF_LIBC5 | LD_SO_CACHE_FLAG_ELF_LIBC6
+        )
+    }
+
+    /// can the library be loaded by an object with the given file info.
+    ///
+    /// the abis which can't be told apart using the file info, like the floating point abis of arm and riscv and the
+    /// mips abis which share a class, are all considered compatible.
+    pub fn is_compatible_with(&self, file_info: &ElfFileInfo) -> bool {
+        self.is_elf() && compatible_required_abis(file_info).contains(&self.required_abi())
+    }
+}
+
+/// the values of the required abi flags which are compatible with an object with the given file info.
+fn compatible_required_abis(file_info: &ElfFileInfo) -> &'static [i32] {
+    let is_64_bit = file_info.bit_length == ArchBitLength::Arch64Bit;
+    let Some(arch) = file_info.arch.known() else {
+        return &[0];
+    };
+    match (arch, is_64_bit) {
+        (Architechture::X8664, true) => &[LD_SO_CACHE_FLAG_X8664_LIB64],
+        (Architechture::X8664, false) => &[LD_SO_CACHE_FLAG_X8664_LIBX32],
+        (Architechture::AArch64, true) => &[LD_SO_CACHE_FLAG_AARCH64_LIB64],
+        (Architechture::S390, true) => &[LD_SO_CACHE_FLAG_S390_LIB64],
+        (Architechture::Ppc64, true) => &[LD_SO_CACHE_FLAG_POWERPC_LIB64],
+        (Architechture::Sparcv9, true) => &[LD_SO_CACHE_FLAG_SPARC_LIB64],
+        (Architechture::Ia64, true) => &[LD_SO_CACHE_FLAG_IA64_LIB64],
+        (Architechture::Mips, true) => &[
+            LD_SO_CACHE_FLAG_MIPS64_LIBN64,
+            LD_SO_CACHE_FLAG_MIPS64_LIBN64_NAN2008,
+        ],
+        (Architechture::Mips, false) => &[
+            0,
+            LD_SO_CACHE_FLAG_MIPS_LIB32_NAN2008,
+            LD_SO_CACHE_FLAG_MIPS64_LIBN32,
+            LD_SO_CACHE_FLAG_MIPS64_LIBN32_NAN2008,
+        ],
+        (Architechture::Arm, _) => &[0, LD_SO_CACHE_FLAG_ARM_LIBHF, LD_SO_CACHE_FLAG_ARM_LIBSF],
+        (Architechture::Riscv, _) => &[
+            LD_SO_CACHE_FLAG_RISCV_FLOAT_ABI_SOFT,
+            LD_SO_CACHE_FLAG_RISCV_FLOAT_ABI_DOUBLE,
+        ],
+        (Architechture::Loongarch, _) => &[
+            LD_SO_CACHE_FLAG_LARCH_FLOAT_ABI_SOFT,
+            LD_SO_CACHE_FLAG_LARCH_FLOAT_ABI_DOUBLE,
+        ],
+        _ => &[0],
+    }
+}
+
+/// a parsed `/etc/ld.so.cache` file.
+#[derive(Debug, Clone)]
+pub struct LdSoCache<'a> {
+    data: DebugIgnore<&'a [u8]>,
+    format: LdSoCacheFormat,
+    endianness: Endianness,
+    entries_offset: usize,
+    entries_amount: usize,
+
+    /// the offset which the string offsets in the entries are relative to.
+    strings_base: usize,
+
+    /// the offset of the array of hwcaps subdirectory name offsets, and its length.
+    hwcaps: Option<(usize, usize)>,
+    generator: Option<&'a [u8]>,
+}
+impl<'a> LdSoCache<'a> {
+    /// parses a cache. the endianness of the new format is taken from its header, and is assumed to be little endian
+    /// if the header doesn't specify it. the legacy format is always assumed to be little endian.
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        if data.starts_with(LD_SO_CACHE_NEW_MAGIC) {
+            return Self::parse_new(data, 0);
+        }
+        if !data.starts_with(LD_SO_CACHE_OLD_MAGIC) {
+            return Err(Error::LdSoCacheMagicIsMissing);
+        }
+        let endianness = Endianness::Little;
+        let header: OldCacheHeader = deserialize_at(
+            data,
+            LD_SO_CACHE_OLD_MAGIC.len(),
+            endianness,
+            Error::LdSoCacheIsTruncated,
+        )?;
+        let entries_offset = LD_SO_CACHE_OLD_MAGIC.len() + OldCacheHeader::SERIALIZED_SIZE;
+        let entries_end = header
+            .entries_amount
+            .checked_mul(OldCacheEntry::SERIALIZED_SIZE as u32)
+            .and_then(|entries_size| entries_offset.checked_add(entries_size as usize))
+            .ok_or(Error::LdSoCacheIsTruncated)?;
+
+        // a new format cache may follow the legacy one, aligned to the alignment of its header.
+        let new_cache_offset = entries_end.next_multiple_of(8);
+        if data
+            .get(new_cache_offset..)
+            .is_some_and(|rest| rest.starts_with(LD_SO_CACHE_NEW_MAGIC))
+        {
+            return Self::parse_new(data, new_cache_offset);
+        }
+        if entries_end > data.len() {
+            return Err(Error::LdSoCacheIsTruncated);
+        }
+        Ok(Self {
+            data: data.into(),
+            format: LdSoCacheFormat::Old,
+            endianness,
+            entries_offset,
+            entries_amount: header.entries_amount as usize,
+            strings_base: entries_end,
+            hwcaps: None,
+            generator: None,
+        })
+    }
+
+    fn parse_new(data: &'a [u8], offset: usize) -> Result<Self> {
+        let header_offset = offset + LD_SO_CACHE_NEW_MAGIC.len();
+        // the endianness is stored in the low 2 bits of the flags, whose offset doesn't depend on the endianness.
+        let flags = *data
+            .get(header_offset + 8)
+            .ok_or(Error::LdSoCacheIsTruncated)?;
+        let endianness = match flags & 0b11 {
+            3 => Endianness::Big,
+            _ => Endianness::Little,
+        };
+        let header: NewCacheHeader =
+            deserialize_at(data, header_offset, endianness, Error::LdSoCacheIsTruncated)?;
+        let entries_offset = header_offset + NewCacheHeader::SERIALIZED_SIZE;
+        let entries_end = header
+            .entries_amount
+            .checked_mul(NewCacheEntry::SERIALIZED_SIZE as u32)
+            .and_then(|entries_size| entries_offset.checked_add(entries_size as usize))
+            .ok_or(Error::LdSoCacheIsTruncated)?;
+        if entries_end > data.len() {
+            return Err(Error::LdSoCacheIsTruncated);
+        }
+        let mut cache = Self {
+            data: data.into(),
+            format: LdSoCacheFormat::New,
+            endianness,
+            entries_offset,
+            entries_amount: header.entries_amount as usize,
+            strings_base: offset,
+            hwcaps: None,
+            generator: None,
+        };
+        // unlike the string offsets, the offsets of the extensions are relative to the start of the file.
+        if header.extension_offset != 0 {
+            cache.parse_extensions(header.extension_offset as usize)?;
+        }
+        Ok(cache)
+    }
+
+    fn parse_extensions(&mut self, offset: usize) -> Result<()> {
+        let magic: u32 = deserialize_at(
+            self.data.0,
+            offset,
+            self.endianness,
+            Error::LdSoCacheIsTruncated,
+        )?;
+        if magic != CACHE_EXTENSION_MAGIC {
+            return Ok(());
+        }
+        let sections_amount: u32 = deserialize_at(
+            self.data.0,
+            offset + 4,
+            self.endianness,
+            Error::LdSoCacheIsTruncated,
+        )?;
+        for i in 0..sections_amount as usize {
+            let section: CacheExtensionSection = deserialize_at(
+                self.data.0,
+                offset + 8 + i * CacheExtensionSection::SERIALIZED_SIZE,
+                self.endianness,
+                Error::LdSoCacheIsTruncated,
+            )?;
+            let section_offset = section.offset as usize;
+            match section.tag {
+                CACHE_EXTENSION_TAG_GENERATOR => {
+                    self.generator = Some(
+                        self.data
+                            .get(section_offset..section_offset + section.size as usize)
+                            .ok_or(Error::LdSoCacheIsTruncated)?,
+                    );
+                }
+                CACHE_EXTENSION_TAG_GLIBC_HWCAPS => {
+                    self.hwcaps = Some((section_offset, section.size as usize / 4));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn format(&self) -> LdSoCacheFormat {
+        self.format
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries_amount
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries_amount == 0
+    }
+
+    /// the description of the program which generated the cache, from the generator extension of the new format.
+    pub fn generator(&self) -> Option<&'a [u8]> {
+        self.generator
+    }
+
+    /// returns the null terminated string at the given offset relative to the strings base of the cache.
+    fn string_at(&self, offset: u32) -> Result<&'a [u8]> {
+        let start = self.strings_base + offset as usize;
+        let rest = self
+            .data
+            .get(start..)
+            .ok_or(Error::LdSoCacheStringOutOfBounds { offset })?;
+        let len = rest
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(Error::LdSoCacheStringOutOfBounds { offset })?;
+        Ok(&rest[..len])
+    }
+
+    fn hwcaps_subdirectory(&self, hwcap: u64) -> Result<Option<&'a [u8]>> {
+        if hwcap & DL_CACHE_HWCAP_EXTENSION == 0 {
+            return Ok(None);
+        }
+        let index = hwcap as u32 as usize;
+        let Some((offset, _)) = self.hwcaps.filter(|&(_, amount)| index < amount) else {
+            return Ok(None);
+        };
+        let string_offset: u32 = deserialize_at(
+            self.data.0,
+            offset + index * 4,
+            self.endianness,
+            Error::LdSoCacheIsTruncated,
+        )?;
+        Ok(Some(self.string_at(string_offset)?))
+    }
+
+    pub fn get(&self, index: usize) -> Result<LdSoCacheEntry<'a>> {
+        if index >= self.entries_amount {
+            return Err(Error::RecordIndexOutOfBounds {
+                record_name: "ld.so cache entry",
+                index,
+                records_amount: self.entries_amount,
+            });
+        }
+        match self.format {
+            LdSoCacheFormat::Old => {
+                let entry: OldCacheEntry = deserialize_at(
+                    self.data.0,
+                    self.entries_offset + index * OldCacheEntry::SERIALIZED_SIZE,
+                    self.endianness,
+                    Error::LdSoCacheIsTruncated,
+                )?;
+                Ok(LdSoCacheEntry {
+                    flags: entry.flags,
+                    name: self.string_at(entry.key)?,
+                    path: self.string_at(entry.value)?,
+                    hwcap: 0,
+                    hwcaps_subdirectory: None,
+                })
+            }
+            LdSoCacheFormat::New => {
+                let entry: NewCacheEntry = deserialize_at(
+                    self.data.0,
+                    self.entries_offset + index * NewCacheEntry::SERIALIZED_SIZE,
+                    self.endianness,
+                    Error::LdSoCacheIsTruncated,
+                )?;
+                Ok(LdSoCacheEntry {
+                    flags: entry.flags,
+                    name: self.string_at(entry.key)?,
+                    path: self.string_at(entry.value)?,
+                    hwcap: entry.hwcap,
+                    hwcaps_subdirectory: self.hwcaps_subdirectory(entry.hwcap)?,
+                })
+            }
+        }
+    }
+
+    pub fn iter(&self) -> LdSoCacheIter<'a> {
+        LdSoCacheIter {
+            cache: self.clone(),
+            index: 0,
+        }
+    }
+
+    /// returns an iterator over the entries with the given name which are compatible with an object with the given
+    /// file info, in the order of the cache.
+    pub fn lookup<'b>(
+        &self,
+        name: &'b [u8],
+        file_info: &'b ElfFileInfo,
+    ) -> impl Iterator<Item = Result<LdSoCacheEntry<'a>>> + 'b
+    where
+        'a: 'b,
+    {
+        self.iter().filter(move |entry| match entry {
+            Ok(entry) => entry.name == name && entry.is_compatible_with(file_info),
+            Err(_) => true,
+        })
+    }
+
+    /// finds the library with the given name which the dynamic loader would use for an object with the given file
+    /// info. since the hardware of the machine which will run the object is not known, libraries in `glibc-hwcaps`
+    /// subdirectories are ignored, unless there is no other choice.
+    pub fn find(&self, name: &[u8], file_info: &ElfFileInfo) -> Result<Option<LdSoCacheEntry<'a>>> {
+        let mut hwcaps_entry = None;
+        for entry in self.lookup(name, file_info) {
+            let entry = entry?;
+            if entry.hwcaps_subdirectory.is_none() {
+                return Ok(Some(entry));
+            }
+            hwcaps_entry.get_or_insert(entry);
+        }
+        Ok(hwcaps_entry)
+    }
+}
+
+impl<'a> IntoIterator for &LdSoCache<'a> {
+    type Item = Result<LdSoCacheEntry<'a>>;
+    type IntoIter = LdSoCacheIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LdSoCacheIter<'a> {
+    cache: LdSoCache<'a>,
+    index: usize,
+}
+impl<'a> Iterator for LdSoCacheIter<'a> {
+    type Item = Result<LdSoCacheEntry<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.cache.len() {
+            return None;
+        }
+        let entry = self.cache.get(self.index);
+        self.index += 1;
+        Some(entry)
+    }
+}
@@ -1,15 +1,95 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod abi_compat;
+#[cfg(all(feature = "std", unix))]
+mod debug_file;
+#[cfg(feature = "demangle")]
+mod demangle;
+#[cfg(all(feature = "std", unix))]
+mod dependencies;
+#[cfg(feature = "alloc")]
+mod dynamic_editor;
+#[cfg(feature = "alloc")]
+mod elf_diff;
 mod elf_types;
+mod gnu_property;
+mod ld_so_cache;
+#[cfg(feature = "alloc")]
+mod memory_image;
+#[cfg(feature = "alloc")]
+mod object_writer;
+#[cfg(feature = "alloc")]
+mod rewriter;
+mod security_report;
+mod segment_mapping;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "alloc")]
+mod string_table_builder;
+#[cfg(feature = "alloc")]
+mod strip;
+#[cfg(feature = "alloc")]
+mod symbol_resolution;
+#[cfg(feature = "alloc")]
+mod symbol_table_builder;
+mod symbol_versions;
 
 use core::marker::PhantomData;
 
-use binary_serde::{BinaryDeserializerFromBufSafe, Endianness};
+#[cfg(feature = "alloc")]
+pub use abi_compat::*;
+use binary_serde::{BinaryDeserializerFromBufSafe, BinarySerde, Endianness};
+#[cfg(all(feature = "std", unix))]
+pub use debug_file::*;
+#[cfg(feature = "demangle")]
+pub use demangle::*;
+#[cfg(all(feature = "std", unix))]
+pub use dependencies::*;
+#[cfg(feature = "alloc")]
+pub use dynamic_editor::*;
+#[cfg(feature = "alloc")]
+pub use elf_diff::*;
 pub use elf_types::*;
+pub use gnu_property::*;
+pub use ld_so_cache::*;
+#[cfg(feature = "alloc")]
+pub use memory_image::*;
+#[cfg(feature = "alloc")]
+pub use object_writer::*;
+#[cfg(feature = "alloc")]
+pub use rewriter::*;
+pub use security_report::*;
+pub use segment_mapping::*;
+#[cfg(feature = "alloc")]
+pub use string_table_builder::*;
+#[cfg(feature = "alloc")]
+pub use strip::*;
+#[cfg(feature = "alloc")]
+pub use symbol_resolution::*;
+#[cfg(feature = "alloc")]
+pub use symbol_table_builder::*;
+pub use symbol_versions::*;
 use thiserror_no_std::Error;
 
-const SHN_UNDEF: u16 = 0;
-const SHN_ABS: u16 = 0xfff1;
+/// the section index of undefined symbols.
+pub const SHN_UNDEF: u16 = 0;
+
+/// the first reserved section index. section indexes starting from this value have special meanings, and are not
+/// indexes of actual sections.
+pub const SHN_LORESERVE: u16 = 0xff00;
+
+/// the section index of symbols with absolute values, which are not affected by relocation.
+pub const SHN_ABS: u16 = 0xfff1;
+
+/// the section index of common symbols, which are not yet allocated.
+pub const SHN_COMMON: u16 = 0xfff2;
+
+/// the section index of symbols whose actual section index is stored in the extended section indexes table.
+pub const SHN_XINDEX: u16 = 0xffff;
 
 #[derive(Debug, Clone)]
 pub struct ElfParser<'a> {
@@ -42,7 +122,7 @@ impl<'a> ElfParser<'a> {
                 // to know the architechture we must first parse the header, but to parse the header we need to have an instance of
                 // a parser, so we use a placeholder here which will be filled later. the architechture shouldn't affect the way
                 // the header is parsed, so it's ok to parse the header while the parser uses a placeholder architechture value.
-                arch: elf_types::Architechture::None,
+                arch: elf_types::Architechture::None.into(),
             },
         };
 
@@ -72,7 +152,7 @@ impl<'a> ElfParser<'a> {
 
     pub fn header(&self) -> Result<ElfHeader> {
         let mut deserializer = self.deserializer();
-        Ok(ElfHeader::deserialize(&mut deserializer, &self, ())?)
+        Ok(ElfHeader::deserialize(&mut deserializer, self, ())?)
     }
 
     fn records_table<T: VariantStructBinarySerde<'a>>(
@@ -84,7 +164,9 @@ impl<'a> ElfParser<'a> {
         context: T::Context,
     ) -> Result<ElfRecordsTable<'a, T>> {
         let record_len = T::record_len(&self.file_info);
-        if specified_record_len != record_len as u64 {
+
+        // empty tables, like the program header table of relocatable files, may specify an entry size of 0.
+        if specified_record_len != record_len as u64 && records_amount != 0 {
             return Err(Error::UnexpectedEntrySize {
                 record_name,
                 expected_size: record_len as u64,
@@ -130,11 +212,13 @@ impl<'a> ElfParser<'a> {
         len: usize,
         offset_range_of_what: &'static str,
     ) -> Result<&'a [u8]> {
-        let offset_range = offset..offset + len;
-        self.data
-            .get(offset_range.clone())
+        // the offset and length may come from untrusted values, for example from the dynamic section, so they may
+        // overflow.
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
             .ok_or(Error::OffsetRangeOutOfBounds {
-                offset_range,
+                offset_range: offset..offset.saturating_add(len),
                 file_len: self.data.len(),
                 offset_range_of_what,
             })
@@ -155,6 +239,316 @@ impl<'a> ElfParser<'a> {
             _ => Err(Error::SectionNamesSectionIsNotAStringTable),
         }
     }
+
+    /// finds a section by its name.
+    pub fn section_by_name(&self, name: impl AsRef<[u8]>) -> Result<Option<SectionHeaderRef<'a>>> {
+        for section in self.section_headers()? {
+            let section = section?;
+            if section.name_bytes()? == name.as_ref() {
+                return Ok(Some(section));
+            }
+        }
+        Ok(None)
+    }
+
+    /// finds the first note which matches the given predicate. the notes are searched in the `PT_NOTE` segments, and
+    /// then in the notes sections, since the segments of separate debug files don't contain the notes.
+    pub fn find_note(
+        &self,
+        mut predicate: impl FnMut(&Note<'a>) -> bool,
+    ) -> Result<Option<Note<'a>>> {
+        for program_header in self.program_headers()? {
+            let program_header = program_header?;
+            if *program_header.ty() != ProgramHeaderType::Note {
+                continue;
+            }
+            for note in program_header.notes()? {
+                let note = note?;
+                if predicate(&note) {
+                    return Ok(Some(note));
+                }
+            }
+        }
+        for section in self.section_headers()? {
+            let section = section?;
+            if *section.ty() != SectionHeaderType::Note {
+                continue;
+            }
+            for note in section.notes()? {
+                let note = note?;
+                if predicate(&note) {
+                    return Ok(Some(note));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// returns the build id of the file, from its `NT_GNU_BUILD_ID` note.
+    pub fn build_id(&self) -> Result<Option<&'a [u8]>> {
+        Ok(self
+            .find_note(|note| note.is_gnu(NoteType::GnuBuildId))?
+            .map(|note| note.desc))
+    }
+
+    /// returns the content of the `.gnu_debuglink` section.
+    pub fn debuglink(&self) -> Result<Option<DebugLink<'a>>> {
+        let Some(section) = self.section_by_name(".gnu_debuglink")? else {
+            return Ok(None);
+        };
+        let content = section.content()?;
+        let file_name_len = content
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(Error::MalformedDebugLink)?;
+        let crc_offset = (file_name_len + 1).next_multiple_of(4);
+        let crc_bytes: [u8; 4] = content
+            .get(crc_offset..crc_offset + 4)
+            .ok_or(Error::MalformedDebugLink)?
+            .try_into()
+            .unwrap();
+        Ok(Some(DebugLink {
+            file_name: &content[..file_name_len],
+            crc: match self.file_info.endianness {
+                Endianness::Big => u32::from_be_bytes(crc_bytes),
+                Endianness::Little => u32::from_le_bytes(crc_bytes),
+            },
+        }))
+    }
+
+    /// returns the content of the `.gnu_debugaltlink` section.
+    pub fn debugaltlink(&self) -> Result<Option<DebugAltLink<'a>>> {
+        let Some(section) = self.section_by_name(".gnu_debugaltlink")? else {
+            return Ok(None);
+        };
+        let content = section.content()?;
+        let file_name_len = content
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(Error::MalformedDebugLink)?;
+        Ok(Some(DebugAltLink {
+            file_name: &content[..file_name_len],
+            build_id: &content[file_name_len + 1..],
+        }))
+    }
+
+    /// translates a virtual address to an offset in the file, using the loadable segments of the file. returns `None`
+    /// if the address is not backed by the content of any loadable segment.
+    pub fn virt_addr_to_file_offset(&self, virt_addr: u64) -> Result<Option<u64>> {
+        for program_header in self.program_headers()? {
+            let program_header = program_header?;
+            if *program_header.ty() != ProgramHeaderType::Load {
+                continue;
+            }
+            let start = program_header.virt_addr();
+            if virt_addr >= start && virt_addr - start < program_header.size_in_file() {
+                return Ok(program_header.offset().checked_add(virt_addr - start));
+            }
+        }
+        Ok(None)
+    }
+
+    fn find_program_header(&self, ty: ProgramHeaderType) -> Result<Option<ProgramHeaderRef<'a>>> {
+        for program_header in self.program_headers()? {
+            let program_header = program_header?;
+            if *program_header.ty() == ty {
+                return Ok(Some(program_header));
+            }
+        }
+        Ok(None)
+    }
+
+    /// returns the path of the program interpreter specified by the `PT_INTERP` segment, without its null terminator.
+    pub fn interpreter(&self) -> Result<Option<&'a [u8]>> {
+        let Some(program_header) = self.find_program_header(ProgramHeaderType::Interp)? else {
+            return Ok(None);
+        };
+        let content = program_header.content_in_file()?;
+        let len = content
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(content.len());
+        Ok(Some(&content[..len]))
+    }
+
+    /// returns the dynamic section of the file, which is found using the `PT_DYNAMIC` segment, or using the section
+    /// headers if the file has no program headers.
+    ///
+    /// the dynamic string table is found using the `DT_STRTAB` and `DT_STRSZ` entries when the dynamic section is found
+    /// using the program headers, and using the linked section of the dynamic section otherwise.
+    pub fn dynamic_section(&self) -> Result<Option<DynamicSection<'a>>> {
+        if let Some(program_header) = self.find_program_header(ProgramHeaderType::Dynamic)? {
+            let entries: DynamicEntries<'a> = self.records_table(
+                program_header.offset() as usize,
+                DynamicEntryRef::record_len(&self.file_info) as u64,
+                program_header.size_in_file() as usize
+                    / DynamicEntryRef::record_len(&self.file_info),
+                "dynamic entry",
+                (),
+            )?;
+            let dynamic = DynamicSection {
+                entries,
+                string_table: StringTable {
+                    content: (&[][..]).into(),
+                },
+            };
+            let (Some(strtab_addr), Some(strtab_size)) = (
+                dynamic.value_of(DynamicTag::Strtab as i64)?,
+                dynamic.value_of(DynamicTag::Strsz as i64)?,
+            ) else {
+                return Ok(Some(dynamic));
+            };
+            let Some(strtab_offset) = self.virt_addr_to_file_offset(strtab_addr)? else {
+                return Ok(Some(dynamic));
+            };
+            let content = self.get_offset_range_content(
+                strtab_offset as usize,
+                strtab_size as usize,
+                "dynamic string table",
+            )?;
+            return Ok(Some(DynamicSection {
+                string_table: StringTable {
+                    content: content.into(),
+                },
+                ..dynamic
+            }));
+        }
+        for section in self.section_headers()? {
+            let section = section?;
+            if let SectionData::Dynamic(entries) = section.data()? {
+                let string_table = match self
+                    .section_headers()?
+                    .get(section.link() as usize)?
+                    .data()?
+                {
+                    SectionData::StringTable(string_table) => string_table,
+                    _ => StringTable {
+                        content: (&[][..]).into(),
+                    },
+                };
+                return Ok(Some(DynamicSection {
+                    entries,
+                    string_table,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// the dynamic section of an elf file, along with the dynamic string table which its entries refer to.
+#[derive(Debug, Clone)]
+pub struct DynamicSection<'a> {
+    entries: DynamicEntries<'a>,
+    string_table: StringTable<'a>,
+}
+impl<'a> DynamicSection<'a> {
+    /// all entries of the dynamic section, including the terminating `DT_NULL` entry and any padding after it.
+    pub fn entries(&self) -> &DynamicEntries<'a> {
+        &self.entries
+    }
+
+    /// the dynamic string table. empty if the file doesn't specify one.
+    pub fn string_table(&self) -> &StringTable<'a> {
+        &self.string_table
+    }
+
+    /// returns an iterator over the entries of the dynamic section which come before the terminating `DT_NULL` entry.
+    pub fn live_entries(&self) -> DynamicLiveEntriesIter<'a> {
+        DynamicLiveEntriesIter {
+            entries: self.entries.iter(),
+            reached_end: false,
+        }
+    }
+
+    /// returns the value of the first entry with the given tag.
+    pub fn value_of(&self, tag: i64) -> Result<Option<u64>> {
+        for entry in self.live_entries() {
+            let entry = entry?;
+            if entry.tag() == tag {
+                return Ok(Some(entry.value()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// returns the string referenced by the first entry with the given tag.
+    pub fn string_of(&self, tag: i64) -> Result<Option<&'a [u8]>> {
+        let Some(offset) = self.value_of(tag)? else {
+            return Ok(None);
+        };
+        Ok(Some(self.string_table.bytes_at_offset(
+            offset as usize,
+            "dynamic entry string",
+        )?))
+    }
+
+    /// returns the names of the libraries specified by the `DT_NEEDED` entries, in order.
+    pub fn needed(&self) -> DynamicNeededIter<'a> {
+        DynamicNeededIter {
+            entries: self.live_entries(),
+            string_table: self.string_table.clone(),
+        }
+    }
+
+    pub fn soname(&self) -> Result<Option<&'a [u8]>> {
+        self.string_of(DynamicTag::Soname as i64)
+    }
+
+    pub fn rpath(&self) -> Result<Option<&'a [u8]>> {
+        self.string_of(DynamicTag::Rpath as i64)
+    }
+
+    pub fn runpath(&self) -> Result<Option<&'a [u8]>> {
+        self.string_of(DynamicTag::Runpath as i64)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynamicLiveEntriesIter<'a> {
+    entries: DynamicEntriesIter<'a>,
+    reached_end: bool,
+}
+impl<'a> Iterator for DynamicLiveEntriesIter<'a> {
+    type Item = Result<DynamicEntryRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reached_end {
+            return None;
+        }
+        let entry = self.entries.next()?;
+        if let Ok(entry) = &entry {
+            if entry.tag() == DynamicTag::Null as i64 {
+                self.reached_end = true;
+                return None;
+            }
+        }
+        Some(entry)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynamicNeededIter<'a> {
+    entries: DynamicLiveEntriesIter<'a>,
+    string_table: StringTable<'a>,
+}
+impl<'a> Iterator for DynamicNeededIter<'a> {
+    type Item = Result<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = match self.entries.next()? {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+            if entry.tag() == DynamicTag::Needed as i64 {
+                return Some(
+                    self.string_table
+                        .bytes_at_offset(entry.value() as usize, "needed library name"),
+                );
+            }
+        }
+    }
 }
 
 impl<'a> SectionHeaderRef<'a> {
@@ -172,6 +566,26 @@ impl<'a> SectionHeaderRef<'a> {
             .string_at_offset(self.name_offset() as usize, "section name")
     }
 
+    /// returns the raw bytes of the section's name, which may not be valid utf8.
+    pub fn name_bytes(&self) -> Result<&'a [u8]> {
+        self.parser
+            .section_names_string_table()?
+            .bytes_at_offset(self.name_offset() as usize, "section name")
+    }
+
+    pub fn name_lossy(&self) -> Result<LossyStr<'a>> {
+        Ok(LossyStr(self.name_bytes()?))
+    }
+
+    /// returns an iterator over the notes of a `SHT_NOTE` section.
+    pub fn notes(&self) -> Result<NotesIter<'a>> {
+        Ok(NotesIter::new(
+            self.content()?,
+            self.parser.file_info.endianness,
+            self.address_alignemnt(),
+        ))
+    }
+
     fn generic_rel_section_build(
         &self,
         entries: GenericRelEntries<'a>,
@@ -209,7 +623,10 @@ impl<'a> SectionHeaderRef<'a> {
     }
 
     pub fn data(&self) -> Result<SectionData<'a>> {
-        match self.ty() {
+        let Some(ty) = self.ty().known() else {
+            return Ok(SectionData::UnknownSectionType);
+        };
+        match ty {
             SectionHeaderType::Strtab => Ok(SectionData::StringTable(StringTable {
                 content: self.content()?.into(),
             })),
@@ -241,6 +658,13 @@ impl<'a> SectionHeaderRef<'a> {
             SectionHeaderType::Dynsym => Ok(SectionData::DynamicSymbolTable(
                 self.parse_as_symbol_table()?,
             )),
+            SectionHeaderType::Dynamic => Ok(SectionData::Dynamic(self.parser.records_table(
+                self.offset() as usize,
+                self.entry_size(),
+                (self.size() / self.entry_size()) as usize,
+                "dynamic entry",
+                (),
+            )?)),
             _ => Ok(SectionData::UnknownSectionType),
         }
     }
@@ -252,6 +676,7 @@ pub enum SectionData<'a> {
     SymbolTable(SymbolEntries<'a>),
     DynamicSymbolTable(SymbolEntries<'a>),
     RelocationSection(GenericRelSection<'a>),
+    Dynamic(DynamicEntries<'a>),
     UnknownSectionType,
 }
 
@@ -260,8 +685,8 @@ pub type SymbolEntriesIter<'a> = ElfRecordsTableIter<'a, SymbolRef<'a>>;
 
 impl<'a> SymbolRef<'a> {
     pub fn name(&self) -> Result<&'a str> {
-        match self.info().ty {
-            elf_types::SymbolType::Section => self
+        match self.info().ty.known() {
+            Some(elf_types::SymbolType::Section) => self
                 .section()?
                 .as_optional_section()
                 .ok_or(Error::SectionSymbolHasNoSectionIndex)?
@@ -273,6 +698,25 @@ impl<'a> SymbolRef<'a> {
         }
     }
 
+    /// returns the raw bytes of the symbol's name, which may not be valid utf8.
+    pub fn name_bytes(&self) -> Result<&'a [u8]> {
+        match self.info().ty.known() {
+            Some(elf_types::SymbolType::Section) => self
+                .section()?
+                .as_optional_section()
+                .ok_or(Error::SectionSymbolHasNoSectionIndex)?
+                .name_bytes(),
+            _ => self
+                .context
+                .string_table
+                .bytes_at_offset(self.name_index_in_string_table() as usize, "symbol name"),
+        }
+    }
+
+    pub fn name_lossy(&self) -> Result<LossyStr<'a>> {
+        Ok(LossyStr(self.name_bytes()?))
+    }
+
     pub fn section(&self) -> Result<SymbolSection<'a>> {
         match self.related_section_index() {
             SHN_UNDEF => Ok(SymbolSection::UndefinedSection),
@@ -284,6 +728,7 @@ impl<'a> SymbolRef<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
 pub enum SymbolSection<'a> {
     /// the symbol is not defined relative to any section
     UndefinedSection,
@@ -327,10 +772,94 @@ impl<'a> GenericRelSection<'a> {
                 Ok(symbols)
             }
             _ => Err(Error::LinkedSectionOfRelocationSectionIsNotASymbolTable {
-                linked_section_index: self.linked_symbol_table_index as usize,
+                linked_section_index: self.linked_symbol_table_index,
             }),
         }
     }
+
+    /// returns an iterator over the entries of this relocation section, where each entry is resolved to the symbol that
+    /// it references and to the address that it patches.
+    pub fn resolved_entries(&self) -> Result<ResolvedRelocationsIter<'a>> {
+        let symbols = if self.linked_symbol_table_index == SHN_UNDEF as usize {
+            None
+        } else {
+            Some(self.linked_symbol_table()?)
+        };
+
+        // in relocatable files the offset of a relocation is relative to the start of the relocated section, while in
+        // linked files it is the virtual address of the relocated location.
+        let base_address = if *self.parser.header()?.ty() == ElfFileType::Rel {
+            self.relocated_section()?.address()
+        } else {
+            0
+        };
+
+        Ok(ResolvedRelocationsIter {
+            entries: self.entries.iter(),
+            symbols,
+            base_address,
+        })
+    }
+}
+
+/// a relocation entry along with the symbol that it references and the address that it patches.
+#[derive(Debug, Clone)]
+pub struct ResolvedRelocation<'a> {
+    pub rel: GenericRel,
+
+    /// the symbol referenced by the relocation, or `None` if the relocation doesn't reference any symbol.
+    pub symbol: Option<SymbolRef<'a>>,
+
+    /// the address of the location that is patched by the relocation.
+    pub address: u64,
+}
+impl<'a> ResolvedRelocation<'a> {
+    pub fn symbol_name(&self) -> Result<Option<&'a str>> {
+        self.symbol.as_ref().map(|symbol| symbol.name()).transpose()
+    }
+
+    pub fn symbol_section(&self) -> Result<Option<SymbolSection<'a>>> {
+        self.symbol
+            .as_ref()
+            .map(|symbol| symbol.section())
+            .transpose()
+    }
+
+    /// the mips64 special symbol of the relocation, if any.
+    pub fn special_symbol(&self) -> Option<RelocationSpecialSymbolMips64> {
+        self.rel
+            .rel
+            .mips64_special_symbol()
+            .filter(|special_symbol| *special_symbol != RelocationSpecialSymbolMips64::Undefined)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolvedRelocationsIter<'a> {
+    entries: GenericRelEntriesIter<'a>,
+    symbols: Option<SymbolEntries<'a>>,
+    base_address: u64,
+}
+impl<'a> ResolvedRelocationsIter<'a> {
+    fn resolve(&self, rel: GenericRel) -> Result<ResolvedRelocation<'a>> {
+        let symbol = match (rel.symbol_index(), &self.symbols) {
+            (0, _) | (_, None) => None,
+            (symbol_index, Some(symbols)) => Some(symbols.get(symbol_index as usize)?),
+        };
+        Ok(ResolvedRelocation {
+            address: self.base_address.wrapping_add(rel.offset()),
+            rel,
+            symbol,
+        })
+    }
+}
+impl<'a> Iterator for ResolvedRelocationsIter<'a> {
+    type Item = Result<ResolvedRelocation<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rel = self.entries.next()?;
+        Some(rel.and_then(|rel| self.resolve(rel)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -362,7 +891,7 @@ impl<'a> IntoIterator for GenericRelEntries<'a> {
         self.iter()
     }
 }
-impl<'a, 'r> IntoIterator for &'r GenericRelEntries<'a> {
+impl<'a> IntoIterator for &GenericRelEntries<'a> {
     type Item = Result<GenericRel>;
 
     type IntoIter = GenericRelEntriesIter<'a>;
@@ -399,7 +928,12 @@ pub struct StringTable<'a> {
     content: DebugIgnore<&'a [u8]>,
 }
 impl<'a> StringTable<'a> {
-    pub fn string_at_offset(&self, offset: usize, offset_of_what: &'static str) -> Result<&'a str> {
+    pub fn content(&self) -> &'a [u8] {
+        &self.content
+    }
+
+    /// returns the bytes of the string at the given offset, without the null terminator.
+    pub fn bytes_at_offset(&self, offset: usize, offset_of_what: &'static str) -> Result<&'a [u8]> {
         let slice = self
             .content
             .get(offset..)
@@ -410,7 +944,81 @@ impl<'a> StringTable<'a> {
             })?;
         let cstr = core::ffi::CStr::from_bytes_until_nul(slice)
             .map_err(|_| Error::StringTableNotNullTerminated)?;
-        cstr.to_str().map_err(|_| Error::StringTableInvalidUtf8)
+        Ok(cstr.to_bytes())
+    }
+
+    pub fn string_at_offset(&self, offset: usize, offset_of_what: &'static str) -> Result<&'a str> {
+        core::str::from_utf8(self.bytes_at_offset(offset, offset_of_what)?)
+            .map_err(|_| Error::StringTableInvalidUtf8)
+    }
+
+    /// returns the string at the given offset, replacing any invalid utf8 sequences in it with the replacement
+    /// character when it is displayed.
+    pub fn string_at_offset_lossy(
+        &self,
+        offset: usize,
+        offset_of_what: &'static str,
+    ) -> Result<LossyStr<'a>> {
+        Ok(LossyStr(self.bytes_at_offset(offset, offset_of_what)?))
+    }
+
+    /// returns an iterator over all strings in the string table, along with their offsets.
+    pub fn strings(&self) -> StringTableStringsIter<'a> {
+        StringTableStringsIter {
+            content: &self.content,
+            offset: 0,
+        }
+    }
+}
+
+/// an iterator over the strings of a string table, which yields the offset of each string along with its bytes.
+///
+/// trailing bytes at the end of the table which are not null terminated are ignored.
+#[derive(Debug, Clone)]
+pub struct StringTableStringsIter<'a> {
+    content: &'a [u8],
+    offset: usize,
+}
+impl<'a> Iterator for StringTableStringsIter<'a> {
+    type Item = (usize, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.content.get(self.offset..)?;
+        let len = remaining.iter().position(|&byte| byte == 0)?;
+        let offset = self.offset;
+        self.offset += len + 1;
+        Some((offset, &remaining[..len]))
+    }
+}
+
+/// a string which may contain invalid utf8 sequences. when displayed, invalid sequences are replaced with the
+/// replacement character.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LossyStr<'a>(pub &'a [u8]);
+impl<'a> LossyStr<'a> {
+    pub fn bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// returns the string if it is valid utf8.
+    pub fn as_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.0).ok()
+    }
+}
+impl<'a> core::fmt::Display for LossyStr<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for chunk in self.0.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_str(char::REPLACEMENT_CHARACTER.encode_utf8(&mut [0; 4]))?;
+            }
+        }
+        Ok(())
+    }
+}
+impl<'a> core::fmt::Debug for LossyStr<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\"{}\"", self.0.escape_ascii())
     }
 }
 
@@ -422,11 +1030,128 @@ impl<'a> ProgramHeaderRef<'a> {
             "program header content",
         )
     }
+
+    /// returns an iterator over the notes of a `PT_NOTE` segment.
+    pub fn notes(&self) -> Result<NotesIter<'a>> {
+        Ok(NotesIter::new(
+            self.content_in_file()?,
+            self.parser.file_info.endianness,
+            self.alignment(),
+        ))
+    }
+}
+
+/// a note from a notes section or segment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Note<'a> {
+    /// the name of the owner of the note, without its null terminator.
+    pub name: &'a [u8],
+    pub ty: u32,
+    pub desc: &'a [u8],
+}
+impl<'a> Note<'a> {
+    /// is this a gnu note of the given type.
+    pub fn is_gnu(&self, ty: NoteType) -> bool {
+        self.name == GNU_NOTE_NAME && self.ty == ty as u32
+    }
+}
+
+/// an iterator over the notes of a notes section or segment.
+#[derive(Debug, Clone)]
+pub struct NotesIter<'a> {
+    data: DebugIgnore<&'a [u8]>,
+    offset: usize,
+    endianness: Endianness,
+    alignment: usize,
+}
+impl<'a> NotesIter<'a> {
+    /// creates an iterator over the notes in the given data. the alignment is the alignment of the section or segment
+    /// which contains the notes, which determines the padding of the notes. notes are padded to 4 bytes, unless their
+    /// alignment is 8.
+    pub fn new(data: &'a [u8], endianness: Endianness, alignment: u64) -> Self {
+        Self {
+            data: data.into(),
+            offset: 0,
+            endianness,
+            alignment: if alignment == 8 { 8 } else { 4 },
+        }
+    }
+}
+impl<'a> Iterator for NotesIter<'a> {
+    type Item = Result<Note<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let note_offset = self.offset;
+        // stop iterating after a truncated note, since the following notes can't be located.
+        self.offset = self.data.len();
+        let align = |value: usize| value.checked_next_multiple_of(self.alignment);
+
+        let rest = &self.data[note_offset..];
+        let Some(header) = rest
+            .get(..NoteHeader::SERIALIZED_SIZE)
+            .and_then(|buf| NoteHeader::binary_deserialize(buf, self.endianness).ok())
+        else {
+            return Some(Err(Error::NoteIsTruncated {
+                offset: note_offset,
+            }));
+        };
+        let name_start = NoteHeader::SERIALIZED_SIZE;
+        let name_end = name_start + header.name_size as usize;
+        let Some(desc_start) = align(name_end) else {
+            return Some(Err(Error::NoteIsTruncated {
+                offset: note_offset,
+            }));
+        };
+        let desc_end = desc_start.saturating_add(header.desc_size as usize);
+        let (Some(name), Some(desc), Some(note_end)) = (
+            rest.get(name_start..name_end),
+            rest.get(desc_start..desc_end),
+            align(desc_end),
+        ) else {
+            return Some(Err(Error::NoteIsTruncated {
+                offset: note_offset,
+            }));
+        };
+        let name_len = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+        self.offset = note_offset + note_end;
+        Some(Ok(Note {
+            name: &name[..name_len],
+            ty: header.ty,
+            desc,
+        }))
+    }
+}
+
+/// the `.gnu_debuglink` section of a stripped file, which links it to a separate debug file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugLink<'a> {
+    /// the file name of the debug file.
+    pub file_name: &'a [u8],
+
+    /// the crc32 checksum of the debug file.
+    pub crc: u32,
+}
+
+/// the `.gnu_debugaltlink` section of a debug file, which links it to a supplementary debug file containing debug
+/// information which is shared by multiple debug files.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugAltLink<'a> {
+    /// the path of the supplementary debug file.
+    pub file_name: &'a [u8],
+
+    /// the build id of the supplementary debug file.
+    pub build_id: &'a [u8],
 }
 
 pub type ProgramHeaders<'a> = ElfRecordsTable<'a, ProgramHeaderRef<'a>>;
 pub type ProgramHeadersIter<'a> = ElfRecordsTableIter<'a, ProgramHeaderRef<'a>>;
 
+pub type DynamicEntries<'a> = ElfRecordsTable<'a, DynamicEntryRef<'a>>;
+pub type DynamicEntriesIter<'a> = ElfRecordsTableIter<'a, DynamicEntryRef<'a>>;
+
 pub type SectionHeaders<'a> = ElfRecordsTable<'a, SectionHeaderRef<'a>>;
 pub type SectionHeadersIter<'a> = ElfRecordsTableIter<'a, SectionHeaderRef<'a>>;
 
@@ -444,8 +1169,11 @@ impl<'a, T: VariantStructBinarySerde<'a>> ElfRecordsTable<'a, T> {
     pub fn len(&self) -> usize {
         self.table_records_amount
     }
+    pub fn is_empty(&self) -> bool {
+        self.table_records_amount == 0
+    }
     pub fn get(&self, index: usize) -> Result<T> {
-        if index > self.table_records_amount {
+        if index >= self.table_records_amount {
             return Err(Error::RecordIndexOutOfBounds {
                 record_name: self.record_name,
                 index,
@@ -486,7 +1214,7 @@ impl<'a, T: VariantStructBinarySerde<'a>> IntoIterator for ElfRecordsTable<'a, T
     }
 }
 
-impl<'r, 'a, T: VariantStructBinarySerde<'a>> IntoIterator for &'r ElfRecordsTable<'a, T> {
+impl<'a, T: VariantStructBinarySerde<'a>> IntoIterator for &ElfRecordsTable<'a, T> {
     type Item = Result<T>;
 
     type IntoIter = ElfRecordsTableIter<'a, T>;
@@ -520,6 +1248,33 @@ impl<'a, T: VariantStructBinarySerde<'a>> Iterator for ElfRecordsTableIter<'a, T
     }
 }
 
+/// serializes the given value and appends it to the end of the given buffer.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_serialized<T: binary_serde::BinarySerde>(
+    buf: &mut alloc::vec::Vec<u8>,
+    value: &T,
+    endianness: Endianness,
+) {
+    let start = buf.len();
+    buf.resize(start + T::SERIALIZED_SIZE, 0);
+    value.binary_serialize(&mut buf[start..], endianness);
+}
+
+/// deserializes the value at the given offset of the given data, or returns the given error if the data is too short to
+/// contain it.
+pub(crate) fn deserialize_at<T: binary_serde::BinarySerde>(
+    data: &[u8],
+    offset: usize,
+    endianness: Endianness,
+    truncated_error: Error,
+) -> Result<T> {
+    let buf = offset
+        .checked_add(T::SERIALIZED_SIZE)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(truncated_error)?;
+    T::binary_deserialize(buf, endianness).map_err(|err| Error::BinaryDeserializeError(err.into()))
+}
+
 pub trait VariantStructBinarySerde<'a>: Sized {
     type Context: Clone;
     fn deserialize(
@@ -595,6 +1350,84 @@ pub enum Error {
 
     #[error("symbol of type section has no section index")]
     SectionSymbolHasNoSectionIndex,
+
+    #[error("strings in a string table can't contain null bytes")]
+    StringContainsNullByte,
+
+    #[error("string table is too large")]
+    StringTableIsTooLarge,
+
+    #[error("the {value_of_what} doesn't fit in its field in the elf file")]
+    ValueOutOfRange { value_of_what: &'static str },
+
+    #[error("the written file would have {amount} sections, but extended section numbering is not supported")]
+    TooManySections { amount: usize },
+
+    #[error("can't append data to a nobits section")]
+    AppendToNobitsSection,
+
+    #[error("can't reserve bss space in a section which is not a nobits section")]
+    ReserveBssInSectionWithContent,
+
+    #[error("relocations of this architechture can't have explicit addends")]
+    ExplicitAddendNotSupported,
+
+    #[error("the content of section {section_index} grew and doesn't fit in its place, but it can't be moved since it is loaded to memory")]
+    SectionDoesntFitInSegment { section_index: usize },
+
+    #[error("symbol {symbol_index} is defined in a removed section, but is still referenced by section {section_index}")]
+    RemovedSymbolIsReferenced {
+        symbol_index: usize,
+        section_index: usize,
+    },
+
+    #[error("dynamic symbol {symbol_index} is defined in a removed section")]
+    DynamicSymbolInRemovedSection { symbol_index: usize },
+
+    #[error("no program header can be used to describe a new loadable segment")]
+    NoRoomForNewSegment,
+
+    #[error("elf has no dynamic section")]
+    NoDynamicSection,
+
+    #[error("the linked section of the dynamic section is not a string table")]
+    LinkedSectionOfDynamicSectionIsNotAStringTable,
+
+    #[error("elf has no program interpreter")]
+    NoInterpreter,
+
+    #[error("note at offset {offset} of a notes section or segment is truncated")]
+    NoteIsTruncated { offset: usize },
+
+    #[error("debug link section is malformed")]
+    MalformedDebugLink,
+
+    #[error("gnu property at offset {offset} of a gnu property note is truncated")]
+    GnuPropertyIsTruncated { offset: usize },
+
+    #[error("ld.so cache magic is missing")]
+    LdSoCacheMagicIsMissing,
+
+    #[error("ld.so cache is truncated")]
+    LdSoCacheIsTruncated,
+
+    #[error("string offset {offset} of ld.so cache entry is out of bounds or not null terminated")]
+    LdSoCacheStringOutOfBounds { offset: u32 },
+
+    #[error("the linked section of a symbol versioning section is not a string table")]
+    LinkedSectionOfVersionSectionIsNotAStringTable,
+
+    #[error("symbol versioning section is truncated")]
+    SymbolVersionSectionIsTruncated,
+
+    #[error("failed to serialize elf file summary to json")]
+    JsonSerializeError,
+
+    #[error("address {address:#x} of the memory image is out of range for the {format} format")]
+    ImageAddressOutOfRange { address: u64, format: &'static str },
+
+    #[error("the contents of the memory image overlap at address {address:#x}")]
+    OverlappingImageContent { address: u64 },
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
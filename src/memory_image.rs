@@ -0,0 +1,356 @@
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use crate::{ElfParser, Error, ProgramHeaderType, Result, SectionHeaderType};
+
+/// the amount of data bytes in each record of the intel hex and s-record formats.
+const RECORD_DATA_LEN: usize = 16;
+
+/// the maximum length of the header of an s-record file.
+const SREC_MAX_HEADER_LEN: usize = 40;
+
+/// which address of a loadable segment determines the location of its content in the memory image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageAddress {
+    /// the physical address (lma) of the segment, which is where its content is stored when flashing the image. this
+    /// is what objcopy uses.
+    #[default]
+    Physical,
+
+    /// the virtual address (vma) of the segment, which is where its content is located at runtime.
+    Virtual,
+}
+
+/// options which control the creation of a memory image by `ElfParser::memory_image`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MemoryImageOptions {
+    address: ImageAddress,
+    gap_fill: Option<u8>,
+    included_sections: Vec<Vec<u8>>,
+    excluded_sections: Vec<Vec<u8>>,
+}
+impl MemoryImageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// places the content at the given kind of addresses. the default is the physical address.
+    pub fn address(mut self, address: ImageAddress) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// fills the gaps between the chunks of the image with the given byte, like objcopy's `--gap-fill`. flat binaries
+    /// are always filled, using zeroes by default, while the intel hex and s-record formats only contain the gaps if a
+    /// gap fill byte is specified.
+    pub fn gap_fill(mut self, byte: u8) -> Self {
+        self.gap_fill = Some(byte);
+        self
+    }
+
+    /// only includes the section with the given name, and the other sections which were included this way, like
+    /// objcopy's `-j`.
+    pub fn include_section(mut self, name: impl Into<Vec<u8>>) -> Self {
+        self.included_sections.push(name.into());
+        self
+    }
+
+    /// excludes the section with the given name, like objcopy's `-R`.
+    pub fn exclude_section(mut self, name: impl Into<Vec<u8>>) -> Self {
+        self.excluded_sections.push(name.into());
+        self
+    }
+
+    fn is_section_included(&self, name: &[u8]) -> bool {
+        (self.included_sections.is_empty()
+            || self
+                .included_sections
+                .iter()
+                .any(|included| included == name))
+            && !self
+                .excluded_sections
+                .iter()
+                .any(|excluded| excluded == name)
+    }
+}
+
+/// a contiguous piece of content of a memory image.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemoryImageChunk<'a> {
+    pub address: u64,
+    pub data: &'a [u8],
+}
+impl<'a> MemoryImageChunk<'a> {
+    pub fn end_address(&self) -> u64 {
+        self.address + self.data.len() as u64
+    }
+}
+
+/// the content of the loadable segments of an elf file, placed at their addresses, which can be exported to the formats
+/// used for flashing microcontrollers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemoryImage<'a> {
+    /// the chunks of the image, sorted by address. chunks never overlap, but there may be gaps between them.
+    pub chunks: Vec<MemoryImageChunk<'a>>,
+
+    /// the entry point of the elf file.
+    pub entry: u64,
+    gap_fill: Option<u8>,
+}
+impl<'a> MemoryImage<'a> {
+    /// the lowest address of the image, or `None` if the image is empty.
+    pub fn start_address(&self) -> Option<u64> {
+        self.chunks.first().map(|chunk| chunk.address)
+    }
+
+    /// the address right after the end of the image, or `None` if the image is empty.
+    pub fn end_address(&self) -> Option<u64> {
+        self.chunks.last().map(MemoryImageChunk::end_address)
+    }
+
+    /// returns the image as a flat binary, like objcopy's `-O binary`. the binary starts at the lowest address of the
+    /// image, and its gaps are filled with the gap fill byte.
+    pub fn to_flat_binary(&self) -> Vec<u8> {
+        let (Some(start), Some(end)) = (self.start_address(), self.end_address()) else {
+            return Vec::new();
+        };
+        let mut binary = vec![self.gap_fill.unwrap_or(0); (end - start) as usize];
+        for chunk in &self.chunks {
+            let offset = (chunk.address - start) as usize;
+            binary[offset..offset + chunk.data.len()].copy_from_slice(chunk.data);
+        }
+        binary
+    }
+
+    /// the chunks which should be written to the intel hex and s-record formats. if a gap fill byte was specified, each
+    /// gap is written as a separate chunk filled with it, like objcopy does.
+    fn record_chunks(&self) -> Vec<(u64, Cow<'_, [u8]>)> {
+        let mut record_chunks = Vec::new();
+        for (i, chunk) in self.chunks.iter().enumerate() {
+            record_chunks.push((chunk.address, Cow::Borrowed(chunk.data)));
+            if let (Some(gap_fill), Some(next)) = (self.gap_fill, self.chunks.get(i + 1)) {
+                let gap_len = (next.address - chunk.end_address()) as usize;
+                if gap_len != 0 {
+                    record_chunks.push((chunk.end_address(), Cow::Owned(vec![gap_fill; gap_len])));
+                }
+            }
+        }
+        record_chunks
+    }
+
+    /// returns the image in the intel hex format, like objcopy's `-O ihex`. addresses below 1MB are written using
+    /// extended segment address records, and higher addresses using extended linear address records.
+    pub fn to_intel_hex(&self) -> Result<String> {
+        let mut out = String::new();
+        let mut segment_base = 0u64;
+        let mut linear_base = 0u64;
+        for (address, data) in self.record_chunks() {
+            let mut address = address;
+            let mut data: &[u8] = &data;
+            while !data.is_empty() {
+                let mut len = data.len().min(RECORD_DATA_LEN);
+                if address < segment_base || address > segment_base + linear_base + 0xffff {
+                    if linear_base == 0 && address <= 0xfffff {
+                        segment_base = address & 0xf0000;
+                        let segment = (segment_base >> 4) as u16;
+                        write_intel_hex_record(&mut out, 2, 0, &segment.to_be_bytes());
+                    } else {
+                        // readers may combine the extended segment address with the extended linear address, so it
+                        // must be reset first.
+                        if segment_base != 0 {
+                            write_intel_hex_record(&mut out, 2, 0, &[0, 0]);
+                            segment_base = 0;
+                        }
+                        if address > 0xffff_ffff {
+                            return Err(Error::ImageAddressOutOfRange {
+                                address,
+                                format: "intel hex",
+                            });
+                        }
+                        linear_base = address & 0xffff_0000;
+                        let upper = (linear_base >> 16) as u16;
+                        write_intel_hex_record(&mut out, 4, 0, &upper.to_be_bytes());
+                    }
+                }
+
+                // records can't cross a 64KB boundary.
+                let record_address = (address - segment_base - linear_base) as usize;
+                len = len.min(0x10000 - record_address);
+                write_intel_hex_record(&mut out, 0, record_address as u16, &data[..len]);
+                address += len as u64;
+                data = &data[len..];
+            }
+        }
+
+        if self.entry != 0 {
+            if self.entry <= 0xfffff {
+                // a `CS:IP` pair.
+                let cs = ((self.entry & 0xf0000) >> 4) as u16;
+                let ip = self.entry as u16;
+                let mut start = [0u8; 4];
+                start[..2].copy_from_slice(&cs.to_be_bytes());
+                start[2..].copy_from_slice(&ip.to_be_bytes());
+                write_intel_hex_record(&mut out, 3, 0, &start);
+            } else {
+                let entry =
+                    u32::try_from(self.entry).map_err(|_| Error::ImageAddressOutOfRange {
+                        address: self.entry,
+                        format: "intel hex",
+                    })?;
+                write_intel_hex_record(&mut out, 5, 0, &entry.to_be_bytes());
+            }
+        }
+        write_intel_hex_record(&mut out, 1, 0, &[]);
+        Ok(out)
+    }
+
+    /// returns the image in the motorola s-record format, like objcopy's `-O srec`. the header record contains the
+    /// given header, which objcopy sets to the name of the output file, truncated to 40 bytes. the smallest address
+    /// size which fits all data records is used.
+    pub fn to_srec(&self, header: &[u8]) -> Result<String> {
+        let max_address = self.end_address().map_or(0, |end| end.saturating_sub(1));
+        let address_len = match max_address {
+            0..=0xffff => 2,
+            0x10000..=0xffffff => 3,
+            0x1000000..=0xffffffff => 4,
+            _ => {
+                return Err(Error::ImageAddressOutOfRange {
+                    address: max_address,
+                    format: "s-record",
+                })
+            }
+        };
+        // the data record types are 1 to 3, and the matching termination record types are 9 to 7.
+        let data_record_type = address_len - 1;
+
+        let mut out = String::new();
+        let header = &header[..header.len().min(SREC_MAX_HEADER_LEN)];
+        write_srec_record(&mut out, 0, 0, 2, header);
+        for (address, data) in self.record_chunks() {
+            for (i, record_data) in data.chunks(RECORD_DATA_LEN).enumerate() {
+                let record_address = address + (i * RECORD_DATA_LEN) as u64;
+                write_srec_record(
+                    &mut out,
+                    data_record_type,
+                    record_address,
+                    address_len,
+                    record_data,
+                );
+            }
+        }
+        write_srec_record(
+            &mut out,
+            10 - data_record_type,
+            self.entry,
+            address_len,
+            &[],
+        );
+        Ok(out)
+    }
+}
+
+/// writes a single intel hex record, whose checksum is the two's complement of the sum of its bytes.
+fn write_intel_hex_record(out: &mut String, record_type: u8, address: u16, data: &[u8]) {
+    let [address_hi, address_lo] = address.to_be_bytes();
+    let header = [data.len() as u8, address_hi, address_lo, record_type];
+    let sum = header
+        .iter()
+        .chain(data)
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    out.push(':');
+    push_hex_bytes(out, &header);
+    push_hex_bytes(out, data);
+    push_hex_bytes(out, &[sum.wrapping_neg()]);
+    out.push_str("\r\n");
+}
+
+/// writes a single s-record, whose checksum is the one's complement of the sum of its length, address and data bytes.
+fn write_srec_record(
+    out: &mut String,
+    record_type: usize,
+    address: u64,
+    address_len: usize,
+    data: &[u8],
+) {
+    let address_bytes = &address.to_be_bytes()[8 - address_len..];
+    let len = (address_len + data.len() + 1) as u8;
+    let sum = address_bytes
+        .iter()
+        .chain(data)
+        .fold(len, |sum, byte| sum.wrapping_add(*byte));
+    // writing to a string never fails.
+    let _ = write!(out, "S{}", record_type);
+    push_hex_bytes(out, &[len]);
+    push_hex_bytes(out, address_bytes);
+    push_hex_bytes(out, data);
+    push_hex_bytes(out, &[!sum]);
+    out.push_str("\r\n");
+}
+
+fn push_hex_bytes(out: &mut String, bytes: &[u8]) {
+    for byte in bytes {
+        let _ = write!(out, "{:02X}", byte);
+    }
+}
+
+impl<'a> ElfParser<'a> {
+    /// returns the memory image of the file, which consists of the file content of its `PT_LOAD` segments, placed at
+    /// their physical or virtual addresses.
+    ///
+    /// like objcopy, only the content of the allocated sections of each segment is included, so padding and headers
+    /// which are a part of a segment are left out. files without section headers include the whole content of their
+    /// segments, and can't be filtered by section.
+    pub fn memory_image(&self, options: &MemoryImageOptions) -> Result<MemoryImage<'a>> {
+        let has_sections = !self.section_headers()?.is_empty();
+        let mut chunks = Vec::new();
+        for segment in self.program_headers()? {
+            let segment = segment?;
+            if *segment.ty() != ProgramHeaderType::Load || segment.size_in_file() == 0 {
+                continue;
+            }
+            let content = segment.content_in_file()?;
+            let base_address = match options.address {
+                ImageAddress::Physical => segment.phys_addr(),
+                ImageAddress::Virtual => segment.virt_addr(),
+            };
+            if !has_sections {
+                chunks.push(MemoryImageChunk {
+                    address: base_address,
+                    data: content,
+                });
+                continue;
+            }
+            for section in segment.sections()? {
+                let section = section?;
+                if *section.ty() == SectionHeaderType::Nobits
+                    || section.size() == 0
+                    || !options.is_section_included(section.name_bytes()?)
+                {
+                    continue;
+                }
+                let offset_in_segment = (section.offset() - segment.offset()) as usize;
+                chunks.push(MemoryImageChunk {
+                    address: base_address + offset_in_segment as u64,
+                    data: &content[offset_in_segment..offset_in_segment + section.size() as usize],
+                });
+            }
+        }
+
+        chunks.sort_by_key(|chunk| chunk.address);
+        // a section which is contained in several segments appears once for each of them.
+        chunks.dedup();
+        for pair in chunks.windows(2) {
+            if pair[1].address < pair[0].end_address() {
+                return Err(Error::OverlappingImageContent {
+                    address: pair[1].address,
+                });
+            }
+        }
+
+        Ok(MemoryImage {
+            chunks,
+            entry: self.header()?.entry(),
+            gap_fill: options.gap_fill,
+        })
+    }
+}
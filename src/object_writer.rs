@@ -0,0 +1,722 @@
+use alloc::vec::Vec;
+
+use binary_serde::{BinarySerde, Endianness};
+
+use crate::{
+    AbiVersion, ArchBitLength, Architechture, ElfEndianness, ElfFileType, ElfFlags, ElfHeader,
+    ElfHeader32, ElfHeader64, ElfIdent, ElfIdentHeader, ElfVersion, ElfVersionInIdent, Error,
+    NewSymbol, OsAbi, RelInfoRegular32, RelInfoRegular64, RelRegular32, RelRegular64, RelaMips64,
+    RelaRegular32, RelaRegular64, RelocationTypeOrUnknown, Result, SectionHeader, SectionHeader32,
+    SectionHeader64, SectionHeaderFlags, SectionHeaderType, StringTableBuilder, SymbolBinding,
+    SymbolId, SymbolInfo, SymbolOtherInfo, SymbolTableBuilder, SymbolTableKind, SymbolType,
+    SymbolVisibility, VariantStructBinarySerde, ELF_IDENT_PADDING_SIZE, ELF_MAGIC, SHN_ABS,
+    SHN_COMMON, SHN_UNDEF,
+};
+
+/// the maximum amount of sections that can be referenced by a 16 bit section index, before reaching the reserved
+/// section indexes.
+const MAX_SECTIONS_AMOUNT: usize = 0xff00;
+
+/// an identifier of a section which was added to an object writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ObjectSectionId(usize);
+
+/// an identifier of a symbol which was added to an object writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ObjectSymbolId(usize);
+
+/// a section of an object file.
+#[derive(Debug, Clone)]
+pub struct ObjectSection {
+    pub name: Vec<u8>,
+    pub ty: SectionHeaderType,
+    pub flags: SectionHeaderFlags,
+    pub alignment: u64,
+    pub entry_size: u64,
+
+    /// the content of the section. empty for `NOBITS` sections.
+    pub data: Vec<u8>,
+
+    /// the size of the section, which is the size of its content for sections which have content in the file.
+    pub size: u64,
+    pub relocations: Vec<ObjectRelocation>,
+}
+
+/// the location that a symbol of an object file refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectSymbolDefinition {
+    /// the symbol is defined at the given offset inside of a section of the object.
+    Section {
+        section: ObjectSectionId,
+        offset: u64,
+    },
+
+    /// the symbol is defined in some other object.
+    Undefined,
+
+    /// the symbol has an absolute value which is not affected by relocation.
+    Absolute(u64),
+
+    /// a common symbol, which will be allocated by the linker with the given alignment.
+    Common { alignment: u64 },
+}
+
+/// a symbol of an object file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectSymbol {
+    pub name: Vec<u8>,
+    pub ty: SymbolType,
+    pub binding: SymbolBinding,
+    pub visibility: SymbolVisibility,
+    pub definition: ObjectSymbolDefinition,
+    pub size: u64,
+}
+
+/// a relocation in a section of an object file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectRelocation {
+    /// the offset of the relocated location inside of the section.
+    pub offset: u64,
+    pub symbol: ObjectSymbolId,
+    pub ty: RelocationTypeOrUnknown,
+    pub addend: i64,
+}
+
+/// a writer for relocatable object files (`ET_REL`), like the ones produced by an assembler.
+///
+/// the writer takes care of emitting the symbol table, the string tables and the relocation sections, and of linking
+/// them to each other.
+#[derive(Debug, Clone)]
+pub struct ObjectWriter {
+    arch: Architechture,
+    bit_length: ArchBitLength,
+    endianness: Endianness,
+    os_abi: OsAbi,
+    flags: ElfFlags,
+    executable_stack: bool,
+    sections: Vec<ObjectSection>,
+    symbols: Vec<ObjectSymbol>,
+
+    /// the section symbol of each section, if one was created.
+    section_symbols: Vec<Option<ObjectSymbolId>>,
+}
+impl ObjectWriter {
+    pub fn new(arch: Architechture, bit_length: ArchBitLength, endianness: Endianness) -> Self {
+        Self {
+            arch,
+            bit_length,
+            endianness,
+            os_abi: OsAbi::Sysv,
+            flags: ElfFlags::empty(),
+            executable_stack: false,
+            sections: Vec::new(),
+            symbols: Vec::new(),
+            section_symbols: Vec::new(),
+        }
+    }
+
+    pub fn set_os_abi(&mut self, os_abi: OsAbi) {
+        self.os_abi = os_abi;
+    }
+
+    /// sets the processor specific flags of the object, for example the floating point abi on risc-v.
+    pub fn set_flags(&mut self, flags: ElfFlags) {
+        self.flags = flags;
+    }
+
+    /// marks whether the code in this object requires an executable stack. this is recorded in the `.note.GNU-stack`
+    /// section.
+    pub fn set_executable_stack(&mut self, executable_stack: bool) {
+        self.executable_stack = executable_stack;
+    }
+
+    /// does the architechture of the object store relocation addends in the relocation entries (`SHT_RELA`), or in the
+    /// relocated location (`SHT_REL`).
+    pub fn uses_rela(&self) -> bool {
+        !matches!(
+            (self.arch, self.bit_length),
+            (Architechture::I386, _)
+                | (Architechture::Arm, _)
+                | (Architechture::Mips, ArchBitLength::Arch32Bit)
+        )
+    }
+
+    pub fn add_section(
+        &mut self,
+        name: impl Into<Vec<u8>>,
+        ty: SectionHeaderType,
+        flags: SectionHeaderFlags,
+    ) -> ObjectSectionId {
+        self.sections.push(ObjectSection {
+            name: name.into(),
+            ty,
+            flags,
+            alignment: 1,
+            entry_size: 0,
+            data: Vec::new(),
+            size: 0,
+            relocations: Vec::new(),
+        });
+        self.section_symbols.push(None);
+        ObjectSectionId(self.sections.len() - 1)
+    }
+
+    /// returns the section with the given name, or adds it if there is no such section.
+    fn standard_section(
+        &mut self,
+        name: &str,
+        ty: SectionHeaderType,
+        flags: SectionHeaderFlags,
+    ) -> ObjectSectionId {
+        match self
+            .sections
+            .iter()
+            .position(|section| section.name == name.as_bytes())
+        {
+            Some(index) => ObjectSectionId(index),
+            None => self.add_section(name, ty, flags),
+        }
+    }
+
+    pub fn text_section(&mut self) -> ObjectSectionId {
+        self.standard_section(
+            ".text",
+            SectionHeaderType::Progbits,
+            SectionHeaderFlags::ALLOC | SectionHeaderFlags::EXECINSTR,
+        )
+    }
+
+    pub fn data_section(&mut self) -> ObjectSectionId {
+        self.standard_section(
+            ".data",
+            SectionHeaderType::Progbits,
+            SectionHeaderFlags::ALLOC | SectionHeaderFlags::WRITE,
+        )
+    }
+
+    pub fn rodata_section(&mut self) -> ObjectSectionId {
+        self.standard_section(
+            ".rodata",
+            SectionHeaderType::Progbits,
+            SectionHeaderFlags::ALLOC,
+        )
+    }
+
+    pub fn bss_section(&mut self) -> ObjectSectionId {
+        self.standard_section(
+            ".bss",
+            SectionHeaderType::Nobits,
+            SectionHeaderFlags::ALLOC | SectionHeaderFlags::WRITE,
+        )
+    }
+
+    pub fn section(&self, id: ObjectSectionId) -> &ObjectSection {
+        &self.sections[id.0]
+    }
+
+    pub fn section_mut(&mut self, id: ObjectSectionId) -> &mut ObjectSection {
+        &mut self.sections[id.0]
+    }
+
+    /// appends the given data to the section at the given alignment, and returns the offset of the data in the
+    /// section.
+    ///
+    /// fails if the section is a `NOBITS` section.
+    pub fn append_data(
+        &mut self,
+        section: ObjectSectionId,
+        data: &[u8],
+        alignment: u64,
+    ) -> Result<u64> {
+        let section = &mut self.sections[section.0];
+        if section.ty == SectionHeaderType::Nobits {
+            return Err(Error::AppendToNobitsSection);
+        }
+        let offset = align_up(section.size, alignment);
+        section.data.resize(offset as usize, 0);
+        section.data.extend_from_slice(data);
+        section.size = section.data.len() as u64;
+        section.alignment = section.alignment.max(alignment);
+        Ok(offset)
+    }
+
+    /// reserves space of the given size in a `NOBITS` section, and returns the offset of the space in the section.
+    ///
+    /// fails if the section is not a `NOBITS` section.
+    pub fn reserve_bss(
+        &mut self,
+        section: ObjectSectionId,
+        size: u64,
+        alignment: u64,
+    ) -> Result<u64> {
+        let section = &mut self.sections[section.0];
+        if section.ty != SectionHeaderType::Nobits {
+            return Err(Error::ReserveBssInSectionWithContent);
+        }
+        let offset = align_up(section.size, alignment);
+        section.size = offset.checked_add(size).ok_or(Error::ValueOutOfRange {
+            value_of_what: "section size",
+        })?;
+        section.alignment = section.alignment.max(alignment);
+        Ok(offset)
+    }
+
+    pub fn add_symbol(&mut self, symbol: ObjectSymbol) -> ObjectSymbolId {
+        self.symbols.push(symbol);
+        ObjectSymbolId(self.symbols.len() - 1)
+    }
+
+    pub fn symbol(&self, id: ObjectSymbolId) -> &ObjectSymbol {
+        &self.symbols[id.0]
+    }
+
+    pub fn symbol_mut(&mut self, id: ObjectSymbolId) -> &mut ObjectSymbol {
+        &mut self.symbols[id.0]
+    }
+
+    /// returns the section symbol of the given section, which can be used for relocations against local data in the
+    /// section, creating it if it doesn't exist yet.
+    pub fn section_symbol(&mut self, section: ObjectSectionId) -> ObjectSymbolId {
+        if let Some(symbol) = self.section_symbols[section.0] {
+            return symbol;
+        }
+        let symbol = self.add_symbol(ObjectSymbol {
+            name: Vec::new(),
+            ty: SymbolType::Section,
+            binding: SymbolBinding::Local,
+            visibility: SymbolVisibility::Default,
+            definition: ObjectSymbolDefinition::Section { section, offset: 0 },
+            size: 0,
+        });
+        self.section_symbols[section.0] = Some(symbol);
+        symbol
+    }
+
+    /// adds a relocation to the given section.
+    ///
+    /// fails if the architechture of the object uses `SHT_REL` relocations and the addend is not 0. in that case the
+    /// addend should be stored in the relocated location instead.
+    pub fn add_relocation(
+        &mut self,
+        section: ObjectSectionId,
+        relocation: ObjectRelocation,
+    ) -> Result<()> {
+        if !self.uses_rela() && relocation.addend != 0 {
+            return Err(Error::ExplicitAddendNotSupported);
+        }
+        self.sections[section.0].relocations.push(relocation);
+        Ok(())
+    }
+
+    /// writes the object file.
+    ///
+    /// fails if the object has too many sections, or if some value doesn't fit in the fields of a 32 bit object.
+    pub fn write(&self) -> Result<Vec<u8>> {
+        let uses_rela = self.uses_rela();
+        let word_size: u64 = match self.bit_length {
+            ArchBitLength::Arch32Bit => 4,
+            ArchBitLength::Arch64Bit => 8,
+        };
+
+        // assign section indexes. each relocation section is placed right after the section that it relocates, and the
+        // null section is at index 0.
+        let mut section_indexes = Vec::with_capacity(self.sections.len());
+        let mut next_index = 1;
+        for section in &self.sections {
+            section_indexes.push(next_index);
+            next_index += if section.relocations.is_empty() { 1 } else { 2 };
+        }
+        let gnu_stack_index = next_index;
+        let symtab_index = gnu_stack_index + 1;
+        let strtab_index = symtab_index + 1;
+        let shstrtab_index = strtab_index + 1;
+        let sections_amount = shstrtab_index + 1;
+        if sections_amount > MAX_SECTIONS_AMOUNT {
+            return Err(Error::TooManySections {
+                amount: sections_amount,
+            });
+        }
+
+        let mut symbol_table = SymbolTableBuilder::new(SymbolTableKind::Static);
+        let symbol_ids: Vec<SymbolId> = self
+            .symbols
+            .iter()
+            .map(|symbol| {
+                let (section_index, value) = match symbol.definition {
+                    ObjectSymbolDefinition::Section { section, offset } => {
+                        (section_indexes[section.0] as u16, offset)
+                    }
+                    ObjectSymbolDefinition::Undefined => (SHN_UNDEF, 0),
+                    ObjectSymbolDefinition::Absolute(value) => (SHN_ABS, value),
+                    ObjectSymbolDefinition::Common { alignment } => (SHN_COMMON, alignment),
+                };
+                symbol_table.add(NewSymbol {
+                    name: symbol.name.clone(),
+                    value,
+                    size: symbol.size,
+                    info: SymbolInfo {
+                        ty: symbol.ty.into(),
+                        binding: symbol.binding.into(),
+                    },
+                    other_info: SymbolOtherInfo {
+                        visibility: symbol.visibility,
+                        padding: 0,
+                    },
+                    section_index,
+                    version: None,
+                })
+            })
+            .collect();
+        let mut strtab = StringTableBuilder::new();
+        let symbol_table = symbol_table.build(&mut strtab, self.bit_length, self.endianness)?;
+
+        let mut shstrtab = StringTableBuilder::new();
+        let mut headers = Vec::with_capacity(sections_amount);
+        headers.push(SectionHeaderFields::default());
+        let mut contents: Vec<Vec<u8>> = alloc::vec![Vec::new()];
+        for section in &self.sections {
+            headers.push(SectionHeaderFields {
+                name_offset: shstrtab.add(&section.name)?,
+                ty: section.ty,
+                flags: section.flags,
+                size: section.size,
+                alignment: section.alignment,
+                entry_size: section.entry_size,
+                ..Default::default()
+            });
+            contents.push(section.data.clone());
+
+            if section.relocations.is_empty() {
+                continue;
+            }
+            let relocated_section_index = headers.len() - 1;
+            let mut relocation_section_name = Vec::from(if uses_rela { ".rela" } else { ".rel" });
+            relocation_section_name.extend_from_slice(&section.name);
+            let content = self.serialize_relocations(&section.relocations, |symbol| {
+                symbol_table.index_of(symbol_ids[symbol.0])
+            })?;
+            headers.push(SectionHeaderFields {
+                name_offset: shstrtab.add(&relocation_section_name)?,
+                ty: if uses_rela {
+                    SectionHeaderType::Rela
+                } else {
+                    SectionHeaderType::Rel
+                },
+                flags: SectionHeaderFlags::INFO_LINK,
+                size: content.len() as u64,
+                link: symtab_index as u32,
+                info: relocated_section_index as u32,
+                alignment: word_size,
+                entry_size: (content.len() / section.relocations.len()) as u64,
+                ..Default::default()
+            });
+            contents.push(content);
+        }
+
+        headers.push(SectionHeaderFields {
+            name_offset: shstrtab.add(".note.GNU-stack")?,
+            ty: SectionHeaderType::Progbits,
+            flags: if self.executable_stack {
+                SectionHeaderFlags::EXECINSTR
+            } else {
+                SectionHeaderFlags::empty()
+            },
+            alignment: 1,
+            ..Default::default()
+        });
+        contents.push(Vec::new());
+
+        let symtab_content = symbol_table.to_bytes();
+        headers.push(SectionHeaderFields {
+            name_offset: shstrtab.add(".symtab")?,
+            ty: SectionHeaderType::Symtab,
+            size: symtab_content.len() as u64,
+            link: strtab_index as u32,
+            info: symbol_table.first_non_local_index,
+            alignment: word_size,
+            entry_size: symbol_table.entry_size() as u64,
+            ..Default::default()
+        });
+        contents.push(symtab_content);
+
+        let strtab_name_offset = shstrtab.add(".strtab")?;
+        let shstrtab_name_offset = shstrtab.add(".shstrtab")?;
+        headers.push(SectionHeaderFields {
+            name_offset: strtab_name_offset,
+            ty: SectionHeaderType::Strtab,
+            size: strtab.len() as u64,
+            alignment: 1,
+            ..Default::default()
+        });
+        contents.push(strtab.into_bytes());
+        headers.push(SectionHeaderFields {
+            name_offset: shstrtab_name_offset,
+            ty: SectionHeaderType::Strtab,
+            size: shstrtab.len() as u64,
+            alignment: 1,
+            ..Default::default()
+        });
+        contents.push(shstrtab.into_bytes());
+
+        // lay out the content of the sections after the elf header, followed by the section header table.
+        let header_size = match self.bit_length {
+            ArchBitLength::Arch32Bit => ElfHeader32::SERIALIZED_SIZE,
+            ArchBitLength::Arch64Bit => ElfHeader64::SERIALIZED_SIZE,
+        };
+        let section_header_size = match self.bit_length {
+            ArchBitLength::Arch32Bit => SectionHeader32::SERIALIZED_SIZE,
+            ArchBitLength::Arch64Bit => SectionHeader64::SERIALIZED_SIZE,
+        };
+        let mut file = alloc::vec![0u8; header_size];
+        for (header, content) in headers.iter_mut().zip(&contents).skip(1) {
+            let offset = align_up(file.len() as u64, header.alignment);
+            file.resize(offset as usize, 0);
+            file.extend_from_slice(content);
+            header.offset = offset;
+        }
+        let section_headers_offset = align_up(file.len() as u64, word_size);
+        file.resize(
+            section_headers_offset as usize + section_header_size * headers.len(),
+            0,
+        );
+        for (header, buf) in headers
+            .iter()
+            .zip(file[section_headers_offset as usize..].chunks_exact_mut(section_header_size))
+        {
+            header
+                .to_section_header(self.bit_length)?
+                .serialize(buf, self.endianness);
+        }
+
+        self.build_header(
+            section_headers_offset,
+            sections_amount as u16,
+            shstrtab_index as u16,
+        )?
+        .serialize(&mut file[..header_size], self.endianness);
+        Ok(file)
+    }
+
+    fn serialize_relocations(
+        &self,
+        relocations: &[ObjectRelocation],
+        symbol_index: impl Fn(ObjectSymbolId) -> u32,
+    ) -> Result<Vec<u8>> {
+        let mut content = Vec::new();
+        for relocation in relocations {
+            let symbol_index = symbol_index(relocation.symbol);
+            let out_of_range = |value_of_what| Error::ValueOutOfRange { value_of_what };
+            let info32 = || -> Result<RelInfoRegular32> {
+                Ok(RelInfoRegular32 {
+                    ty: relocation
+                        .ty
+                        .try_into()
+                        .map_err(|_| out_of_range("relocation type"))?,
+                    symbol_index,
+                })
+            };
+            let info64 = RelInfoRegular64 {
+                ty: relocation.ty,
+                symbol_index,
+            };
+            let offset32 = || -> Result<u32> {
+                relocation
+                    .offset
+                    .try_into()
+                    .map_err(|_| out_of_range("relocation offset"))
+            };
+            match (self.arch, self.bit_length, self.uses_rela()) {
+                (Architechture::Mips, ArchBitLength::Arch64Bit, _) => {
+                    let none = RelocationTypeOrUnknown::from_raw(0).try_into().unwrap();
+                    crate::push_serialized(
+                        &mut content,
+                        &RelaMips64 {
+                            offset: relocation.offset,
+                            symbol_index,
+                            special_symbol: 0,
+                            ty3: none,
+                            ty2: none,
+                            ty: relocation
+                                .ty
+                                .try_into()
+                                .map_err(|_| out_of_range("relocation type"))?,
+                            addend: relocation.addend,
+                        },
+                        self.endianness,
+                    )
+                }
+                (_, ArchBitLength::Arch32Bit, true) => crate::push_serialized(
+                    &mut content,
+                    &RelaRegular32 {
+                        offset: offset32()?,
+                        info: info32()?,
+                        addend: relocation
+                            .addend
+                            .try_into()
+                            .map_err(|_| out_of_range("relocation addend"))?,
+                    },
+                    self.endianness,
+                ),
+                (_, ArchBitLength::Arch32Bit, false) => crate::push_serialized(
+                    &mut content,
+                    &RelRegular32 {
+                        offset: offset32()?,
+                        info: info32()?,
+                    },
+                    self.endianness,
+                ),
+                (_, ArchBitLength::Arch64Bit, true) => crate::push_serialized(
+                    &mut content,
+                    &RelaRegular64 {
+                        offset: relocation.offset,
+                        info: info64,
+                        addend: relocation.addend,
+                    },
+                    self.endianness,
+                ),
+                (_, ArchBitLength::Arch64Bit, false) => crate::push_serialized(
+                    &mut content,
+                    &RelRegular64 {
+                        offset: relocation.offset,
+                        info: info64,
+                    },
+                    self.endianness,
+                ),
+            }
+        }
+        Ok(content)
+    }
+
+    fn build_header(
+        &self,
+        section_headers_offset: u64,
+        sections_amount: u16,
+        section_names_section_index: u16,
+    ) -> Result<ElfHeader> {
+        let ident = ElfIdent {
+            header: ElfIdentHeader {
+                magic: ELF_MAGIC.try_into().unwrap(),
+                bit_size: self.bit_length,
+                endianness: ElfEndianness::from(self.endianness),
+                elf_version: ElfVersionInIdent::Current,
+                os_abi: self.os_abi.into(),
+                abi_version: AbiVersion::Valid.into(),
+            },
+            padding: [0; ELF_IDENT_PADDING_SIZE],
+        };
+        Ok(match self.bit_length {
+            ArchBitLength::Arch32Bit => ElfHeader::ElfHeader32(ElfHeader32 {
+                ident,
+                ty: ElfFileType::Rel.into(),
+                arch: self.arch.into(),
+                version: ElfVersion::Current.into(),
+                entry: 0,
+                program_headers_off: 0,
+                section_headers_off: section_headers_offset.try_into().map_err(|_| {
+                    Error::ValueOutOfRange {
+                        value_of_what: "section headers offset",
+                    }
+                })?,
+                flags: self.flags,
+                header_size: ElfHeader32::SERIALIZED_SIZE as u16,
+                program_header_entry_size: 0,
+                program_headers_amount: 0,
+                section_header_entry_size: SectionHeader32::SERIALIZED_SIZE as u16,
+                section_headers_amount: sections_amount,
+                section_names_section_index,
+            }),
+            ArchBitLength::Arch64Bit => ElfHeader::ElfHeader64(ElfHeader64 {
+                ident,
+                ty: ElfFileType::Rel.into(),
+                arch: self.arch.into(),
+                version: ElfVersion::Current.into(),
+                entry: 0,
+                program_headers_off: 0,
+                section_headers_off: section_headers_offset,
+                flags: self.flags,
+                header_size: ElfHeader64::SERIALIZED_SIZE as u16,
+                program_header_entry_size: 0,
+                program_headers_amount: 0,
+                section_header_entry_size: SectionHeader64::SERIALIZED_SIZE as u16,
+                section_headers_amount: sections_amount,
+                section_names_section_index,
+            }),
+        })
+    }
+}
+
+/// the fields of a section header, before they are converted to a section header of a specific bit length.
+#[derive(Debug, Clone)]
+struct SectionHeaderFields {
+    name_offset: u32,
+    ty: SectionHeaderType,
+    flags: SectionHeaderFlags,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    alignment: u64,
+    entry_size: u64,
+}
+impl Default for SectionHeaderFields {
+    fn default() -> Self {
+        Self {
+            name_offset: 0,
+            ty: SectionHeaderType::Null,
+            flags: SectionHeaderFlags::empty(),
+            offset: 0,
+            size: 0,
+            link: 0,
+            info: 0,
+            alignment: 0,
+            entry_size: 0,
+        }
+    }
+}
+impl SectionHeaderFields {
+    fn to_section_header(&self, bit_length: ArchBitLength) -> Result<SectionHeader> {
+        Ok(match bit_length {
+            ArchBitLength::Arch32Bit => {
+                let to_u32 = |value: u64, value_of_what| -> Result<u32> {
+                    value
+                        .try_into()
+                        .map_err(|_| Error::ValueOutOfRange { value_of_what })
+                };
+                SectionHeader::SectionHeader32(SectionHeader32 {
+                    name_offset: self.name_offset,
+                    ty: self.ty.into(),
+                    flags: self.flags.try_into().map_err(|_| Error::ValueOutOfRange {
+                        value_of_what: "section flags",
+                    })?,
+                    address: 0,
+                    offset: to_u32(self.offset, "section offset")?,
+                    size: to_u32(self.size, "section size")?,
+                    link: self.link,
+                    info: self.info,
+                    address_alignemnt: to_u32(self.alignment, "section alignment")?,
+                    entry_size: to_u32(self.entry_size, "section entry size")?,
+                })
+            }
+            ArchBitLength::Arch64Bit => SectionHeader::SectionHeader64(SectionHeader64 {
+                name_offset: self.name_offset,
+                ty: self.ty.into(),
+                flags: self.flags,
+                address: 0,
+                offset: self.offset,
+                size: self.size,
+                link: self.link,
+                info: self.info,
+                address_alignemnt: self.alignment,
+                entry_size: self.entry_size,
+            }),
+        })
+    }
+}
+
+fn align_up(value: u64, alignment: u64) -> u64 {
+    if alignment <= 1 {
+        value
+    } else {
+        value.div_ceil(alignment) * alignment
+    }
+}
@@ -0,0 +1,1168 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::ops::Range;
+
+use binary_serde::{BinaryDeserializerFromBufSafe, BinarySerde, Endianness};
+
+use crate::{
+    ArchBitLength, ElfHeader, ElfParser, Error, ProgramHeader, ProgramHeaderFlags,
+    ProgramHeaderType, Rel, Rela, Result, SectionHeader, SectionHeader32, SectionHeader64,
+    SectionHeaderFlags, SectionHeaderType, StringTableBuilder, Symbol32, Symbol64,
+    VariantStructBinarySerde, SHN_LORESERVE, SHN_UNDEF, SHN_XINDEX,
+};
+
+/// the minimal alignment of new loadable segments.
+const MIN_PAGE_SIZE: u64 = 0x1000;
+
+/// an identifier of a section in an elf rewriter. the identifier stays valid when other sections are added or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RewriterSectionId(usize);
+
+/// a section of an elf file which is being rewritten.
+#[derive(Debug, Clone)]
+pub struct RewriterSection<'a> {
+    /// the header of the section. the offset, size, link and info fields are fixed up when the file is written.
+    pub header: SectionHeader,
+    name: Cow<'a, [u8]>,
+    content: Cow<'a, [u8]>,
+
+    /// the sections referenced by the link and info fields of the header.
+    link: Option<RewriterSectionId>,
+    info_link: Option<RewriterSectionId>,
+
+    /// the index of the section in the original file, or `None` if the section was added.
+    original_index: Option<usize>,
+
+    /// the range of the file which contained the content of the section in the original file.
+    original_range: Option<Range<usize>>,
+    removed: bool,
+
+    /// was the section moved to a new loadable segment.
+    in_new_segment: bool,
+}
+impl<'a> RewriterSection<'a> {
+    pub fn name(&self) -> &[u8] {
+        &self.name
+    }
+
+    /// the content of the section. empty for `NOBITS` sections.
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    pub fn original_index(&self) -> Option<usize> {
+        self.original_index
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    /// the section referenced by the link field of the section.
+    pub fn link(&self) -> Option<RewriterSectionId> {
+        self.link
+    }
+
+    /// the section referenced by the info field of the section, for sections whose info field contains a section index.
+    pub fn info_link(&self) -> Option<RewriterSectionId> {
+        self.info_link
+    }
+
+    /// sets the section referenced by the link field of the section. the link field is set to the index of the
+    /// referenced section when the file is written.
+    pub fn set_link(&mut self, link: Option<RewriterSectionId>) {
+        self.link = link;
+    }
+
+    /// sets the section referenced by the info field of the section, and marks the info field as containing a section
+    /// index.
+    pub fn set_info_link(&mut self, info_link: Option<RewriterSectionId>) {
+        if info_link.is_some() {
+            let flags = self.header.flags() | SectionHeaderFlags::INFO_LINK;
+            self.header.set_flags(flags);
+        }
+        self.info_link = info_link;
+    }
+
+    fn is_nobits(&self) -> bool {
+        *self.header.ty() == SectionHeaderType::Nobits
+    }
+
+    /// does the info field of the section contain the index of another section.
+    fn info_is_section_index(&self) -> bool {
+        self.header.flags().contains(SectionHeaderFlags::INFO_LINK)
+            || matches!(
+                self.header.ty().known(),
+                Some(SectionHeaderType::Rel | SectionHeaderType::Rela)
+            )
+    }
+}
+
+/// rewrites an existing elf file, while preserving the layout of all parts of the file which were not modified.
+///
+/// sections whose content is modified are written in place if their new content fits in their original location.
+/// otherwise they are moved to the end of the file, which is only possible for sections which are not loaded to memory,
+/// since moving a loaded section would require moving the segment that contains it. loaded sections which need to grow
+/// can instead be moved to a new loadable segment using [`ElfRewriter::add_load_segment`].
+///
+/// when sections are removed, the sections which follow the content of the segments are packed together, so that the
+/// removed content doesn't take space in the file.
+///
+/// writing a file without modifying it produces a file which is byte identical to the original file.
+#[derive(Debug, Clone)]
+pub struct ElfRewriter<'a> {
+    parser: ElfParser<'a>,
+    data: &'a [u8],
+    endianness: Endianness,
+    bit_length: ArchBitLength,
+    header: ElfHeader,
+    program_headers: Vec<ProgramHeader>,
+    sections: Vec<RewriterSection<'a>>,
+    section_names_changed: bool,
+    new_segment: Option<NewLoadSegment>,
+
+    /// should the part of the file which follows the content of the segments be compacted when the file is written.
+    compact: bool,
+}
+
+/// a loadable segment which was added at the end of the file.
+#[derive(Debug, Clone)]
+struct NewLoadSegment {
+    program_header_index: usize,
+    sections: Vec<RewriterSectionId>,
+    virt_addr: u64,
+    size: u64,
+    alignment: u64,
+    flags: ProgramHeaderFlags,
+
+    /// the indexes of the program headers which describe one of the moved sections, along with the section which they
+    /// describe.
+    following_program_headers: Vec<(usize, RewriterSectionId)>,
+
+    /// the range of the original file which contains a segment that was added at the end of the file by a previous
+    /// edit. its content is copied to the start of this segment, at the same addresses.
+    reused_content: Option<Range<usize>>,
+
+    /// the indexes of the program headers which point into the reused content, and move along with it.
+    shifted_program_headers: Vec<usize>,
+
+    /// the offset in the segment of the program header table, if it was moved to the segment.
+    program_headers_offset: Option<u64>,
+}
+impl<'a> ElfRewriter<'a> {
+    pub fn new(parser: &ElfParser<'a>) -> Result<Self> {
+        let header = parser.header()?;
+        let program_headers = parser
+            .program_headers()?
+            .iter()
+            .map(|program_header| Ok(program_header?.raw().clone()))
+            .collect::<Result<Vec<_>>>()?;
+        let section_headers = parser.section_headers()?;
+        let mut sections = Vec::with_capacity(section_headers.len());
+        for (index, section) in section_headers.iter().enumerate() {
+            let section = section?;
+            let content = if *section.ty() == SectionHeaderType::Nobits
+                || *section.ty() == SectionHeaderType::Null
+            {
+                &[]
+            } else {
+                section.content()?
+            };
+            sections.push(RewriterSection {
+                header: section.raw().clone(),
+                name: if index == 0 {
+                    Cow::Borrowed(&[])
+                } else {
+                    Cow::Borrowed(section.name_bytes()?)
+                },
+                content: Cow::Borrowed(content),
+                link: None,
+                info_link: None,
+                original_index: Some(index),
+                original_range: Some(
+                    section.offset() as usize..section.offset() as usize + content.len(),
+                ),
+                removed: false,
+                in_new_segment: false,
+            });
+        }
+        let sections_amount = sections.len();
+        let to_id = |index: u32| {
+            (index != 0 && (index as usize) < sections_amount)
+                .then_some(RewriterSectionId(index as usize))
+        };
+        for section in &mut sections {
+            section.link = to_id(section.header.link());
+            if section.info_is_section_index() {
+                section.info_link = to_id(section.header.info());
+            }
+        }
+        Ok(Self {
+            parser: parser.clone(),
+            data: parser.data(),
+            endianness: parser.file_info().endianness,
+            bit_length: parser.file_info().bit_length,
+            header,
+            program_headers,
+            sections,
+            section_names_changed: false,
+            new_segment: None,
+            compact: false,
+        })
+    }
+
+    /// the parser of the original file.
+    pub fn parser(&self) -> &ElfParser<'a> {
+        &self.parser
+    }
+
+    pub fn header(&self) -> &ElfHeader {
+        &self.header
+    }
+
+    /// returns the elf header. the section header table fields are fixed up when the file is written.
+    pub fn header_mut(&mut self) -> &mut ElfHeader {
+        &mut self.header
+    }
+
+    pub fn program_headers(&self) -> &[ProgramHeader] {
+        &self.program_headers
+    }
+
+    /// returns the program headers, which can be modified to move segments. the amount of program headers can't be
+    /// changed.
+    pub fn program_headers_mut(&mut self) -> &mut [ProgramHeader] {
+        &mut self.program_headers
+    }
+
+    /// returns an iterator over the ids of the sections which were not removed, in the order in which they will appear
+    /// in the written file.
+    pub fn section_ids(&self) -> impl Iterator<Item = RewriterSectionId> + '_ {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter(|(_, section)| !section.removed)
+            .map(|(index, _)| RewriterSectionId(index))
+    }
+
+    pub fn section(&self, id: RewriterSectionId) -> &RewriterSection<'a> {
+        &self.sections[id.0]
+    }
+
+    pub fn section_mut(&mut self, id: RewriterSectionId) -> &mut RewriterSection<'a> {
+        &mut self.sections[id.0]
+    }
+
+    /// returns the id of the section which had the given index in the original file.
+    pub fn original_section(&self, index: usize) -> Option<RewriterSectionId> {
+        self.sections
+            .get(index)
+            .filter(|section| section.original_index == Some(index))
+            .map(|_| RewriterSectionId(index))
+    }
+
+    /// finds a section which was not removed by its name.
+    pub fn find_section(&self, name: impl AsRef<[u8]>) -> Option<RewriterSectionId> {
+        self.section_ids()
+            .find(|&id| self.sections[id.0].name.as_ref() == name.as_ref())
+    }
+
+    /// adds a section to the end of the file. the offset, size and name of the given header are ignored. the link and
+    /// info fields are used as is, unless the sections that they reference are set using
+    /// [`RewriterSection::set_link`] and [`RewriterSection::set_info_link`].
+    ///
+    /// the section is not added to any segment, so even if it is marked as allocated it will not be loaded to memory.
+    pub fn add_section(
+        &mut self,
+        name: impl Into<Vec<u8>>,
+        header: SectionHeader,
+        content: Vec<u8>,
+    ) -> RewriterSectionId {
+        self.sections.push(RewriterSection {
+            header,
+            name: Cow::Owned(name.into()),
+            content: Cow::Owned(content),
+            link: None,
+            info_link: None,
+            original_index: None,
+            original_range: None,
+            removed: false,
+            in_new_segment: false,
+        });
+        self.section_names_changed = true;
+        RewriterSectionId(self.sections.len() - 1)
+    }
+
+    /// removes a section, along with the relocation sections which apply to it, and the section groups whose members
+    /// were all removed.
+    ///
+    /// the symbols which are defined in removed sections are removed from the symbol table when the file is written, so
+    /// they must not be referenced by any relocation or section group which is kept.
+    pub fn remove_section(&mut self, id: RewriterSectionId) {
+        self.sections[id.0].removed = true;
+        self.compact = true;
+        for other_id in 0..self.sections.len() {
+            let other = &self.sections[other_id];
+            if !other.removed
+                && other.info_link == Some(id)
+                && matches!(
+                    other.header.ty().known(),
+                    Some(SectionHeaderType::Rel | SectionHeaderType::Rela)
+                )
+            {
+                self.remove_section(RewriterSectionId(other_id));
+            }
+        }
+
+        let Some(original_index) = self.sections[id.0].original_index else {
+            return;
+        };
+        for other_id in 0..self.sections.len() {
+            let other = &self.sections[other_id];
+            if other.removed || *other.header.ty() != SectionHeaderType::Group {
+                continue;
+            }
+            let all_members_removed = {
+                let mut members = self.group_members(&other.content);
+                members
+                    .clone()
+                    .any(|member| member as usize == original_index)
+                    && members.all(|member| {
+                        self.original_section(member as usize)
+                            .is_none_or(|member| self.sections[member.0].removed)
+                    })
+            };
+            if all_members_removed {
+                self.remove_section(RewriterSectionId(other_id));
+            }
+        }
+    }
+
+    /// replaces the content of a section with a `NOBITS` placeholder, which keeps the address and size of the section but
+    /// takes no space in the file.
+    pub fn convert_to_nobits(&mut self, id: RewriterSectionId) {
+        let section = &mut self.sections[id.0];
+        section.header.set_ty(SectionHeaderType::Nobits.into());
+        section.content = Cow::Borrowed(&[]);
+        self.compact = true;
+    }
+
+    pub fn rename_section(&mut self, id: RewriterSectionId, name: impl Into<Vec<u8>>) {
+        self.sections[id.0].name = Cow::Owned(name.into());
+        self.section_names_changed = true;
+    }
+
+    /// replaces the content of a section. the size of the section is updated accordingly.
+    pub fn set_section_content(&mut self, id: RewriterSectionId, content: Vec<u8>) {
+        let section = &mut self.sections[id.0];
+        section.header.set_size(content.len() as u64);
+        section.content = Cow::Owned(content);
+    }
+
+    /// moves the given sections to a new loadable segment at the end of the file, which allows loaded sections to grow.
+    /// returns the virtual address at which the first of the given sections was placed. the sections are laid out in
+    /// the given order, and their addresses are updated, so the size of their content must not change after they are
+    /// moved.
+    ///
+    /// all calls share a single new segment, whose flags are the union of the flags of all calls. if the last loadable
+    /// segment of the file was added at the end of the file by a previous edit, it is extended instead, so the file can
+    /// be edited repeatedly. otherwise, the new segment is described by an unused `PT_NULL` program header if there is
+    /// one, or else the program header table is moved to the start of the new segment, with room for one more program
+    /// header, and the `PT_PHDR` program header is updated accordingly.
+    ///
+    /// program headers which exactly describe one of the moved sections, like the `PT_INTERP` and `PT_DYNAMIC` program
+    /// headers, are updated to describe the new location of the section.
+    pub fn add_load_segment(
+        &mut self,
+        sections: &[RewriterSectionId],
+        flags: ProgramHeaderFlags,
+    ) -> Result<u64> {
+        let mut new_segment = match self.new_segment.take() {
+            Some(new_segment) => new_segment,
+            None => match self.reuse_appended_segment() {
+                Some(new_segment) => new_segment,
+                None => self.create_load_segment()?,
+            },
+        };
+        new_segment.flags |= flags;
+
+        let mut first_address = None;
+        for &id in sections {
+            // a section which was already moved by a previous edit is moved again, since it may have grown.
+            new_segment.sections.retain(|&other_id| other_id != id);
+
+            let section = &mut self.sections[id.0];
+            new_segment.size =
+                align_up(new_segment.size, section.header.address_alignemnt().max(1));
+            let address = new_segment.virt_addr + new_segment.size;
+            section.header.set_address(address);
+            section.in_new_segment = true;
+            new_segment.size += section.content.len() as u64;
+            new_segment.sections.push(id);
+            first_address.get_or_insert(address);
+
+            let Some(range) = section.original_range.clone() else {
+                continue;
+            };
+            for (index, program_header) in self.program_headers.iter().enumerate() {
+                if *program_header.ty() != ProgramHeaderType::Load
+                    && index != new_segment.program_header_index
+                    && !range.is_empty()
+                    && program_header.offset() == range.start as u64
+                    && program_header.size_in_file() == range.len() as u64
+                {
+                    new_segment.following_program_headers.push((index, id));
+                }
+            }
+        }
+        let end_address = new_segment.virt_addr + new_segment.size;
+        self.new_segment = Some(new_segment);
+        Ok(first_address.unwrap_or(end_address))
+    }
+
+    /// the alignment of new loadable segments.
+    fn load_segment_alignment(&self) -> u64 {
+        self.program_headers
+            .iter()
+            .filter(|program_header| *program_header.ty() == ProgramHeaderType::Load)
+            .map(|program_header| program_header.alignment())
+            .max()
+            .unwrap_or(0)
+            .max(MIN_PAGE_SIZE)
+    }
+
+    /// the difference between the virtual addresses and the file offsets of the first loadable segment. old kernels
+    /// assume that the program header table is mapped at this difference from its file offset.
+    fn load_bias(&self) -> u64 {
+        self.program_headers
+            .iter()
+            .find(|program_header| *program_header.ty() == ProgramHeaderType::Load)
+            .map_or(0, |program_header| {
+                program_header
+                    .virt_addr()
+                    .wrapping_sub(program_header.offset())
+            })
+    }
+
+    /// if the last loadable segment was added at the end of the file by a previous edit, returns a new segment which
+    /// replaces it, and contains its content at the same addresses.
+    fn reuse_appended_segment(&mut self) -> Option<NewLoadSegment> {
+        let is_load =
+            |program_header: &ProgramHeader| *program_header.ty() == ProgramHeaderType::Load;
+        let index = self.program_headers.iter().rposition(is_load)?;
+        let segment = &self.program_headers[index];
+        let range = segment.offset() as usize..(segment.offset() + segment.size_in_file()) as usize;
+        let is_highest_segment = self
+            .program_headers
+            .iter()
+            .filter(|program_header| is_load(program_header))
+            .all(|program_header| program_header.virt_addr() <= segment.virt_addr());
+        let section_headers_in_segment = self.header.section_headers_amount() != 0
+            && self.header.section_headers_off() >= range.start as u64;
+        let program_headers_off = self.header.program_headers_off();
+        let program_headers_end = program_headers_off
+            + self.header.program_headers_amount() as u64
+                * self.header.program_header_entry_size() as u64;
+        let program_headers_offset = (program_headers_off >= range.start as u64)
+            .then(|| program_headers_off - range.start as u64);
+        if range.is_empty()
+            || range.end != self.data.len()
+            || segment.size_in_file() != segment.size_in_memory()
+            || !is_highest_segment
+            || section_headers_in_segment
+            || (program_headers_offset.is_none() && program_headers_end > range.start as u64)
+        {
+            return None;
+        }
+
+        let virt_addr = segment.virt_addr();
+        let mut sections = Vec::new();
+        for (id, section) in self.sections.iter_mut().enumerate() {
+            let in_segment = section
+                .original_range
+                .as_ref()
+                .is_some_and(|section_range| {
+                    !section_range.is_empty()
+                        && section_range.start >= range.start
+                        && section_range.end <= range.end
+                });
+            if in_segment && !section.removed {
+                section.in_new_segment = true;
+                sections.push(RewriterSectionId(id));
+            }
+        }
+        let shifted_program_headers = self
+            .program_headers
+            .iter()
+            .enumerate()
+            .filter(|&(other_index, program_header)| {
+                other_index != index
+                    && program_header.size_in_file() != 0
+                    && program_header.offset() >= range.start as u64
+                    && program_header.offset() < range.end as u64
+            })
+            .map(|(other_index, _)| other_index)
+            .collect();
+        let new_segment = NewLoadSegment {
+            program_header_index: index,
+            sections,
+            virt_addr,
+            size: range.len() as u64,
+            alignment: segment.alignment().max(1),
+            flags: *segment.flags(),
+            following_program_headers: Vec::new(),
+            reused_content: Some(range),
+            shifted_program_headers,
+            program_headers_offset,
+        };
+
+        // the content of the segment is rewritten as a part of the new segment, so the original copy is dropped.
+        self.program_headers[index].set_size_in_file(0);
+        self.compact = true;
+        Some(new_segment)
+    }
+
+    /// creates an empty loadable segment, described by an unused `PT_NULL` program header, or by a program header added
+    /// to the program header table, which is moved to the start of the segment.
+    fn create_load_segment(&mut self) -> Result<NewLoadSegment> {
+        if self.program_headers.is_empty() {
+            return Err(Error::NoRoomForNewSegment);
+        }
+        let is_load =
+            |program_header: &ProgramHeader| *program_header.ty() == ProgramHeaderType::Load;
+        let alignment = self.load_segment_alignment();
+        let end_of_memory = self
+            .program_headers
+            .iter()
+            .filter(|program_header| is_load(program_header))
+            .map(|program_header| program_header.virt_addr() + program_header.size_in_memory())
+            .max()
+            .unwrap_or(0);
+
+        // the new segment has the highest address, so it must come after all other loadable segments.
+        let last_load = self.program_headers.iter().rposition(is_load);
+        let null_slot = self
+            .program_headers
+            .iter()
+            .position(|program_header| *program_header.ty() == ProgramHeaderType::Null);
+        let (program_header_index, virt_addr, program_headers_offset) = match null_slot {
+            Some(slot) => {
+                let program_header_index = match last_load {
+                    Some(last_load) if last_load > slot => {
+                        let program_header = self.program_headers.remove(slot);
+                        self.program_headers.insert(last_load, program_header);
+                        last_load
+                    }
+                    _ => slot,
+                };
+                (
+                    program_header_index,
+                    align_up(end_of_memory, alignment),
+                    None,
+                )
+            }
+            None => {
+                let program_header_index = last_load.map_or(self.program_headers.len(), |i| i + 1);
+                let program_header = self.program_headers[0].clone();
+                self.program_headers
+                    .insert(program_header_index, program_header);
+                // place the segment so that the program header table is mapped where old kernels expect it to be,
+                // assuming that the file doesn't grow much when it is written.
+                let virt_addr = align_up(
+                    end_of_memory.max(self.load_bias().wrapping_add(self.data.len() as u64)),
+                    alignment,
+                );
+                (program_header_index, virt_addr, Some(0))
+            }
+        };
+        let size = match program_headers_offset {
+            Some(_) => {
+                self.program_headers.len() as u64 * self.header.program_header_entry_size() as u64
+            }
+            None => 0,
+        };
+        Ok(NewLoadSegment {
+            program_header_index,
+            sections: Vec::new(),
+            virt_addr,
+            size,
+            alignment,
+            flags: ProgramHeaderFlags::empty(),
+            following_program_headers: Vec::new(),
+            reused_content: None,
+            shifted_program_headers: Vec::new(),
+            program_headers_offset,
+        })
+    }
+
+    /// the end of the part of the file which contains the elf header, the program header table and the content of the
+    /// segments.
+    fn end_of_segments(&self) -> usize {
+        // the content of a reused segment and the program header table, if it was moved, are written as a part of the
+        // new segment.
+        let (shifted_program_headers, program_headers_moved) = match &self.new_segment {
+            Some(new_segment) => (
+                new_segment.shifted_program_headers.as_slice(),
+                new_segment.program_headers_offset.is_some(),
+            ),
+            None => (&[][..], false),
+        };
+        let program_headers_end = if program_headers_moved {
+            0
+        } else {
+            self.header.program_headers_off()
+                + self.header.program_headers_amount() as u64
+                    * self.header.program_header_entry_size() as u64
+        };
+        self.program_headers
+            .iter()
+            .enumerate()
+            .filter(|(index, program_header)| {
+                program_header.size_in_file() != 0 && !shifted_program_headers.contains(index)
+            })
+            .map(|(_, program_header)| program_header.offset() + program_header.size_in_file())
+            .chain([self.header.header_size() as u64, program_headers_end])
+            .max()
+            .unwrap_or(0) as usize
+    }
+
+    /// the index of each section in the written file, or `None` if it was removed.
+    fn new_section_indexes(&self) -> Vec<Option<usize>> {
+        let mut next_index = 0;
+        self.sections
+            .iter()
+            .map(|section| {
+                if section.removed {
+                    None
+                } else {
+                    next_index += 1;
+                    Some(next_index - 1)
+                }
+            })
+            .collect()
+    }
+
+    /// are the indexes of the original sections unchanged in the written file.
+    fn are_original_indexes_preserved(&self, new_indexes: &[Option<usize>]) -> bool {
+        self.sections
+            .iter()
+            .zip(new_indexes)
+            .all(|(section, new_index)| match section.original_index {
+                Some(original_index) => *new_index == Some(original_index),
+                None => true,
+            })
+    }
+
+    fn read_word(&self, word: &[u8]) -> u32 {
+        let word = [word[0], word[1], word[2], word[3]];
+        match self.endianness {
+            Endianness::Big => u32::from_be_bytes(word),
+            Endianness::Little => u32::from_le_bytes(word),
+        }
+    }
+
+    fn write_word(&self, word: u32) -> [u8; 4] {
+        match self.endianness {
+            Endianness::Big => word.to_be_bytes(),
+            Endianness::Little => word.to_le_bytes(),
+        }
+    }
+
+    /// the original section indexes of the members of a `SHT_GROUP` section with the given content.
+    fn group_members<'s>(&'s self, content: &'s [u8]) -> impl Iterator<Item = u32> + Clone + 's {
+        // the first word of the section holds the flags of the group.
+        content
+            .chunks_exact(4)
+            .skip(1)
+            .map(|member| self.read_word(member))
+    }
+
+    fn symbol_section_index(&self, record: &[u8]) -> Result<u16> {
+        let deserialize_error =
+            |err: binary_serde::DeserializeError| Error::BinaryDeserializeError(err.into());
+        Ok(match self.bit_length {
+            ArchBitLength::Arch32Bit => {
+                Symbol32::binary_deserialize(record, self.endianness)
+                    .map_err(deserialize_error)?
+                    .related_section_index
+            }
+            ArchBitLength::Arch64Bit => {
+                Symbol64::binary_deserialize(record, self.endianness)
+                    .map_err(deserialize_error)?
+                    .related_section_index
+            }
+        })
+    }
+
+    fn set_symbol_section_index(&self, record: &mut [u8], section_index: u16) -> Result<()> {
+        let deserialize_error =
+            |err: binary_serde::DeserializeError| Error::BinaryDeserializeError(err.into());
+        match self.bit_length {
+            ArchBitLength::Arch32Bit => {
+                let mut symbol = Symbol32::binary_deserialize(record, self.endianness)
+                    .map_err(deserialize_error)?;
+                symbol.related_section_index = section_index;
+                symbol.binary_serialize(record, self.endianness);
+            }
+            ArchBitLength::Arch64Bit => {
+                let mut symbol = Symbol64::binary_deserialize(record, self.endianness)
+                    .map_err(deserialize_error)?;
+                symbol.related_section_index = section_index;
+                symbol.binary_serialize(record, self.endianness);
+            }
+        }
+        Ok(())
+    }
+
+    /// translates the section indexes of the symbols of a symbol table to indexes in the written file. symbols which
+    /// are defined in removed sections are dropped, and the extended section indexes table, the relocation sections
+    /// and the section groups which refer to the symbol table are fixed accordingly.
+    fn fix_symbol_table(
+        &self,
+        id: usize,
+        live_ids: &[usize],
+        new_indexes: &[Option<usize>],
+        headers: &mut [SectionHeader],
+        contents: &mut [Cow<[u8]>],
+    ) -> Result<()> {
+        let symbol_table_index = new_indexes[id].unwrap();
+        let is_dynamic = *self.sections[id].header.ty() == SectionHeaderType::Dynsym;
+        let linked: Vec<usize> = live_ids
+            .iter()
+            .copied()
+            .filter(|&other| self.sections[other].link == Some(RewriterSectionId(id)))
+            .collect();
+        let extended_indexes_table = linked
+            .iter()
+            .find(|&&other| *self.sections[other].header.ty() == SectionHeaderType::SymtabShndx)
+            .map(|&other| new_indexes[other].unwrap());
+        let extended_indexes: Vec<u32> = extended_indexes_table
+            .map(|index| {
+                contents[index]
+                    .chunks_exact(4)
+                    .map(|entry| self.read_word(entry))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let record_len = match self.bit_length {
+            ArchBitLength::Arch32Bit => Symbol32::SERIALIZED_SIZE,
+            ArchBitLength::Arch64Bit => Symbol64::SERIALIZED_SIZE,
+        };
+        let mut symbols = Vec::with_capacity(contents[symbol_table_index].len());
+        let mut new_extended_indexes = Vec::new();
+        let mut new_symbol_indexes = Vec::new();
+        let mut kept_amount = 0;
+        for (index, record) in contents[symbol_table_index]
+            .chunks_exact(record_len)
+            .enumerate()
+        {
+            let mut record = record.to_vec();
+            let section_index = self.symbol_section_index(&record)?;
+            let mut extended_index = extended_indexes.get(index).copied().unwrap_or(0);
+            let original_section = match section_index {
+                SHN_XINDEX => Some(extended_index as usize),
+                SHN_UNDEF => None,
+                _ if section_index >= SHN_LORESERVE => None,
+                _ => Some(section_index as usize),
+            };
+            // the ids of the original sections are their original indexes.
+            match original_section.map(|section| new_indexes.get(section).copied().flatten()) {
+                Some(None) if is_dynamic => {
+                    return Err(Error::DynamicSymbolInRemovedSection {
+                        symbol_index: index,
+                    });
+                }
+                Some(None) => {
+                    new_symbol_indexes.push(None);
+                    continue;
+                }
+                Some(Some(new_index)) if section_index == SHN_XINDEX => {
+                    extended_index = new_index as u32;
+                }
+                // the written file has less than `SHN_LORESERVE` sections.
+                Some(Some(new_index)) => {
+                    self.set_symbol_section_index(&mut record, new_index as u16)?
+                }
+                None => {}
+            }
+            new_symbol_indexes.push(Some(kept_amount as u32));
+            kept_amount += 1;
+            symbols.extend_from_slice(&record);
+            new_extended_indexes.extend_from_slice(&self.write_word(extended_index));
+        }
+        contents[symbol_table_index] = Cow::Owned(symbols);
+        headers[symbol_table_index].set_size(contents[symbol_table_index].len() as u64);
+        if let Some(index) = extended_indexes_table {
+            headers[index].set_size(new_extended_indexes.len() as u64);
+            contents[index] = Cow::Owned(new_extended_indexes);
+        }
+        if new_symbol_indexes.iter().all(Option::is_some) {
+            return Ok(());
+        }
+
+        // the info field of a symbol table is the index of its first non local symbol.
+        let first_non_local = headers[symbol_table_index].info() as usize;
+        let new_first_non_local = new_symbol_indexes
+            .iter()
+            .take(first_non_local)
+            .flatten()
+            .count();
+        headers[symbol_table_index].set_info(new_first_non_local as u32);
+
+        let new_symbol_index = |symbol_index: u32, section_index: usize| match new_symbol_indexes
+            .get(symbol_index as usize)
+        {
+            Some(Some(new_index)) => Ok(*new_index),
+            Some(None) => Err(Error::RemovedSymbolIsReferenced {
+                symbol_index: symbol_index as usize,
+                section_index,
+            }),
+            None => Ok(symbol_index),
+        };
+        let file_info = self.parser.file_info();
+        for other in linked {
+            let index = new_indexes[other].unwrap();
+            match self.sections[other].header.ty().known() {
+                Some(SectionHeaderType::Group) => {
+                    let signature = new_symbol_index(headers[index].info(), index)?;
+                    headers[index].set_info(signature);
+                }
+                Some(SectionHeaderType::Rel) => {
+                    let record_len = Rel::record_len(&file_info);
+                    let mut deserializer =
+                        BinaryDeserializerFromBufSafe::new(&contents[index], self.endianness);
+                    let mut fixed = alloc::vec![0; contents[index].len()];
+                    for record in fixed.chunks_exact_mut(record_len) {
+                        let mut rel = Rel::deserialize(&mut deserializer, &self.parser, ())?;
+                        rel.set_symbol_index(new_symbol_index(rel.symbol_index(), index)?);
+                        rel.serialize(record, self.endianness);
+                    }
+                    contents[index] = Cow::Owned(fixed);
+                }
+                Some(SectionHeaderType::Rela) => {
+                    let record_len = Rela::record_len(&file_info);
+                    let mut deserializer =
+                        BinaryDeserializerFromBufSafe::new(&contents[index], self.endianness);
+                    let mut fixed = alloc::vec![0; contents[index].len()];
+                    for record in fixed.chunks_exact_mut(record_len) {
+                        let mut rela = Rela::deserialize(&mut deserializer, &self.parser, ())?;
+                        rela.set_symbol_index(new_symbol_index(rela.symbol_index(), index)?);
+                        rela.serialize(record, self.endianness);
+                    }
+                    contents[index] = Cow::Owned(fixed);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// translates the member section indexes of a `SHT_GROUP` section to indexes in the written file, and drops the
+    /// members which were removed. the first word of the section holds the flags of the group, and is kept as is.
+    fn fix_group_section(&self, content: &[u8], new_indexes: &[Option<usize>]) -> Vec<u8> {
+        let mut fixed = Vec::with_capacity(content.len());
+        fixed.extend_from_slice(content.get(..4).unwrap_or_default());
+        for member in self.group_members(content) {
+            if let Some(new_index) = new_indexes.get(member as usize).copied().flatten() {
+                fixed.extend_from_slice(&self.write_word(new_index as u32));
+            }
+        }
+        fixed
+    }
+
+    /// writes the modified elf file.
+    pub fn write(&self) -> Result<Vec<u8>> {
+        let mut out = self.data.to_vec();
+        let new_indexes = self.new_section_indexes();
+        let live_ids: Vec<usize> = self.section_ids().map(|id| id.0).collect();
+        // extended section numbering is not supported.
+        if live_ids.len() >= SHN_LORESERVE as usize {
+            return Err(Error::TooManySections {
+                amount: live_ids.len(),
+            });
+        }
+        let indexes_preserved = self.are_original_indexes_preserved(&new_indexes);
+        let sections_removed = self.sections.iter().any(|section| section.removed);
+        let word_size: u64 = match self.bit_length {
+            ArchBitLength::Arch32Bit => 4,
+            ArchBitLength::Arch64Bit => 8,
+        };
+
+        let original_names_section = self.header.section_names_section_index() as usize;
+        let names_section = self
+            .sections
+            .iter()
+            .position(|section| section.original_index == Some(original_names_section))
+            .filter(|&id| !self.sections[id].removed);
+
+        // rebuild the section names string table only if names were changed, to preserve its original layout.
+        let mut headers: Vec<SectionHeader> = live_ids
+            .iter()
+            .map(|&id| self.sections[id].header.clone())
+            .collect();
+        let mut contents: Vec<Cow<[u8]>> = live_ids
+            .iter()
+            .map(|&id| Cow::Borrowed(self.sections[id].content.as_ref()))
+            .collect();
+        if let (true, Some(names_section)) = (self.section_names_changed, names_section) {
+            let mut names = StringTableBuilder::new();
+            let mut ids_by_name_len = live_ids.clone();
+            ids_by_name_len.sort_by_key(|&id| core::cmp::Reverse(self.sections[id].name.len()));
+            let mut name_offsets = alloc::vec![0; self.sections.len()];
+            for id in ids_by_name_len {
+                if id != 0 {
+                    name_offsets[id] = names.add(&self.sections[id].name)?;
+                }
+            }
+            for (header, &id) in headers.iter_mut().zip(&live_ids) {
+                header.set_name_offset(name_offsets[id]);
+            }
+            let names_index = new_indexes[names_section].unwrap();
+            headers[names_index].set_size(names.len() as u64);
+            contents[names_index] = Cow::Owned(names.into_bytes());
+        }
+
+        // fix up references to other sections.
+        let index_of = |id: RewriterSectionId| new_indexes[id.0].unwrap_or(0) as u32;
+        for (new_index, &id) in live_ids.iter().enumerate() {
+            let section = &self.sections[id];
+            if let Some(link) = section.link {
+                headers[new_index].set_link(index_of(link));
+            }
+            if let Some(info_link) = section.info_link {
+                headers[new_index].set_info(index_of(info_link));
+            }
+            if !indexes_preserved
+                && section.original_index.is_some()
+                && *section.header.ty() == SectionHeaderType::Group
+            {
+                contents[new_index] =
+                    Cow::Owned(self.fix_group_section(&contents[new_index], &new_indexes));
+            }
+        }
+
+        if !indexes_preserved || sections_removed {
+            for &id in &live_ids {
+                let section = &self.sections[id];
+                if section.original_index.is_some()
+                    && matches!(
+                        section.header.ty().known(),
+                        Some(SectionHeaderType::Symtab | SectionHeaderType::Dynsym)
+                    )
+                {
+                    self.fix_symbol_table(
+                        id,
+                        &live_ids,
+                        &new_indexes,
+                        &mut headers,
+                        &mut contents,
+                    )?;
+                }
+            }
+        }
+
+        // zero the content of removed sections which are not loaded to memory, unless some other section uses the same
+        // part of the file.
+        for section in self.sections.iter().filter(|section| section.removed) {
+            let Some(range) = section.original_range.clone() else {
+                continue;
+            };
+            if section.header.flags().contains(SectionHeaderFlags::ALLOC) {
+                continue;
+            }
+            let is_shared = live_ids.iter().any(|&id| {
+                self.sections[id]
+                    .original_range
+                    .as_ref()
+                    .is_some_and(|other_range| ranges_overlap(&range, other_range))
+            });
+            if !is_shared {
+                out[range].fill(0);
+            }
+        }
+
+        // when compacting, everything which follows the content of the segments is rewritten.
+        let compact_from = self.compact.then(|| self.end_of_segments());
+        if let Some(compact_from) = compact_from {
+            out.truncate(compact_from);
+        }
+        let is_compacted = |range: &Option<Range<usize>>| {
+            compact_from.is_some_and(|compact_from| {
+                range
+                    .as_ref()
+                    .is_some_and(|range| range.start >= compact_from)
+            })
+        };
+
+        // place the content of the sections.
+        for (new_index, &id) in live_ids.iter().enumerate() {
+            let section = &self.sections[id];
+            let content = &contents[new_index];
+            if section.is_nobits() || new_index == 0 || section.in_new_segment {
+                continue;
+            }
+            let compacted = is_compacted(&section.original_range);
+            if let (false, Some(_), Cow::Borrowed(_), Cow::Borrowed(_)) =
+                (compacted, section.original_index, &section.content, content)
+            {
+                // the content of the section was not modified.
+                continue;
+            }
+            match section.original_range.clone() {
+                Some(range) if !compacted && content.len() <= range.len() => {
+                    out[range.start..range.start + content.len()].copy_from_slice(content);
+                    out[range.start + content.len()..range.end].fill(0);
+                    headers[new_index].set_offset(range.start as u64);
+                }
+                Some(_)
+                    if !compacted && section.header.flags().contains(SectionHeaderFlags::ALLOC) =>
+                {
+                    return Err(Error::SectionDoesntFitInSegment {
+                        section_index: new_index,
+                    });
+                }
+                _ => {
+                    let offset =
+                        align_up(out.len() as u64, section.header.address_alignemnt().max(1));
+                    out.resize(offset as usize, 0);
+                    out.extend_from_slice(content);
+                    headers[new_index].set_offset(offset);
+                }
+            }
+            headers[new_index].set_size(content.len() as u64);
+        }
+
+        // reserve room for the section header table, in place if it fits. it is placed before the new segment, so that
+        // the new segment stays at the end of the file, where later edits can extend it.
+        let section_header_size = match self.bit_length {
+            ArchBitLength::Arch32Bit => SectionHeader32::SERIALIZED_SIZE,
+            ArchBitLength::Arch64Bit => SectionHeader64::SERIALIZED_SIZE,
+        };
+        let mut header = self.header.clone();
+        let original_table_offset = header.section_headers_off() as usize;
+        let original_table_len = header.section_headers_amount() as usize * section_header_size;
+        let table_offset = if headers.len() * section_header_size <= original_table_len
+            && !is_compacted(&Some(
+                original_table_offset..original_table_offset + original_table_len,
+            )) {
+            out[original_table_offset..original_table_offset + original_table_len].fill(0);
+            original_table_offset
+        } else {
+            let offset = align_up(out.len() as u64, word_size) as usize;
+            out.resize(offset + headers.len() * section_header_size, 0);
+            offset
+        };
+
+        // place the new loadable segment.
+        let mut program_headers = self.program_headers.clone();
+        let mut program_headers_offset = self.header.program_headers_off();
+        if let Some(new_segment) = &self.new_segment {
+            let alignment = new_segment.alignment;
+            let misalignment = new_segment.virt_addr % alignment;
+            let offset = match new_segment.virt_addr.checked_sub(self.load_bias()) {
+                // keep the program header table mapped where old kernels expect it to be, if possible.
+                Some(offset)
+                    if new_segment.program_headers_offset.is_some()
+                        && offset >= out.len() as u64
+                        && offset % alignment == misalignment =>
+                {
+                    offset
+                }
+                _ => align_up(out.len() as u64, alignment) + misalignment,
+            };
+            out.resize((offset + new_segment.size) as usize, 0);
+            if let Some(range) = new_segment.reused_content.clone() {
+                out[offset as usize..][..range.len()].copy_from_slice(&self.data[range.clone()]);
+                for &index in &new_segment.shifted_program_headers {
+                    let program_header = &mut program_headers[index];
+                    program_header
+                        .set_offset(program_header.offset() - range.start as u64 + offset);
+                }
+            }
+            for &id in &new_segment.sections {
+                let section = &self.sections[id.0];
+                let section_offset = offset + (section.header.address() - new_segment.virt_addr);
+                out[section_offset as usize..][..section.content.len()]
+                    .copy_from_slice(&section.content);
+                if let Some(new_index) = new_indexes[id.0] {
+                    headers[new_index].set_offset(section_offset);
+                    headers[new_index].set_size(section.content.len() as u64);
+                }
+            }
+            let program_header = &mut program_headers[new_segment.program_header_index];
+            program_header.set_ty(ProgramHeaderType::Load.into());
+            program_header.set_flags(new_segment.flags);
+            program_header.set_offset(offset);
+            program_header.set_virt_addr(new_segment.virt_addr);
+            program_header.set_phys_addr(new_segment.virt_addr);
+            program_header.set_size_in_file(new_segment.size);
+            program_header.set_size_in_memory(new_segment.size);
+            program_header.set_alignment(alignment);
+            for &(index, id) in &new_segment.following_program_headers {
+                let section = &self.sections[id.0];
+                let program_header = &mut program_headers[index];
+                program_header
+                    .set_offset(offset + (section.header.address() - new_segment.virt_addr));
+                program_header.set_virt_addr(section.header.address());
+                program_header.set_phys_addr(section.header.address());
+                program_header.set_size_in_file(section.content.len() as u64);
+                program_header.set_size_in_memory(section.content.len() as u64);
+            }
+            if let Some(offset_in_segment) = new_segment.program_headers_offset {
+                program_headers_offset = offset + offset_in_segment;
+                let table_size =
+                    program_headers.len() as u64 * self.header.program_header_entry_size() as u64;
+                for program_header in &mut program_headers {
+                    if *program_header.ty() == ProgramHeaderType::Phdr {
+                        program_header.set_offset(program_headers_offset);
+                        program_header.set_virt_addr(new_segment.virt_addr + offset_in_segment);
+                        program_header.set_phys_addr(new_segment.virt_addr + offset_in_segment);
+                        program_header.set_size_in_file(table_size);
+                        program_header.set_size_in_memory(table_size);
+                    }
+                }
+            }
+        }
+
+        // write the section header table.
+        for (section_header, buf) in headers
+            .iter()
+            .zip(out[table_offset..].chunks_exact_mut(section_header_size))
+        {
+            section_header.serialize(buf, self.endianness);
+        }
+        if !headers.is_empty() {
+            header.set_section_headers_off(table_offset as u64);
+            header.set_section_headers_amount(headers.len() as u16);
+            header.set_section_names_section_index(
+                names_section
+                    .and_then(|id| new_indexes[id])
+                    .map_or(SHN_UNDEF, |index| index as u16),
+            );
+        }
+
+        header.set_program_headers_off(program_headers_offset);
+        header.set_program_headers_amount(program_headers.len() as u16);
+        let program_header_size = header.program_header_entry_size() as usize;
+        let program_headers_offset = program_headers_offset as usize;
+        for (i, program_header) in program_headers.iter().enumerate() {
+            let start = program_headers_offset + i * program_header_size;
+            program_header.serialize(
+                &mut out[start..start + program_header_size],
+                self.endianness,
+            );
+        }
+
+        let header_size = header.header_size() as usize;
+        header.serialize(&mut out[..header_size], self.endianness);
+        Ok(out)
+    }
+}
+
+fn ranges_overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+fn align_up(value: u64, alignment: u64) -> u64 {
+    value.div_ceil(alignment) * alignment
+}
@@ -0,0 +1,234 @@
+use crate::{
+    AArch64Feature1, DecodedGnuProperty, DynamicTag, ElfFileType, ElfParser, ProgramHeaderFlags,
+    ProgramHeaderType, Result, SectionData, SectionHeaderFlags, SectionHeaderType, SymbolEntries,
+    X86Feature1, SHN_UNDEF,
+};
+
+const DF_BIND_NOW: u64 = 0x8;
+const DF_1_NOW: u64 = 0x1;
+const DF_1_PIE: u64 = 0x08000000;
+
+/// the symbols which indicate that the file was compiled with stack protection.
+const STACK_CANARY_SYMBOLS: &[&[u8]] = &[
+    b"__stack_chk_fail",
+    b"__stack_chk_fail_local",
+    b"__stack_chk_guard",
+    b"__intel_security_cookie",
+];
+
+/// the maximum amount of distinct fortified functions which are counted in a security report. glibc has less than a
+/// hundred fortified functions, so it is never reached in practice.
+pub const MAX_FORTIFIED_FUNCTIONS: usize = 128;
+
+/// how much of the relocated data is made read only after relocation processing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relro {
+    /// the file has no `PT_GNU_RELRO` segment.
+    None,
+
+    /// the file has a `PT_GNU_RELRO` segment, but its symbols are bound lazily, so the got entries of the plt remain
+    /// writable.
+    Partial,
+
+    /// the file has a `PT_GNU_RELRO` segment and all of its symbols are bound when it is loaded.
+    Full,
+}
+
+/// whether the file can be loaded at any address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pie {
+    /// the file is an executable which must be loaded at a fixed address.
+    NotPie,
+
+    /// the file is a position independent executable.
+    Pie,
+
+    /// the file is a shared object which is not an executable.
+    SharedObject,
+
+    /// the file is not an executable or a shared object, for example a relocatable object.
+    NotApplicable,
+}
+
+/// the hardening features of an elf file, like the ones reported by checksec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecurityReport {
+    pub relro: Relro,
+    pub pie: Pie,
+
+    /// the stack is not executable, according to the `PT_GNU_STACK` segment. files without a `PT_GNU_STACK` segment
+    /// get an executable stack.
+    pub nx: bool,
+
+    /// the file references the stack protector functions.
+    pub stack_canary: bool,
+
+    /// the amount of distinct fortified functions (`__*_chk`) which the file references. the file was built with
+    /// `_FORTIFY_SOURCE` if this is not 0.
+    ///
+    /// the count saturates at [`MAX_FORTIFIED_FUNCTIONS`], since the names of the counted functions are remembered in
+    /// a fixed size buffer.
+    pub fortified_functions: usize,
+    pub has_rpath: bool,
+    pub has_runpath: bool,
+
+    /// x86 indirect branch tracking, from the gnu property note.
+    pub cet_ibt: bool,
+
+    /// x86 shadow stack, from the gnu property note.
+    pub cet_shstk: bool,
+
+    /// aarch64 branch target identification, from the gnu property note.
+    pub aarch64_bti: bool,
+
+    /// aarch64 pointer authentication, from the gnu property note.
+    pub aarch64_pac: bool,
+
+    /// the file has a `.note.GNU-stack` section which requests an executable stack. this is how relocatable objects
+    /// request an executable stack, which makes the linker mark the whole linked file as requiring one.
+    pub executable_stack_section: bool,
+}
+impl SecurityReport {
+    /// does the file use all of the hardening features which are reported, other than the architechture specific ones.
+    pub fn is_fully_hardened(&self) -> bool {
+        self.relro == Relro::Full
+            && matches!(self.pie, Pie::Pie | Pie::SharedObject)
+            && self.nx
+            && self.stack_canary
+            && !self.has_rpath
+            && !self.has_runpath
+            && !self.executable_stack_section
+    }
+}
+
+impl<'a> ElfParser<'a> {
+    /// computes the hardening features of the file.
+    pub fn security_report(&self) -> Result<SecurityReport> {
+        let header = self.header()?;
+        let dynamic = self.dynamic_section()?;
+        let dynamic_value = |tag: DynamicTag| -> Result<Option<u64>> {
+            match &dynamic {
+                Some(dynamic) => dynamic.value_of(tag as i64),
+                None => Ok(None),
+            }
+        };
+
+        let mut has_relro = false;
+        let mut nx = false;
+        let mut has_interpreter = false;
+        for program_header in self.program_headers()? {
+            let program_header = program_header?;
+            let Some(ty) = program_header.ty().known() else {
+                continue;
+            };
+            match ty {
+                ProgramHeaderType::GnuRelro => has_relro = true,
+                ProgramHeaderType::GnuStack => {
+                    nx = !program_header.flags().contains(ProgramHeaderFlags::X)
+                }
+                ProgramHeaderType::Interp => has_interpreter = true,
+                _ => {}
+            }
+        }
+
+        let flags = dynamic_value(DynamicTag::Flags)?.unwrap_or(0);
+        let flags_1 = dynamic_value(DynamicTag::Flags1)?.unwrap_or(0);
+        let bind_now = dynamic_value(DynamicTag::BindNow)?.is_some()
+            || flags & DF_BIND_NOW != 0
+            || flags_1 & DF_1_NOW != 0;
+        let relro = match (has_relro, bind_now) {
+            (false, _) => Relro::None,
+            (true, false) => Relro::Partial,
+            (true, true) => Relro::Full,
+        };
+
+        let pie = match header.ty().known() {
+            Some(ElfFileType::Exec) => Pie::NotPie,
+            Some(ElfFileType::Dyn) if has_interpreter || flags_1 & DF_1_PIE != 0 => Pie::Pie,
+            Some(ElfFileType::Dyn) => Pie::SharedObject,
+            _ => Pie::NotApplicable,
+        };
+
+        let mut executable_stack_section = false;
+        let mut symbols = None;
+        for section in self.section_headers()? {
+            let section = section?;
+            if *section.ty() == SectionHeaderType::Progbits
+                && section.name_bytes()? == b".note.GNU-stack"
+                && section.flags().contains(SectionHeaderFlags::EXECINSTR)
+            {
+                executable_stack_section = true;
+            }
+            // the dynamic symbol table is preferred, since it is what is left in stripped files.
+            match section.data()? {
+                SectionData::DynamicSymbolTable(table) => symbols = Some(table),
+                SectionData::SymbolTable(table) if symbols.is_none() => symbols = Some(table),
+                _ => {}
+            }
+        }
+        let (stack_canary, fortified_functions) = match symbols {
+            Some(symbols) => scan_hardening_symbols(&symbols)?,
+            None => (false, 0),
+        };
+
+        let mut report = SecurityReport {
+            relro,
+            pie,
+            nx,
+            stack_canary,
+            fortified_functions,
+            has_rpath: dynamic_value(DynamicTag::Rpath)?.is_some(),
+            has_runpath: dynamic_value(DynamicTag::Runpath)?.is_some(),
+            cet_ibt: false,
+            cet_shstk: false,
+            aarch64_bti: false,
+            aarch64_pac: false,
+            executable_stack_section,
+        };
+        if let Some(properties) = self.decoded_gnu_properties()? {
+            for property in properties {
+                match property? {
+                    DecodedGnuProperty::X86Feature1And(features) => {
+                        report.cet_ibt = features.contains(X86Feature1::IBT);
+                        report.cet_shstk = features.contains(X86Feature1::SHSTK);
+                    }
+                    DecodedGnuProperty::AArch64Feature1And(features) => {
+                        report.aarch64_bti = features.contains(AArch64Feature1::BTI);
+                        report.aarch64_pac = features.contains(AArch64Feature1::PAC);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// looks for the stack protector symbols and counts the distinct fortified functions in the given symbol table.
+fn scan_hardening_symbols(symbols: &SymbolEntries) -> Result<(bool, usize)> {
+    let mut stack_canary = false;
+    // the same function may be referenced by several symbols, for example in both the local and the global part of
+    // the symbol table, so only its first occurrence is counted.
+    let mut seen_fortified_functions: [&[u8]; MAX_FORTIFIED_FUNCTIONS] =
+        [&[]; MAX_FORTIFIED_FUNCTIONS];
+    let mut fortified_functions = 0;
+    for symbol in symbols.iter() {
+        let symbol = symbol?;
+        let name = symbol.name_bytes()?;
+        if STACK_CANARY_SYMBOLS.contains(&name) {
+            stack_canary = true;
+        } else if fortified_functions < MAX_FORTIFIED_FUNCTIONS
+            && symbol.related_section_index() == SHN_UNDEF
+            && is_fortified_function_name(name)
+            && !seen_fortified_functions[..fortified_functions].contains(&name)
+        {
+            seen_fortified_functions[fortified_functions] = name;
+            fortified_functions += 1;
+        }
+    }
+    Ok((stack_canary, fortified_functions))
+}
+
+fn is_fortified_function_name(name: &[u8]) -> bool {
+    name.starts_with(b"__") && name.ends_with(b"_chk") && name.len() > b"___chk".len()
+}
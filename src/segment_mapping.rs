@@ -0,0 +1,199 @@
+use crate::{
+    ProgramHeader, ProgramHeaderRef, ProgramHeaderType, ProgramHeadersIter, Result, SectionHeader,
+    SectionHeaderFlags, SectionHeaderRef, SectionHeaderType, SectionHeadersIter,
+};
+
+impl ProgramHeader {
+    /// is the given section a tls `NOBITS` section (`.tbss`) which is not being checked against a `PT_TLS` segment.
+    ///
+    /// such sections don't occupy any space in the memory image of the segment, since each thread gets its own copy of
+    /// them, so they are treated as if they were empty.
+    pub fn is_tbss_special(&self, section: &SectionHeader) -> bool {
+        section.flags().contains(SectionHeaderFlags::TLS)
+            && *section.ty() == SectionHeaderType::Nobits
+            && *self.ty() != ProgramHeaderType::Tls
+    }
+
+    /// the size that the given section occupies inside of this segment.
+    fn section_size_in_segment(&self, section: &SectionHeader) -> u64 {
+        if self.is_tbss_special(section) {
+            0
+        } else {
+            section.size()
+        }
+    }
+
+    /// checks if the type and flags of the given section allow it to be placed inside of this segment.
+    fn can_contain_section_kind(&self, section: &SectionHeader) -> bool {
+        let ty = *self.ty();
+        let known_ty = ty.known();
+        let flags = section.flags();
+
+        // only `PT_LOAD`, `PT_GNU_RELRO` and `PT_TLS` segments can contain tls sections. `PT_TLS` segments contain only tls
+        // sections, and `PT_PHDR` segments contain no sections at all.
+        let tls_ok = if flags.contains(SectionHeaderFlags::TLS) {
+            matches!(
+                known_ty,
+                Some(
+                    ProgramHeaderType::Tls | ProgramHeaderType::GnuRelro | ProgramHeaderType::Load
+                )
+            )
+        } else {
+            ty != ProgramHeaderType::Tls && ty != ProgramHeaderType::Phdr
+        };
+
+        // loadable segments only contain allocated sections.
+        let is_loadable_segment = matches!(
+            known_ty,
+            Some(
+                ProgramHeaderType::Load
+                    | ProgramHeaderType::Dynamic
+                    | ProgramHeaderType::GnuEhFrame
+                    | ProgramHeaderType::GnuStack
+                    | ProgramHeaderType::GnuRelro
+                    | ProgramHeaderType::GnuSframe
+            )
+        ) || (ProgramHeaderType::GnuMbindLo as u32
+            ..=ProgramHeaderType::GnuMbindHi as u32)
+            .contains(&ty.raw());
+        let alloc_ok = flags.contains(SectionHeaderFlags::ALLOC) || !is_loadable_segment;
+
+        tls_ok && alloc_ok
+    }
+
+    /// checks if the file offset range of the given section is contained in the file offset range of this segment.
+    ///
+    /// `NOBITS` sections have no content in the file, so they are always considered to be contained in the segment's file
+    /// range.
+    pub fn contains_section_in_file(&self, section: &SectionHeader) -> bool {
+        if *section.ty() == SectionHeaderType::Nobits {
+            return true;
+        }
+        let section_offset = section.offset();
+        let segment_offset = self.offset();
+        let segment_size = self.size_in_file();
+        section_offset >= segment_offset
+            && section_offset - segment_offset <= segment_size.wrapping_sub(1)
+            && (section_offset - segment_offset).wrapping_add(self.section_size_in_segment(section))
+                <= segment_size
+    }
+
+    /// checks if the virtual address range of the given section is contained in the virtual address range of this
+    /// segment.
+    ///
+    /// sections which are not allocated have no address in memory, so they are always considered to be contained in the
+    /// segment's memory range.
+    pub fn contains_section_in_memory(&self, section: &SectionHeader) -> bool {
+        if !section.flags().contains(SectionHeaderFlags::ALLOC) {
+            return true;
+        }
+        let section_addr = section.address();
+        let segment_addr = self.virt_addr();
+        let segment_size = self.size_in_memory();
+        section_addr >= segment_addr
+            && section_addr - segment_addr <= segment_size.wrapping_sub(1)
+            && (section_addr - segment_addr).wrapping_add(self.section_size_in_segment(section))
+                <= segment_size
+    }
+
+    /// empty sections which lie exactly on the start or the end of a `PT_DYNAMIC` or `PT_NOTE` segment are not considered
+    /// to be a part of it, since they just happen to be adjacent to it.
+    fn is_empty_section_at_boundary(&self, section: &SectionHeader) -> bool {
+        if !matches!(
+            self.ty().known(),
+            Some(ProgramHeaderType::Dynamic | ProgramHeaderType::Note)
+        ) || section.size() != 0
+            || self.size_in_memory() == 0
+        {
+            return false;
+        }
+        let is_strictly_inside_file_range = *section.ty() == SectionHeaderType::Nobits
+            || (section.offset() > self.offset()
+                && section.offset() - self.offset() < self.size_in_file());
+        let is_strictly_inside_memory_range = !section.flags().contains(SectionHeaderFlags::ALLOC)
+            || (section.address() > self.virt_addr()
+                && section.address() - self.virt_addr() < self.size_in_memory());
+        !(is_strictly_inside_file_range && is_strictly_inside_memory_range)
+    }
+
+    /// checks if the given section is contained in this segment, using the same rules that `readelf` uses when
+    /// displaying the section to segment mapping.
+    pub fn contains_section(&self, section: &SectionHeader) -> bool {
+        !self.is_tbss_special(section)
+            && self.can_contain_section_kind(section)
+            && self.contains_section_in_file(section)
+            && self.contains_section_in_memory(section)
+            && !self.is_empty_section_at_boundary(section)
+    }
+}
+
+impl<'a> ProgramHeaderRef<'a> {
+    /// returns an iterator over the sections which are contained in this segment.
+    pub fn sections(&self) -> Result<SegmentSectionsIter<'a>> {
+        let mut sections_iter = self.parser.section_headers()?.iter();
+
+        // skip the null section, which is never a part of any segment.
+        sections_iter.next();
+
+        Ok(SegmentSectionsIter {
+            segment: self.raw.clone(),
+            sections_iter,
+        })
+    }
+}
+
+impl<'a> SectionHeaderRef<'a> {
+    /// returns an iterator over the segments which contain this section.
+    pub fn segments(&self) -> Result<SectionSegmentsIter<'a>> {
+        Ok(SectionSegmentsIter {
+            section: self.raw.clone(),
+            segments_iter: self.parser.program_headers()?.iter(),
+        })
+    }
+}
+
+/// an iterator over the sections which are contained in some segment.
+#[derive(Debug, Clone)]
+pub struct SegmentSectionsIter<'a> {
+    segment: ProgramHeader,
+    sections_iter: SectionHeadersIter<'a>,
+}
+impl<'a> Iterator for SegmentSectionsIter<'a> {
+    type Item = Result<SectionHeaderRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.sections_iter.next()? {
+                Ok(section) => {
+                    if self.segment.contains_section(&section) {
+                        return Some(Ok(section));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// an iterator over the segments which contain some section.
+#[derive(Debug, Clone)]
+pub struct SectionSegmentsIter<'a> {
+    section: SectionHeader,
+    segments_iter: ProgramHeadersIter<'a>,
+}
+impl<'a> Iterator for SectionSegmentsIter<'a> {
+    type Item = Result<ProgramHeaderRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.segments_iter.next()? {
+                Ok(segment) => {
+                    if segment.contains_section(&self.section) {
+                        return Some(Ok(segment));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use alloc::{format, string::String, vec::Vec};
+use serde::{Serialize, Serializer};
+
+use crate::{
+    DecodedType, ElfFlags, ElfHeader, ElfParser, Error, GenericRel, LossyStr, MaybeUnknown,
+    ProgramHeaderFlags, ProgramHeaderRef, RawEnum, RelocationTypeOrUnknown,
+    RelocationTypeOrUnknownU8, Result, SectionData, SectionHeaderFlags, SectionHeaderFlagsU32,
+    SectionHeaderRef, SymbolRef,
+};
+
+/// serializes an address, offset or size as a hex string.
+pub(crate) fn serialize_hex<T: Copy + Into<u64>, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    let value: u64 = (*value).into();
+    serializer.collect_str(&format_args!("{:#x}", value))
+}
+
+/// serializes an enum value using the name of its variant. generated enums merge the values which have different names
+/// in different architechtures or operating systems into a single variant, and the meaning of such values can't be
+/// known without the file, so they are serialized as a raw hex value.
+pub(crate) fn serialize_enum_name<T: RawEnum + core::fmt::Debug, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    let name = format!("{:?}", value);
+    if name.starts_with('_') {
+        serializer.collect_str(&format_args!("{:#x}", value.to_raw()))
+    } else {
+        serializer.serialize_str(&name)
+    }
+}
+
+impl<T: RawEnum + Serialize> Serialize for MaybeUnknown<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            MaybeUnknown::Known(value) => value.serialize(serializer),
+            MaybeUnknown::Unknown(raw) => serializer.collect_str(&format_args!("{:#x}", raw)),
+        }
+    }
+}
+
+impl Serialize for ProgramHeaderFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        bitflags::serde::serialize(self, serializer)
+    }
+}
+
+impl Serialize for SectionHeaderFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        bitflags::serde::serialize(self, serializer)
+    }
+}
+
+impl Serialize for SectionHeaderFlagsU32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        SectionHeaderFlags::from(*self).serialize(serializer)
+    }
+}
+
+/// the meaning of the elf flags depends on the architechture of the file, so they are serialized as a raw hex value.
+impl Serialize for ElfFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serialize_hex(&self.bits(), serializer)
+    }
+}
+
+impl Serialize for RelocationTypeOrUnknownU8 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        RelocationTypeOrUnknown::from(*self).serialize(serializer)
+    }
+}
+
+impl<'a> Serialize for LossyStr<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Serialize)]
+struct FileSummary<'a> {
+    header: ElfHeader,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interpreter: Option<LossyStr<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_id: Option<HexBytes<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic: Option<DynamicSummary<'a>>,
+    program_headers: Vec<ProgramHeaderSummary<'a>>,
+    sections: Vec<SectionSummary<'a>>,
+}
+
+/// the values of the dynamic section which describe how the file is loaded.
+#[derive(Serialize)]
+struct DynamicSummary<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soname: Option<LossyStr<'a>>,
+    needed: Vec<LossyStr<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rpath: Option<LossyStr<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    runpath: Option<LossyStr<'a>>,
+}
+
+#[derive(Serialize)]
+struct ProgramHeaderSummary<'a> {
+    /// the name of the type according to the architechture and os abi of the file.
+    type_name: Option<&'static str>,
+    #[serde(flatten)]
+    header: ProgramHeaderRef<'a>,
+}
+
+#[derive(Serialize)]
+struct SectionSummary<'a> {
+    index: usize,
+    name: LossyStr<'a>,
+    type_name: Option<&'static str>,
+    #[serde(flatten)]
+    header: SectionHeaderRef<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbols: Option<Vec<SymbolSummary<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    relocations: Option<Vec<RelocationSummary>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic_entries: Option<Vec<DynamicEntrySummary>>,
+}
+
+#[derive(Serialize)]
+struct SymbolSummary<'a> {
+    name: LossyStr<'a>,
+    type_name: Option<&'static str>,
+    #[serde(flatten)]
+    symbol: SymbolRef<'a>,
+}
+
+#[derive(Serialize)]
+struct RelocationSummary {
+    /// the name of the type according to the architechture of the file. for mips64 relocations, which have several
+    /// types, this is the name of the first type.
+    type_name: Option<&'static str>,
+    #[serde(flatten)]
+    relocation: GenericRel,
+}
+
+#[derive(Serialize)]
+struct DynamicEntrySummary {
+    /// the name of the tag, or its raw value if it is unknown.
+    tag: TypeName,
+    #[serde(serialize_with = "serialize_hex")]
+    value: u64,
+}
+
+/// the name of a decoded type, or its raw value as hex if it is unknown.
+struct TypeName(DecodedType);
+impl Serialize for TypeName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self.0.short_name() {
+            Some(name) => serializer.serialize_str(name),
+            None => serialize_hex(&self.0.raw, serializer),
+        }
+    }
+}
+
+struct HexBytes<'a>(&'a [u8]);
+impl<'a> Serialize for HexBytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}", self))
+    }
+}
+impl<'a> core::fmt::Display for HexBytes<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ElfParser<'a> {
+    /// returns a json document which describes the whole file: its header, program headers and sections, along with
+    /// the symbols, relocations and dynamic entries of the sections which contain them.
+    pub fn to_json_summary(&self) -> Result<String> {
+        let file_info = self.file_info();
+
+        let dynamic = match self.dynamic_section()? {
+            Some(dynamic) => Some(DynamicSummary {
+                soname: dynamic.soname()?.map(LossyStr),
+                needed: dynamic
+                    .needed()
+                    .map(|needed| needed.map(LossyStr))
+                    .collect::<Result<_>>()?,
+                rpath: dynamic.rpath()?.map(LossyStr),
+                runpath: dynamic.runpath()?.map(LossyStr),
+            }),
+            None => None,
+        };
+
+        let mut program_headers = Vec::new();
+        for header in self.program_headers()? {
+            let header = header?;
+            program_headers.push(ProgramHeaderSummary {
+                type_name: header.ty().decode(&file_info).short_name(),
+                header,
+            });
+        }
+
+        let mut sections = Vec::new();
+        for (index, header) in self.section_headers()?.iter().enumerate() {
+            let header = header?;
+            let mut section = SectionSummary {
+                index,
+                name: header.name_lossy()?,
+                type_name: header.ty().decode(&file_info).short_name(),
+                header: header.clone(),
+                symbols: None,
+                relocations: None,
+                dynamic_entries: None,
+            };
+            match header.data()? {
+                SectionData::SymbolTable(symbols) | SectionData::DynamicSymbolTable(symbols) => {
+                    let mut summaries = Vec::new();
+                    for symbol in &symbols {
+                        let symbol = symbol?;
+                        summaries.push(SymbolSummary {
+                            name: symbol.name_lossy()?,
+                            type_name: symbol.info().ty.decode(&file_info).short_name(),
+                            symbol,
+                        });
+                    }
+                    section.symbols = Some(summaries);
+                }
+                SectionData::RelocationSection(relocations) => {
+                    let mut summaries = Vec::new();
+                    for relocation in relocations.entries.iter() {
+                        let relocation = relocation?;
+                        summaries.push(RelocationSummary {
+                            type_name: file_info
+                                .decode_relocation_type(relocation.raw_type())
+                                .short_name(),
+                            relocation,
+                        });
+                    }
+                    section.relocations = Some(summaries);
+                }
+                SectionData::Dynamic(entries) => {
+                    let mut summaries = Vec::new();
+                    for entry in &entries {
+                        let entry = entry?;
+                        summaries.push(DynamicEntrySummary {
+                            tag: TypeName(file_info.decode_dynamic_tag(entry.tag())),
+                            value: entry.value(),
+                        });
+                    }
+                    section.dynamic_entries = Some(summaries);
+                }
+                SectionData::StringTable(_) | SectionData::UnknownSectionType => {}
+            }
+            sections.push(section);
+        }
+
+        let summary = FileSummary {
+            header: self.header()?,
+            interpreter: self.interpreter()?.map(LossyStr),
+            build_id: self.build_id()?.map(HexBytes),
+            dynamic,
+            program_headers,
+            sections,
+        };
+        serde_json::to_string(&summary).map_err(|_| Error::JsonSerializeError)
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{DebugIgnore, Error, Result, StringTable};
+
+/// a builder for string tables, which deduplicates the strings added to it and merges strings which are suffixes of
+/// previously added strings, such that `bar` shares its storage with `foobar`.
+///
+/// the offset of each string is known as soon as it is added, and it doesn't change when more strings are added, so
+/// it can be stored in structures before the final layout of the file is known. since strings can only be merged into
+/// strings which were added before them, adding longer strings first results in a smaller table.
+#[derive(Debug, Clone)]
+pub struct StringTableBuilder {
+    content: Vec<u8>,
+
+    /// maps the hash of each null terminated suffix of each string in the table to the offset of that suffix.
+    suffixes: BTreeMap<u64, u32>,
+}
+impl StringTableBuilder {
+    /// creates a new string table builder. the table initially contains only the empty string at offset 0, as
+    /// required by the elf specification.
+    pub fn new() -> Self {
+        Self {
+            content: alloc::vec![0],
+            suffixes: BTreeMap::new(),
+        }
+    }
+
+    /// creates a string table builder which contains the strings of an existing string table. the offsets of the
+    /// existing strings are preserved, and strings which are added later can share their storage.
+    pub fn from_bytes(content: Vec<u8>) -> Result<Self> {
+        if content.is_empty() {
+            return Ok(Self::new());
+        }
+        if content.last() != Some(&0) {
+            return Err(Error::StringTableNotNullTerminated);
+        }
+        if u32::try_from(content.len()).is_err() {
+            return Err(Error::StringTableIsTooLarge);
+        }
+        let mut builder = Self {
+            content,
+            suffixes: BTreeMap::new(),
+        };
+        let mut start = 0;
+        while let Some(len) = builder.content[start..].iter().position(|&b| b == 0) {
+            builder.register_suffixes(start, len);
+            start += len + 1;
+        }
+        Ok(builder)
+    }
+
+    /// adds a string to the table and returns its offset in the table.
+    ///
+    /// fails if the string contains a null byte, or if the size of the table would exceed the maximum `u32` value.
+    pub fn add(&mut self, string: impl AsRef<[u8]>) -> Result<u32> {
+        let string = string.as_ref();
+        if string.contains(&0) {
+            return Err(Error::StringContainsNullByte);
+        }
+        if let Some(offset) = self.offset_of(string) {
+            return Ok(offset);
+        }
+
+        if u32::try_from(self.content.len() + string.len() + 1).is_err() {
+            return Err(Error::StringTableIsTooLarge);
+        }
+        let offset = self.content.len();
+        self.content.extend_from_slice(string);
+        self.content.push(0);
+        self.register_suffixes(offset, string.len());
+        Ok(offset as u32)
+    }
+
+    /// registers all suffixes of the string of the given length at the given offset, so that strings added later can
+    /// share its storage. if some suffix already exists in the table, its previous offset is kept.
+    fn register_suffixes(&mut self, offset: usize, len: usize) {
+        let mut hash = SuffixHash::new();
+        for (i, &byte) in self.content[offset..offset + len].iter().enumerate().rev() {
+            hash.push_front(byte);
+            self.suffixes.entry(hash.0).or_insert((offset + i) as u32);
+        }
+    }
+
+    /// returns the offset of the given string if it is already present in the table, either as a full string or as a
+    /// suffix of another string.
+    pub fn offset_of(&self, string: impl AsRef<[u8]>) -> Option<u32> {
+        let string = string.as_ref();
+        if string.is_empty() {
+            return Some(0);
+        }
+        let mut hash = SuffixHash::new();
+        for &byte in string.iter().rev() {
+            hash.push_front(byte);
+        }
+        let offset = *self.suffixes.get(&hash.0)?;
+
+        // different strings may have the same hash, so make sure that the string at the offset is the requested one.
+        let start = offset as usize;
+        let end = start + string.len();
+        (self.content.get(start..end) == Some(string) && self.content.get(end) == Some(&0))
+            .then_some(offset)
+    }
+
+    /// the size of the serialized string table.
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    /// checks if the table contains only the empty string.
+    pub fn is_empty(&self) -> bool {
+        self.content.len() == 1
+    }
+
+    /// the serialized content of the string table.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// returns the serialized content of the string table.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.content
+    }
+
+    /// returns a reader for the strings that were added to the table.
+    pub fn as_string_table(&self) -> StringTable<'_> {
+        StringTable {
+            content: DebugIgnore(self.content.as_slice()),
+        }
+    }
+}
+impl Default for StringTableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// a polynomial hash of a string, which can be updated by prepending bytes to the string.
+struct SuffixHash(u64, u64);
+impl SuffixHash {
+    const BASE: u64 = 0x100000001b3;
+
+    fn new() -> Self {
+        Self(0, 1)
+    }
+
+    fn push_front(&mut self, byte: u8) {
+        // the byte is offset by one so that strings of zero bytes of different lengths have different hashes.
+        self.0 = self.0.wrapping_add((byte as u64 + 1).wrapping_mul(self.1));
+        self.1 = self.1.wrapping_mul(Self::BASE);
+    }
+}
@@ -0,0 +1,369 @@
+use alloc::vec::Vec;
+
+use crate::{
+    ElfFileType, ElfRewriter, GenericRelEntries, ProgramHeaderType, Rel, Rela, Result,
+    RewriterSectionId, SectionData, SectionHeader, SectionHeader32, SectionHeader64,
+    SectionHeaderFlags, SectionHeaderType, SymbolBinding, SymbolType, VariantStructBinarySerde,
+    SHN_LORESERVE, SHN_UNDEF,
+};
+
+/// the name of the section which links a stripped file to its separate debug file.
+pub const GNU_DEBUGLINK_SECTION_NAME: &[u8] = b".gnu_debuglink";
+
+/// the symbols which are removed when stripping a file. the modes match the modes of gnu strip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StripMode {
+    /// removes the symbol table and the debug sections, like `strip --strip-all`. the relocation sections which refer
+    /// to the symbol table are removed along with it.
+    All,
+
+    /// removes the debug sections and the debugging symbols, like `strip --strip-debug`.
+    Debug,
+
+    /// removes the debug sections and all symbols which are not needed for relocation processing, like
+    /// `strip --strip-unneeded`. for files which are not relocatable, no symbols are needed, so the symbol table is
+    /// removed.
+    Unneeded,
+}
+
+/// is the section with the given name a debug section.
+pub fn is_debug_section_name(name: &[u8]) -> bool {
+    [
+        &b".debug"[..],
+        b".zdebug",
+        b".gnu.debuglto_",
+        b".gnu.linkonce.wi.",
+        b".line",
+        b".stab",
+        b".gdb_index",
+    ]
+    .iter()
+    .any(|prefix| name.starts_with(prefix))
+}
+
+/// calculates the crc32 checksum which is stored in the `.gnu_debuglink` section, which is the standard crc32 used by
+/// zlib.
+pub fn gnu_debuglink_crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                0xedb88320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+};
+
+impl<'a> ElfRewriter<'a> {
+    /// strips the debug information and the symbols of the file, according to the given mode.
+    pub fn strip(&mut self, mode: StripMode) -> Result<()> {
+        let debug_sections: Vec<RewriterSectionId> = self
+            .section_ids()
+            .filter(|&id| {
+                let section = self.section(id);
+                !section.header.flags().contains(SectionHeaderFlags::ALLOC)
+                    && is_debug_section_name(section.name())
+            })
+            .collect();
+        for id in debug_sections {
+            self.remove_section(id);
+        }
+
+        let is_relocatable = *self.header().ty() == ElfFileType::Rel;
+        let symbol_tables: Vec<RewriterSectionId> = self
+            .section_ids()
+            .filter(|&id| *self.section(id).header.ty() == SectionHeaderType::Symtab)
+            .collect();
+        for id in symbol_tables {
+            match mode {
+                StripMode::All => self.remove_symbol_table(id),
+                StripMode::Unneeded if !is_relocatable => self.remove_symbol_table(id),
+                StripMode::Debug | StripMode::Unneeded => self.filter_symbol_table(id, mode)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// the live sections whose link field references the given section.
+    fn sections_linked_to(&self, id: RewriterSectionId) -> Vec<RewriterSectionId> {
+        self.section_ids()
+            .filter(|&other| self.section(other).link() == Some(id))
+            .collect()
+    }
+
+    /// removes a symbol table along with its string table, its extended section indexes table and the relocation
+    /// sections which refer to it.
+    fn remove_symbol_table(&mut self, id: RewriterSectionId) {
+        for linked in self.sections_linked_to(id) {
+            self.remove_section(linked);
+        }
+        self.remove_section(id);
+
+        let Some(string_table) = self.section(id).link() else {
+            return;
+        };
+        let names_section = self
+            .original_section(self.header().section_names_section_index() as usize)
+            .filter(|_| self.header().section_names_section_index() != SHN_UNDEF);
+        if Some(string_table) != names_section && self.sections_linked_to(string_table).is_empty() {
+            self.remove_section(string_table);
+        }
+    }
+
+    /// removes the symbols of a symbol table which should be stripped according to the given mode, and fixes the
+    /// relocation sections which refer to the symbol table.
+    fn filter_symbol_table(&mut self, id: RewriterSectionId, mode: StripMode) -> Result<()> {
+        let Some(original_index) = self.section(id).original_index() else {
+            return Ok(());
+        };
+        let parser = self.parser().clone();
+        let section_headers = parser.section_headers()?;
+        let SectionData::SymbolTable(symbols) = section_headers.get(original_index)?.data()? else {
+            return Ok(());
+        };
+        let relocation_sections: Vec<(RewriterSectionId, GenericRelEntries)> = self
+            .sections_linked_to(id)
+            .into_iter()
+            .filter_map(|rel_id| {
+                let index = self.section(rel_id).original_index()?;
+                match section_headers
+                    .get(index)
+                    .and_then(|section| section.data())
+                {
+                    Ok(SectionData::RelocationSection(rel_section)) => {
+                        Some(Ok((rel_id, rel_section.entries)))
+                    }
+                    Ok(_) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .collect::<Result<_>>()?;
+
+        // the signature symbol of a section group is referenced by the `sh_info` field of the group section.
+        let group_sections: Vec<RewriterSectionId> = self
+            .sections_linked_to(id)
+            .into_iter()
+            .filter(|&group_id| *self.section(group_id).header.ty() == SectionHeaderType::Group)
+            .collect();
+
+        let mut is_referenced = alloc::vec![false; symbols.len()];
+        for (_, entries) in &relocation_sections {
+            for rel in entries.iter() {
+                if let Some(referenced) = is_referenced.get_mut(rel?.symbol_index() as usize) {
+                    *referenced = true;
+                }
+            }
+        }
+        for &group_id in &group_sections {
+            let signature_index = self.section(group_id).header.info() as usize;
+            if let Some(referenced) = is_referenced.get_mut(signature_index) {
+                *referenced = true;
+            }
+        }
+
+        let record_len = self.section(id).header.entry_size() as usize;
+        let content = self.section(id).content().to_vec();
+        let original_first_non_local = self.section(id).header.info() as usize;
+        let mut new_content = Vec::with_capacity(content.len());
+        let mut new_indexes = alloc::vec![None; symbols.len()];
+        let mut first_non_local = 0;
+        let mut kept_amount = 0;
+        for (index, symbol) in symbols.iter().enumerate() {
+            let symbol = symbol?;
+            let section_index = symbol.related_section_index();
+            let is_in_removed_section = section_index != SHN_UNDEF
+                && section_index < SHN_LORESERVE
+                && self
+                    .original_section(section_index as usize)
+                    .is_none_or(|section| self.section(section).is_removed());
+            let keep = index == 0
+                || (!is_in_removed_section
+                    && match mode {
+                        StripMode::Debug => symbol.info().ty != SymbolType::File,
+                        _ => is_referenced[index] || symbol.info().binding != SymbolBinding::Local,
+                    });
+            if !keep {
+                continue;
+            }
+            new_indexes[index] = Some(kept_amount as u32);
+            kept_amount += 1;
+            if index < original_first_non_local {
+                first_non_local = kept_amount;
+            }
+            new_content.extend_from_slice(&content[index * record_len..(index + 1) * record_len]);
+        }
+        self.set_section_content(id, new_content);
+        let section = self.section_mut(id);
+        section.header.set_info(first_non_local as u32);
+
+        for (rel_id, entries) in relocation_sections {
+            if self.section(rel_id).is_removed() {
+                continue;
+            }
+            let new_index = |index: u32| {
+                new_indexes
+                    .get(index as usize)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(0)
+            };
+            let file_info = parser.file_info();
+            let mut content = Vec::new();
+            match entries {
+                GenericRelEntries::RelEntries(entries) => {
+                    for rel in entries.iter() {
+                        let mut rel = rel?;
+                        rel.set_symbol_index(new_index(rel.symbol_index()));
+                        let start = content.len();
+                        content.resize(start + Rel::record_len(&file_info), 0);
+                        rel.serialize(&mut content[start..], file_info.endianness);
+                    }
+                }
+                GenericRelEntries::RelaEntries(entries) => {
+                    for rela in entries.iter() {
+                        let mut rela = rela?;
+                        rela.set_symbol_index(new_index(rela.symbol_index()));
+                        let start = content.len();
+                        content.resize(start + Rela::record_len(&file_info), 0);
+                        rela.serialize(&mut content[start..], file_info.endianness);
+                    }
+                }
+            }
+            self.set_section_content(rel_id, content);
+        }
+
+        for group_id in group_sections {
+            let signature_index = self.section(group_id).header.info() as usize;
+            let new_signature_index = new_indexes
+                .get(signature_index)
+                .copied()
+                .flatten()
+                .unwrap_or(0);
+            self.section_mut(group_id)
+                .header
+                .set_info(new_signature_index);
+        }
+
+        // the extended section indexes table has an entry for each symbol.
+        for shndx_id in self.sections_linked_to(id) {
+            if *self.section(shndx_id).header.ty() != SectionHeaderType::SymtabShndx {
+                continue;
+            }
+            let content = self.section(shndx_id).content();
+            let new_content: Vec<u8> = content
+                .chunks_exact(4)
+                .zip(&new_indexes)
+                .filter(|(_, new_index)| new_index.is_some())
+                .flat_map(|(entry, _)| entry.iter().copied())
+                .collect();
+            self.set_section_content(shndx_id, new_content);
+        }
+        Ok(())
+    }
+
+    /// converts the file to a separate debug file, like `objcopy --only-keep-debug`. the content of the allocated
+    /// sections other than notes is replaced with `NOBITS` placeholders, and the segments no longer refer to any
+    /// content in the file other than the program header table and the notes. the section indexes and the symbol table
+    /// are kept intact, so the debug file matches the stripped file.
+    pub fn only_keep_debug(&mut self) {
+        let placeholders: Vec<RewriterSectionId> = self
+            .section_ids()
+            .filter(|&id| {
+                let header = &self.section(id).header;
+                header.flags().contains(SectionHeaderFlags::ALLOC)
+                    && *header.ty() != SectionHeaderType::Note
+                    && *header.ty() != SectionHeaderType::Nobits
+            })
+            .collect();
+        for id in placeholders {
+            self.convert_to_nobits(id);
+        }
+        // only the elf header, the program header table and the notes are left in the segments. the notes are kept in
+        // place, since they are part of the content of the segments.
+        let headers_end = self.header().program_headers_off()
+            + self.header().program_headers_amount() as u64
+                * self.header().program_header_entry_size() as u64;
+        for program_header in self.program_headers_mut() {
+            let new_size = if program_header.offset() == 0
+                && *program_header.ty() == ProgramHeaderType::Load
+            {
+                headers_end.min(program_header.size_in_file())
+            } else if matches!(
+                program_header.ty().known(),
+                Some(
+                    ProgramHeaderType::Phdr
+                        | ProgramHeaderType::Note
+                        | ProgramHeaderType::GnuProperty
+                )
+            ) {
+                program_header.size_in_file()
+            } else {
+                0
+            };
+            program_header.set_size_in_file(new_size);
+        }
+    }
+
+    /// adds a `.gnu_debuglink` section which links the file to a separate debug file with the given file name and
+    /// crc32 checksum, which can be calculated using [`gnu_debuglink_crc32`]. an existing `.gnu_debuglink` section is
+    /// replaced.
+    pub fn add_gnu_debuglink(&mut self, debug_file_name: &[u8], crc: u32) -> RewriterSectionId {
+        let endianness = self.parser().file_info().endianness;
+        let mut content = debug_file_name.to_vec();
+        content.push(0);
+        content.resize(content.len().next_multiple_of(4), 0);
+        content.extend_from_slice(&match endianness {
+            binary_serde::Endianness::Big => crc.to_be_bytes(),
+            binary_serde::Endianness::Little => crc.to_le_bytes(),
+        });
+
+        if let Some(id) = self.find_section(GNU_DEBUGLINK_SECTION_NAME) {
+            self.set_section_content(id, content);
+            return id;
+        }
+        let header = match self.parser().file_info().bit_length {
+            crate::ArchBitLength::Arch32Bit => SectionHeader::SectionHeader32(SectionHeader32 {
+                name_offset: 0,
+                ty: SectionHeaderType::Progbits.into(),
+                flags: SectionHeaderFlags::empty().try_into().unwrap(),
+                address: 0,
+                offset: 0,
+                size: 0,
+                link: 0,
+                info: 0,
+                address_alignemnt: 4,
+                entry_size: 0,
+            }),
+            crate::ArchBitLength::Arch64Bit => SectionHeader::SectionHeader64(SectionHeader64 {
+                name_offset: 0,
+                ty: SectionHeaderType::Progbits.into(),
+                flags: SectionHeaderFlags::empty(),
+                address: 0,
+                offset: 0,
+                size: 0,
+                link: 0,
+                info: 0,
+                address_alignemnt: 4,
+                entry_size: 0,
+            }),
+        };
+        self.add_section(GNU_DEBUGLINK_SECTION_NAME, header, content)
+    }
+}
@@ -0,0 +1,472 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    find_relocation_type, Architechture, DynamicTag, ElfParser, MaybeUnknown, Result, SectionData,
+    SymbolBinding, SymbolEntries, SymbolRef, SymbolType, SymbolVersion, SymbolVersions,
+    SymbolVisibility, SHN_UNDEF, VERSYM_HIDDEN,
+};
+
+const DF_SYMBOLIC: u64 = 0x2;
+
+/// the version indexes below this value are accepted by unversioned references, see `check_match` in glibc.
+const MAX_UNVERSIONED_REFERENCE_VERSION_INDEX: u16 = 3;
+
+/// how a symbol reference was bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingKind {
+    /// the definition was found by searching the global scope.
+    GlobalScope,
+
+    /// the referencing object has `DT_SYMBOLIC`, so its own definition was found before searching the global scope.
+    Symbolic,
+
+    /// the referenced symbol is defined by the referencing object with a non default visibility, so it can't be
+    /// interposed.
+    Protected,
+
+    /// the referenced symbol is a local symbol of the referencing object.
+    Local,
+
+    /// the definition is an `STB_GNU_UNIQUE` symbol, so the reference was bound to the first definition of the symbol
+    /// in the process, which all references to it share.
+    Unique,
+
+    /// the reference is a copy relocation, so the definition was searched for in the global scope while skipping the
+    /// referencing object, which holds the copy.
+    CopyRelocation,
+
+    /// no definition was found for a strong reference, so the dynamic loader would fail to bind it.
+    Unresolved,
+
+    /// no definition was found for a weak reference, so it is bound to address 0.
+    WeakUnresolved,
+}
+
+/// a symbol of one of the objects of the simulated process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScopeSymbol {
+    /// the index of the object, in the order in which the objects were added to the simulator.
+    pub object: usize,
+
+    /// the index of the symbol in the dynamic symbol table of the object.
+    pub symbol_index: usize,
+}
+
+/// the binding of a symbol which is referenced by the dynamic relocations of an object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundSymbol {
+    /// the referencing symbol.
+    pub reference: ScopeSymbol,
+    pub name: Vec<u8>,
+
+    /// the version which the reference requires, if any.
+    pub version: Option<Vec<u8>>,
+
+    /// the symbol which the reference is bound to, or `None` if the reference is unresolved.
+    pub definition: Option<ScopeSymbol>,
+    pub kind: BindingKind,
+}
+
+/// a definition which hides the definitions of the same symbol in objects that come after it in the global scope.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Interposition {
+    pub name: Vec<u8>,
+
+    /// the version of the winning definition, if it has one.
+    pub version: Option<Vec<u8>>,
+
+    /// the winning definition.
+    pub definition: ScopeSymbol,
+
+    /// the definitions which are hidden by the winning definition.
+    pub interposed: Vec<ScopeSymbol>,
+
+    /// the indexes of the objects whose references were bound to the winning definition.
+    pub referenced_by: Vec<usize>,
+}
+
+/// the result of a symbol resolution simulation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindingReport {
+    /// the bindings of all symbol references, ordered by the referencing object.
+    pub bindings: Vec<BoundSymbol>,
+
+    /// the definitions which interpose other definitions of the same symbol.
+    pub interpositions: Vec<Interposition>,
+}
+impl BindingReport {
+    /// returns an iterator over the bindings of the references of the given object.
+    pub fn bindings_of(&self, object: usize) -> impl Iterator<Item = &BoundSymbol> {
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.reference.object == object)
+    }
+
+    /// returns an iterator over the strong references which have no definition.
+    pub fn unresolved(&self) -> impl Iterator<Item = &BoundSymbol> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.kind == BindingKind::Unresolved)
+    }
+}
+
+/// an object of the simulated process.
+#[derive(Debug, Clone)]
+struct ScopeObject<'a> {
+    parser: ElfParser<'a>,
+    symbols: Option<SymbolEntries<'a>>,
+    versions: Option<SymbolVersions<'a>>,
+    symbolic: bool,
+
+    /// the dynamic symbols which are referenced by dynamic relocations, and whether they are referenced by a copy
+    /// relocation.
+    references: BTreeMap<usize, bool>,
+
+    /// the indexes of the exported definitions of the object, by name.
+    definitions: BTreeMap<&'a [u8], Vec<usize>>,
+}
+
+/// predicts which object satisfies each symbol reference of a process, by emulating the symbol lookup of the gnu
+/// dynamic loader.
+///
+/// the objects must be added in the order of the global scope, starting with the executable. this is the order
+/// returned by `DependencyTree::global_scope`. the references of each object are the symbols of its dynamic
+/// relocations, found using its section headers.
+///
+/// like the gnu dynamic loader, the first definition found wins, whether it is weak or strong.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolResolutionSimulator<'a> {
+    objects: Vec<ScopeObject<'a>>,
+}
+impl<'a> SymbolResolutionSimulator<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds the next object of the global scope, and returns its index.
+    pub fn add_object(&mut self, parser: ElfParser<'a>) -> Result<usize> {
+        let mut object = ScopeObject {
+            symbolic: false,
+            symbols: None,
+            versions: parser.symbol_versions()?,
+            references: BTreeMap::new(),
+            definitions: BTreeMap::new(),
+            parser,
+        };
+        if let Some(dynamic) = object.parser.dynamic_section()? {
+            object.symbolic = dynamic.value_of(DynamicTag::Symbolic as i64)?.is_some()
+                || dynamic
+                    .value_of(DynamicTag::Flags as i64)?
+                    .is_some_and(|flags| flags & DF_SYMBOLIC != 0);
+        }
+
+        let copy_relocation_type = copy_relocation_type(*object.parser.header()?.arch());
+        let mut dynamic_symbols_index = None;
+        for (index, section) in object.parser.section_headers()?.iter().enumerate() {
+            if let SectionData::DynamicSymbolTable(symbols) = section?.data()? {
+                object.symbols = Some(symbols);
+                dynamic_symbols_index = Some(index);
+                break;
+            }
+        }
+        let (Some(symbols), Some(dynamic_symbols_index)) =
+            (object.symbols.clone(), dynamic_symbols_index)
+        else {
+            self.objects.push(object);
+            return Ok(self.objects.len() - 1);
+        };
+
+        for section in object.parser.section_headers()? {
+            let SectionData::RelocationSection(relocations) = section?.data()? else {
+                continue;
+            };
+            if relocations.linked_symbol_table_index != dynamic_symbols_index {
+                continue;
+            }
+            for rel in &relocations.entries {
+                let rel = rel?;
+                let symbol_index = rel.symbol_index() as usize;
+                if symbol_index == 0 {
+                    continue;
+                }
+                let is_copy = Some(rel.raw_type()) == copy_relocation_type;
+                *object.references.entry(symbol_index).or_default() |= is_copy;
+            }
+        }
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            let symbol = symbol?;
+            if is_exported_definition(&symbol) {
+                object
+                    .definitions
+                    .entry(symbol.name_bytes()?)
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        self.objects.push(object);
+        Ok(self.objects.len() - 1)
+    }
+
+    /// the amount of objects in the global scope.
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// binds the references of all of the objects and finds the interpositions.
+    pub fn simulate(&self) -> Result<BindingReport> {
+        let mut bindings = Vec::new();
+        for (object_index, object) in self.objects.iter().enumerate() {
+            let Some(symbols) = &object.symbols else {
+                continue;
+            };
+            for (&symbol_index, &is_copy) in &object.references {
+                let symbol = symbols.get(symbol_index)?;
+                bindings.push(self.bind(object_index, symbol_index, &symbol, is_copy)?);
+            }
+        }
+
+        let mut interpositions: BTreeMap<ScopeSymbol, Interposition> = BTreeMap::new();
+        for binding in &bindings {
+            let Some(definition) = binding.definition else {
+                continue;
+            };
+            if binding.kind != BindingKind::GlobalScope {
+                continue;
+            }
+            let version = binding.version.as_deref();
+            let mut interposed = Vec::new();
+            for object in (definition.object + 1)..self.objects.len() {
+                if let Some(symbol_index) = self.lookup_in_object(object, &binding.name, version)? {
+                    interposed.push(ScopeSymbol {
+                        object,
+                        symbol_index,
+                    });
+                }
+            }
+            if interposed.is_empty() {
+                continue;
+            }
+            let definition_version = self.version_name_of(definition)?;
+            let interposition = interpositions
+                .entry(definition)
+                .or_insert_with(|| Interposition {
+                    name: binding.name.clone(),
+                    version: definition_version,
+                    definition,
+                    interposed: Vec::new(),
+                    referenced_by: Vec::new(),
+                });
+            for symbol in interposed {
+                if !interposition.interposed.contains(&symbol) {
+                    interposition.interposed.push(symbol);
+                }
+            }
+            if !interposition
+                .referenced_by
+                .contains(&binding.reference.object)
+            {
+                interposition.referenced_by.push(binding.reference.object);
+            }
+        }
+
+        Ok(BindingReport {
+            bindings,
+            interpositions: interpositions.into_values().collect(),
+        })
+    }
+
+    /// binds a single reference of the given object.
+    fn bind(
+        &self,
+        object_index: usize,
+        symbol_index: usize,
+        symbol: &SymbolRef<'a>,
+        is_copy: bool,
+    ) -> Result<BoundSymbol> {
+        let object = &self.objects[object_index];
+        let name = symbol.name_bytes()?;
+        let version = match &object.versions {
+            Some(versions) => versions.version(symbol_index)?.and_then(|v| v.name()),
+            None => None,
+        };
+        let reference = ScopeSymbol {
+            object: object_index,
+            symbol_index,
+        };
+        let bound = |definition: Option<ScopeSymbol>, kind: BindingKind| BoundSymbol {
+            reference,
+            name: name.to_vec(),
+            version: version.map(<[u8]>::to_vec),
+            definition,
+            kind,
+        };
+
+        let is_defined = symbol.related_section_index() != SHN_UNDEF;
+        if is_defined && symbol.info().binding == SymbolBinding::Local {
+            return Ok(bound(Some(reference), BindingKind::Local));
+        }
+        if is_defined && symbol.other_info().visibility != SymbolVisibility::Default {
+            return Ok(bound(Some(reference), BindingKind::Protected));
+        }
+
+        let mut found = None;
+        if object.symbolic && !is_copy {
+            found = self
+                .lookup_in_object(object_index, name, version)?
+                .map(|symbol_index| (object_index, symbol_index, BindingKind::Symbolic));
+        }
+        if found.is_none() {
+            for candidate in 0..self.objects.len() {
+                // the copy is the symbol of the referencing object, so the definition which is copied must be found
+                // elsewhere.
+                if is_copy && candidate == object_index {
+                    continue;
+                }
+                if let Some(symbol_index) = self.lookup_in_object(candidate, name, version)? {
+                    let kind = if is_copy {
+                        BindingKind::CopyRelocation
+                    } else {
+                        BindingKind::GlobalScope
+                    };
+                    found = Some((candidate, symbol_index, kind));
+                    break;
+                }
+            }
+        }
+
+        let Some((mut definition_object, mut definition_index, mut kind)) = found else {
+            let kind = if symbol.info().binding == SymbolBinding::Weak {
+                BindingKind::WeakUnresolved
+            } else {
+                BindingKind::Unresolved
+            };
+            return Ok(bound(None, kind));
+        };
+
+        // all references to a unique symbol are bound to its first definition in the process, regardless of how it
+        // was found.
+        let definition = self.symbol(definition_object, definition_index)?;
+        if definition.info().binding == SymbolBinding::GnuUnique {
+            for candidate in 0..self.objects.len() {
+                if let Some(symbol_index) = self.lookup_in_object(candidate, name, version)? {
+                    if self.symbol(candidate, symbol_index)?.info().binding
+                        == SymbolBinding::GnuUnique
+                    {
+                        definition_object = candidate;
+                        definition_index = symbol_index;
+                        kind = BindingKind::Unique;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let definition = ScopeSymbol {
+            object: definition_object,
+            symbol_index: definition_index,
+        };
+        Ok(bound(Some(definition), kind))
+    }
+
+    /// looks for a definition of the given symbol in the given object which matches the given version, like
+    /// `check_match` in glibc.
+    fn lookup_in_object(
+        &self,
+        object_index: usize,
+        name: &[u8],
+        version: Option<&[u8]>,
+    ) -> Result<Option<usize>> {
+        let object = &self.objects[object_index];
+        let Some(candidates) = object.definitions.get(name) else {
+            return Ok(None);
+        };
+        let Some(versions) = &object.versions else {
+            // objects without symbol versioning satisfy any version.
+            return Ok(candidates.first().copied());
+        };
+
+        // an unversioned reference is satisfied by the base version, or by the only non hidden version if there is
+        // no such definition.
+        let mut only_versioned = None;
+        let mut versioned_amount = 0;
+        for &symbol_index in candidates {
+            let Some(raw_index) = versions.version_index(symbol_index)? else {
+                return Ok(Some(symbol_index));
+            };
+            let index = raw_index & !VERSYM_HIDDEN;
+            let hidden = raw_index & VERSYM_HIDDEN != 0;
+            match version {
+                Some(version) => {
+                    let definition_version = versions.version(symbol_index)?;
+                    let matches = match definition_version {
+                        Some(SymbolVersion::Defined { name, .. }) => name == version,
+                        // like in glibc, a non hidden global definition satisfies any version.
+                        Some(SymbolVersion::Global) => !hidden,
+                        _ => false,
+                    };
+                    if matches {
+                        return Ok(Some(symbol_index));
+                    }
+                }
+                None => {
+                    if index < MAX_UNVERSIONED_REFERENCE_VERSION_INDEX {
+                        return Ok(Some(symbol_index));
+                    }
+                    if !hidden {
+                        versioned_amount += 1;
+                        only_versioned.get_or_insert(symbol_index);
+                    }
+                }
+            }
+        }
+        Ok(only_versioned.filter(|_| versioned_amount == 1))
+    }
+
+    fn symbol(&self, object_index: usize, symbol_index: usize) -> Result<SymbolRef<'a>> {
+        // symbols are only looked up in objects which have a dynamic symbol table.
+        self.objects[object_index]
+            .symbols
+            .as_ref()
+            .unwrap()
+            .get(symbol_index)
+    }
+
+    fn version_name_of(&self, symbol: ScopeSymbol) -> Result<Option<Vec<u8>>> {
+        Ok(match &self.objects[symbol.object].versions {
+            Some(versions) => versions
+                .version(symbol.symbol_index)?
+                .and_then(|version| version.name())
+                .map(<[u8]>::to_vec),
+            None => None,
+        })
+    }
+}
+
+/// can the symbol satisfy references from other objects.
+pub(crate) fn is_exported_definition(symbol: &SymbolRef) -> bool {
+    let info = symbol.info();
+    symbol.related_section_index() != SHN_UNDEF
+        && matches!(
+            info.binding.known(),
+            Some(SymbolBinding::Global | SymbolBinding::Weak | SymbolBinding::GnuUnique)
+        )
+        && !matches!(
+            info.ty.known(),
+            Some(SymbolType::Section | SymbolType::File)
+        )
+        && matches!(
+            symbol.other_info().visibility,
+            SymbolVisibility::Default | SymbolVisibility::Protected
+        )
+}
+
+/// the type of the copy relocation of the given architechture.
+fn copy_relocation_type(arch: MaybeUnknown<Architechture>) -> Option<u32> {
+    find_relocation_type(arch.known()?, "COPY")
+}
@@ -0,0 +1,367 @@
+use alloc::vec::Vec;
+
+use binary_serde::{BinarySerde, Endianness};
+
+use crate::{
+    push_serialized, ArchBitLength, Error, Result, StringTableBuilder, Symbol, Symbol32, Symbol64,
+    SymbolBinding, SymbolInfo, SymbolOtherInfo, SymbolType, SymbolVisibility,
+    VariantStructBinarySerde, SHN_UNDEF, VER_NDX_GLOBAL, VER_NDX_LOCAL,
+};
+
+/// the amount of bits to shift the hash by to get the second bit of the bloom filter of the `.gnu.hash` section.
+const GNU_HASH_BLOOM_SHIFT: u32 = 26;
+
+/// the kind of symbol table that is being built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SymbolTableKind {
+    /// a `.symtab` section.
+    Static,
+
+    /// a `.dynsym` section. the global symbols of a dynamic symbol table are ordered as required by the `.gnu.hash`
+    /// section, and the `.gnu.hash` and `.gnu.version` sections are generated along with it.
+    Dynamic,
+}
+
+/// a symbol to be added to a symbol table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NewSymbol {
+    pub name: Vec<u8>,
+    pub value: u64,
+    pub size: u64,
+    pub info: SymbolInfo,
+    pub other_info: SymbolOtherInfo,
+
+    /// the index of the section that the symbol is related to, or one of the special section indexes, for example
+    /// `SHN_UNDEF` or `SHN_ABS`.
+    pub section_index: u16,
+
+    /// the version index of the symbol in the `.gnu.version` section. if not specified, local symbols get
+    /// `VER_NDX_LOCAL` and other symbols get `VER_NDX_GLOBAL`. only used by dynamic symbol tables.
+    pub version: Option<u16>,
+}
+impl NewSymbol {
+    pub fn new(
+        name: impl Into<Vec<u8>>,
+        ty: SymbolType,
+        binding: SymbolBinding,
+        section_index: u16,
+        value: u64,
+        size: u64,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            size,
+            info: SymbolInfo {
+                ty: ty.into(),
+                binding: binding.into(),
+            },
+            other_info: SymbolOtherInfo {
+                visibility: SymbolVisibility::Default,
+                padding: 0,
+            },
+            section_index,
+            version: None,
+        }
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.info.binding == SymbolBinding::Local
+    }
+}
+
+/// an identifier of a symbol which was added to a symbol table builder, which can be used to find the final index of
+/// the symbol once the table is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(usize);
+
+/// a builder for symbol tables, which places local symbols before global symbols as required by the elf
+/// specification.
+#[derive(Debug, Clone)]
+pub struct SymbolTableBuilder {
+    kind: SymbolTableKind,
+    symbols: Vec<NewSymbol>,
+}
+impl SymbolTableBuilder {
+    pub fn new(kind: SymbolTableKind) -> Self {
+        Self {
+            kind,
+            symbols: Vec::new(),
+        }
+    }
+
+    pub fn kind(&self) -> SymbolTableKind {
+        self.kind
+    }
+
+    /// adds a symbol to the table. the index of the symbol in the table is only known once the table is built, and can
+    /// be retrieved using the returned id.
+    pub fn add(&mut self, symbol: NewSymbol) -> SymbolId {
+        self.symbols.push(symbol);
+        SymbolId(self.symbols.len() - 1)
+    }
+
+    pub fn get(&self, id: SymbolId) -> &NewSymbol {
+        &self.symbols[id.0]
+    }
+
+    pub fn get_mut(&mut self, id: SymbolId) -> &mut NewSymbol {
+        &mut self.symbols[id.0]
+    }
+
+    /// the amount of symbols in the table, not including the null symbol.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// builds the symbol table, adding the names of the symbols to the given string table.
+    ///
+    /// fails if some name can't be added to the string table, or if building a 32 bit table and the value or size of
+    /// some symbol doesn't fit in 32 bits.
+    pub fn build(
+        &self,
+        string_table: &mut StringTableBuilder,
+        bit_length: ArchBitLength,
+        endianness: Endianness,
+    ) -> Result<BuiltSymbolTable> {
+        let (locals, globals): (Vec<usize>, Vec<usize>) =
+            (0..self.symbols.len()).partition(|&i| self.symbols[i].is_local());
+
+        // the `.gnu.hash` section only covers defined symbols, which must be placed at the end of the table, sorted by
+        // their hash bucket.
+        let mut gnu_hash_info = None;
+        let globals = match self.kind {
+            SymbolTableKind::Static => globals,
+            SymbolTableKind::Dynamic => {
+                let (undefined, mut defined): (Vec<usize>, Vec<usize>) = globals
+                    .into_iter()
+                    .partition(|&i| self.symbols[i].section_index == SHN_UNDEF);
+                let buckets_amount = (defined.len() / 4).max(1) as u32;
+                defined.sort_by_key(|&i| gnu_hash(&self.symbols[i].name) % buckets_amount);
+                gnu_hash_info = Some((1 + locals.len() + undefined.len(), buckets_amount));
+                undefined.into_iter().chain(defined).collect()
+            }
+        };
+
+        let order: Vec<usize> = locals.iter().copied().chain(globals).collect();
+        let mut index_by_id = alloc::vec![0u32; self.symbols.len()];
+        for (i, &id) in order.iter().enumerate() {
+            index_by_id[id] = (i + 1) as u32;
+        }
+
+        // add longer names first so that shorter names can share their storage.
+        let mut name_offsets = alloc::vec![0u32; self.symbols.len()];
+        let mut ids_by_name_len: Vec<usize> = (0..self.symbols.len()).collect();
+        ids_by_name_len.sort_by_key(|&i| core::cmp::Reverse(self.symbols[i].name.len()));
+        for i in ids_by_name_len {
+            name_offsets[i] = string_table.add(&self.symbols[i].name)?;
+        }
+
+        let null_symbol = NewSymbol {
+            name: Vec::new(),
+            value: 0,
+            size: 0,
+            info: SymbolInfo {
+                ty: SymbolType::Notype.into(),
+                binding: SymbolBinding::Local.into(),
+            },
+            other_info: SymbolOtherInfo {
+                visibility: SymbolVisibility::Default,
+                padding: 0,
+            },
+            section_index: SHN_UNDEF,
+            version: Some(VER_NDX_LOCAL),
+        };
+        let records = core::iter::once((&null_symbol, 0))
+            .chain(order.iter().map(|&i| (&self.symbols[i], name_offsets[i])))
+            .map(|(symbol, name_offset)| build_symbol_record(symbol, name_offset, bit_length))
+            .collect::<Result<_>>()?;
+
+        let gnu_hash = gnu_hash_info.map(|(first_hashed_index, buckets_amount)| {
+            build_gnu_hash(
+                order[first_hashed_index - 1..]
+                    .iter()
+                    .map(|&i| gnu_hash(&self.symbols[i].name)),
+                first_hashed_index as u32,
+                buckets_amount,
+                bit_length,
+                endianness,
+            )
+        });
+        let gnu_version = match self.kind {
+            SymbolTableKind::Static => None,
+            SymbolTableKind::Dynamic => {
+                let mut content = Vec::new();
+                for symbol in
+                    core::iter::once(&null_symbol).chain(order.iter().map(|&i| &self.symbols[i]))
+                {
+                    let version = symbol.version.unwrap_or(if symbol.is_local() {
+                        VER_NDX_LOCAL
+                    } else {
+                        VER_NDX_GLOBAL
+                    });
+                    push_serialized(&mut content, &version, endianness);
+                }
+                Some(content)
+            }
+        };
+
+        Ok(BuiltSymbolTable {
+            records,
+            first_non_local_index: (1 + locals.len()) as u32,
+            index_by_id,
+            endianness,
+            gnu_hash,
+            gnu_version,
+        })
+    }
+}
+
+fn build_symbol_record(
+    symbol: &NewSymbol,
+    name_offset: u32,
+    bit_length: ArchBitLength,
+) -> Result<Symbol> {
+    Ok(match bit_length {
+        ArchBitLength::Arch32Bit => Symbol::Symbol32(Symbol32 {
+            name_index_in_string_table: name_offset,
+            value: symbol
+                .value
+                .try_into()
+                .map_err(|_| Error::ValueOutOfRange {
+                    value_of_what: "symbol value",
+                })?,
+            size: symbol.size.try_into().map_err(|_| Error::ValueOutOfRange {
+                value_of_what: "symbol size",
+            })?,
+            info: symbol.info.clone(),
+            other_info: symbol.other_info.clone(),
+            related_section_index: symbol.section_index,
+        }),
+        ArchBitLength::Arch64Bit => Symbol::Symbol64(Symbol64 {
+            name_index_in_string_table: name_offset,
+            info: symbol.info.clone(),
+            other_info: symbol.other_info.clone(),
+            related_section_index: symbol.section_index,
+            value: symbol.value,
+            size: symbol.size,
+        }),
+    })
+}
+
+/// the hash function used by the `.gnu.hash` section.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |hash, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte as u32)
+    })
+}
+
+/// builds the content of a `.gnu.hash` section for the given hashes of the hashed symbols, which must already be sorted
+/// by their bucket.
+fn build_gnu_hash(
+    hashes: impl Iterator<Item = u32> + Clone,
+    first_hashed_index: u32,
+    buckets_amount: u32,
+    bit_length: ArchBitLength,
+    endianness: Endianness,
+) -> Vec<u8> {
+    let word_bits: u32 = match bit_length {
+        ArchBitLength::Arch32Bit => 32,
+        ArchBitLength::Arch64Bit => 64,
+    };
+    let hashed_amount = hashes.clone().count() as u32;
+
+    // use about 12 bits of the bloom filter for each symbol. the amount of words must be a power of 2.
+    let bloom_words_amount = (hashed_amount * 12 / word_bits).max(1).next_power_of_two();
+    let mut bloom = alloc::vec![0u64; bloom_words_amount as usize];
+    let mut buckets = alloc::vec![0u32; buckets_amount as usize];
+    let mut chains = Vec::with_capacity(hashed_amount as usize);
+    for (i, hash) in hashes.enumerate() {
+        let word = &mut bloom[((hash / word_bits) % bloom_words_amount) as usize];
+        *word |= 1 << (hash % word_bits);
+        *word |= 1 << ((hash >> GNU_HASH_BLOOM_SHIFT) % word_bits);
+
+        let bucket = &mut buckets[(hash % buckets_amount) as usize];
+        if *bucket == 0 {
+            *bucket = first_hashed_index + i as u32;
+        } else {
+            // the symbol is not the last one in its bucket's chain.
+            let previous: &mut u32 = chains.last_mut().unwrap();
+            *previous &= !1;
+        }
+
+        // mark the symbol as the last one in its bucket's chain, until another symbol is added to the bucket.
+        chains.push(hash | 1);
+    }
+
+    let mut content = Vec::new();
+    for header_field in [
+        buckets_amount,
+        first_hashed_index,
+        bloom_words_amount,
+        GNU_HASH_BLOOM_SHIFT,
+    ] {
+        push_serialized(&mut content, &header_field, endianness);
+    }
+    for word in bloom {
+        match bit_length {
+            ArchBitLength::Arch32Bit => push_serialized(&mut content, &(word as u32), endianness),
+            ArchBitLength::Arch64Bit => push_serialized(&mut content, &word, endianness),
+        }
+    }
+    for value in buckets.into_iter().chain(chains) {
+        push_serialized(&mut content, &value, endianness);
+    }
+    content
+}
+
+/// a built symbol table.
+#[derive(Debug, Clone)]
+pub struct BuiltSymbolTable {
+    /// the records of the table, starting with the null symbol.
+    pub records: Vec<Symbol>,
+
+    /// the index of the first non local symbol, which should be used as the `sh_info` of the symbol table section.
+    pub first_non_local_index: u32,
+
+    /// the content of the `.gnu.hash` section of the table, if it is a dynamic symbol table.
+    pub gnu_hash: Option<Vec<u8>>,
+
+    /// the content of the `.gnu.version` section of the table, if it is a dynamic symbol table.
+    pub gnu_version: Option<Vec<u8>>,
+
+    index_by_id: Vec<u32>,
+    endianness: Endianness,
+}
+impl BuiltSymbolTable {
+    /// returns the index of the symbol with the given id in the table.
+    pub fn index_of(&self, id: SymbolId) -> u32 {
+        self.index_by_id[id.0]
+    }
+
+    /// the size of each record in the table, which should be used as the `sh_entsize` of the symbol table section.
+    pub fn entry_size(&self) -> usize {
+        match self.records.first() {
+            Some(Symbol::Symbol32(_)) => Symbol32::SERIALIZED_SIZE,
+            _ => Symbol64::SERIALIZED_SIZE,
+        }
+    }
+
+    /// serializes the records of the table to the content of the symbol table section.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let entry_size = self.entry_size();
+        let mut content = alloc::vec![0u8; self.records.len() * entry_size];
+        for (record, buf) in self
+            .records
+            .iter()
+            .zip(content.chunks_exact_mut(entry_size))
+        {
+            record.serialize(buf, self.endianness);
+        }
+        content
+    }
+}
@@ -0,0 +1,342 @@
+use binary_serde::Endianness;
+
+use crate::{
+    deserialize_at, elf_types::DebugIgnore, ElfParser, Error, Result, SectionData,
+    SectionHeaderType, StringTable, VersionDefinition, VersionDefinitionAux, VersionNeed,
+    VersionNeedAux, VERSYM_HIDDEN, VER_FLG_BASE, VER_NDX_GLOBAL, VER_NDX_LOCAL,
+};
+
+/// the version of a dynamic symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolVersion<'a> {
+    /// the symbol is local to the file.
+    Local,
+
+    /// the symbol is global and has no specific version.
+    Global,
+
+    /// the symbol is defined by the file with a version which the file defines.
+    Defined {
+        name: &'a [u8],
+        index: u16,
+
+        /// the symbol can only be referenced using an explicit version, like a `name@VERSION` symbol, as opposed to
+        /// the default version of the symbol, `name@@VERSION`.
+        hidden: bool,
+    },
+
+    /// the symbol is referenced by the file with a version which one of its needed libraries defines.
+    Needed {
+        name: &'a [u8],
+        file_name: &'a [u8],
+        index: u16,
+        hidden: bool,
+    },
+
+    /// the version index of the symbol doesn't match any version of the file.
+    Unknown { index: u16 },
+}
+impl<'a> SymbolVersion<'a> {
+    /// the name of the version, if the symbol has a specific version.
+    pub fn name(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Defined { name, .. } | Self::Needed { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        match self {
+            Self::Defined { hidden, .. } | Self::Needed { hidden, .. } => *hidden,
+            _ => false,
+        }
+    }
+}
+
+/// a version which is defined by the file, from its `.gnu.version_d` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DefinedVersion<'a> {
+    pub name: &'a [u8],
+    pub index: u16,
+    pub flags: u16,
+    pub hash: u32,
+}
+impl<'a> DefinedVersion<'a> {
+    /// is this the version definition which describes the file itself rather than a version.
+    pub fn is_base(&self) -> bool {
+        self.flags & VER_FLG_BASE != 0
+    }
+}
+
+/// a version which the file needs from one of its needed libraries, from its `.gnu.version_r` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NeededVersion<'a> {
+    pub name: &'a [u8],
+    pub file_name: &'a [u8],
+    pub index: u16,
+    pub flags: u16,
+    pub hash: u32,
+}
+
+/// the symbol versioning information of a file, from its `.gnu.version`, `.gnu.version_d` and `.gnu.version_r`
+/// sections.
+#[derive(Debug, Clone)]
+pub struct SymbolVersions<'a> {
+    versym: DebugIgnore<&'a [u8]>,
+    verdef: DebugIgnore<&'a [u8]>,
+    verneed: DebugIgnore<&'a [u8]>,
+    string_table: StringTable<'a>,
+    endianness: Endianness,
+}
+impl<'a> SymbolVersions<'a> {
+    /// the raw `.gnu.version` entry of the dynamic symbol with the given index, including its hidden bit.
+    pub fn version_index(&self, symbol_index: usize) -> Result<Option<u16>> {
+        let Some(offset) = symbol_index
+            .checked_mul(2)
+            .filter(|&offset| offset < self.versym.len())
+        else {
+            return Ok(None);
+        };
+        Ok(Some(deserialize_at(
+            &self.versym,
+            offset,
+            self.endianness,
+            Error::SymbolVersionSectionIsTruncated,
+        )?))
+    }
+
+    /// the version of the dynamic symbol with the given index, or `None` if the file has no `.gnu.version` entry for
+    /// it.
+    pub fn version(&self, symbol_index: usize) -> Result<Option<SymbolVersion<'a>>> {
+        let Some(raw_index) = self.version_index(symbol_index)? else {
+            return Ok(None);
+        };
+        let hidden = raw_index & VERSYM_HIDDEN != 0;
+        let index = raw_index & !VERSYM_HIDDEN;
+        match index {
+            VER_NDX_LOCAL => return Ok(Some(SymbolVersion::Local)),
+            VER_NDX_GLOBAL => return Ok(Some(SymbolVersion::Global)),
+            _ => {}
+        }
+        for version in self.defined_versions() {
+            let version = version?;
+            if version.index == index {
+                return Ok(Some(SymbolVersion::Defined {
+                    name: version.name,
+                    index,
+                    hidden,
+                }));
+            }
+        }
+        for version in self.needed_versions() {
+            let version = version?;
+            if version.index == index {
+                return Ok(Some(SymbolVersion::Needed {
+                    name: version.name,
+                    file_name: version.file_name,
+                    index,
+                    hidden,
+                }));
+            }
+        }
+        Ok(Some(SymbolVersion::Unknown { index }))
+    }
+
+    /// returns an iterator over the versions defined by the file.
+    pub fn defined_versions(&self) -> DefinedVersionsIter<'a> {
+        DefinedVersionsIter {
+            versions: self.clone(),
+            offset: Some(0).filter(|_| !self.verdef.is_empty()),
+        }
+    }
+
+    /// returns an iterator over the versions needed by the file.
+    pub fn needed_versions(&self) -> NeededVersionsIter<'a> {
+        NeededVersionsIter {
+            versions: self.clone(),
+            need_offset: Some(0).filter(|_| !self.verneed.is_empty()),
+            aux: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefinedVersionsIter<'a> {
+    versions: SymbolVersions<'a>,
+    offset: Option<usize>,
+}
+impl<'a> DefinedVersionsIter<'a> {
+    fn read(&self, offset: usize) -> Result<(DefinedVersion<'a>, u32)> {
+        let versions = &self.versions;
+        let definition: VersionDefinition = deserialize_at(
+            &versions.verdef,
+            offset,
+            versions.endianness,
+            Error::SymbolVersionSectionIsTruncated,
+        )?;
+        let aux_offset = offset
+            .checked_add(definition.aux_offset as usize)
+            .ok_or(Error::SymbolVersionSectionIsTruncated)?;
+        let aux: VersionDefinitionAux = deserialize_at(
+            &versions.verdef,
+            aux_offset,
+            versions.endianness,
+            Error::SymbolVersionSectionIsTruncated,
+        )?;
+        let name = versions
+            .string_table
+            .bytes_at_offset(aux.name_offset as usize, "version definition name")?;
+        Ok((
+            DefinedVersion {
+                name,
+                index: definition.index,
+                flags: definition.flags,
+                hash: definition.hash,
+            },
+            definition.next_offset,
+        ))
+    }
+}
+impl<'a> Iterator for DefinedVersionsIter<'a> {
+    type Item = Result<DefinedVersion<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset.take()?;
+        match self.read(offset) {
+            Ok((version, next_offset)) => {
+                if next_offset != 0 {
+                    self.offset = offset.checked_add(next_offset as usize);
+                }
+                Some(Ok(version))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NeededVersionsIter<'a> {
+    versions: SymbolVersions<'a>,
+    need_offset: Option<usize>,
+
+    /// the file name of the current library, the offset of its next auxiliary entry, and the offset of the next
+    /// library.
+    aux: Option<(&'a [u8], usize, Option<usize>)>,
+}
+impl<'a> NeededVersionsIter<'a> {
+    fn next_result(&mut self) -> Result<Option<NeededVersion<'a>>> {
+        let versions = &self.versions;
+        loop {
+            if let Some((file_name, aux_offset, next_need_offset)) = self.aux.take() {
+                let aux: VersionNeedAux = deserialize_at(
+                    &versions.verneed,
+                    aux_offset,
+                    versions.endianness,
+                    Error::SymbolVersionSectionIsTruncated,
+                )?;
+                let name = versions
+                    .string_table
+                    .bytes_at_offset(aux.name_offset as usize, "needed version name")?;
+                if aux.next_offset != 0 {
+                    self.aux = aux_offset
+                        .checked_add(aux.next_offset as usize)
+                        .map(|next_aux_offset| (file_name, next_aux_offset, next_need_offset));
+                } else {
+                    self.need_offset = next_need_offset;
+                }
+                return Ok(Some(NeededVersion {
+                    name,
+                    file_name,
+                    index: aux.index,
+                    flags: aux.flags,
+                    hash: aux.hash,
+                }));
+            }
+            let Some(need_offset) = self.need_offset.take() else {
+                return Ok(None);
+            };
+            let need: VersionNeed = deserialize_at(
+                &versions.verneed,
+                need_offset,
+                versions.endianness,
+                Error::SymbolVersionSectionIsTruncated,
+            )?;
+            let file_name = versions
+                .string_table
+                .bytes_at_offset(need.file_name_offset as usize, "needed version file name")?;
+            let next_need_offset = Some(need.next_offset)
+                .filter(|&next_offset| next_offset != 0)
+                .and_then(|next_offset| need_offset.checked_add(next_offset as usize));
+            if need.aux_amount == 0 {
+                self.need_offset = next_need_offset;
+                continue;
+            }
+            self.aux = need_offset
+                .checked_add(need.aux_offset as usize)
+                .map(|aux_offset| (file_name, aux_offset, next_need_offset));
+        }
+    }
+}
+impl<'a> Iterator for NeededVersionsIter<'a> {
+    type Item = Result<NeededVersion<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_result() {
+            Ok(version) => version.map(Ok),
+            Err(err) => {
+                // stop iterating after a malformed entry, since the following entries can't be located.
+                self.need_offset = None;
+                self.aux = None;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'a> ElfParser<'a> {
+    /// returns the symbol versioning information of the file, which is found using its section headers. returns
+    /// `None` if the file has no symbol versioning sections.
+    pub fn symbol_versions(&self) -> Result<Option<SymbolVersions<'a>>> {
+        let mut versym: &[u8] = &[];
+        let mut verdef: &[u8] = &[];
+        let mut verneed: &[u8] = &[];
+        let mut string_table_index = None;
+        for section in self.section_headers()? {
+            let section = section?;
+            let Some(ty) = section.ty().known() else {
+                continue;
+            };
+            match ty {
+                SectionHeaderType::GnuVersym => versym = section.content()?,
+                SectionHeaderType::GnuVerdef => {
+                    verdef = section.content()?;
+                    string_table_index = Some(section.link());
+                }
+                SectionHeaderType::GnuVerneed => {
+                    verneed = section.content()?;
+                    string_table_index = Some(section.link());
+                }
+                _ => {}
+            }
+        }
+        if versym.is_empty() && verdef.is_empty() && verneed.is_empty() {
+            return Ok(None);
+        }
+        let string_table = match string_table_index {
+            Some(index) => match self.section_headers()?.get(index as usize)?.data()? {
+                SectionData::StringTable(string_table) => string_table,
+                _ => return Err(Error::LinkedSectionOfVersionSectionIsNotAStringTable),
+            },
+            None => StringTable {
+                content: (&[][..]).into(),
+            },
+        };
+        Ok(Some(SymbolVersions {
+            versym: versym.into(),
+            verdef: verdef.into(),
+            verneed: verneed.into(),
+            string_table,
+            endianness: self.file_info().endianness,
+        }))
+    }
+}
@@ -0,0 +1,13 @@
+template <typename T> T f(T x) {
+    return x + 1;
+}
+
+int g_data = 5;
+
+long use_long(long x) {
+    return f(x) + g_data;
+}
+
+int use_int(int x) {
+    return f(x);
+}
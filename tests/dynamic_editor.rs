@@ -0,0 +1,81 @@
+use elflib::{DynamicEditor, ElfParser, Error, ProgramHeaderType};
+
+const MAIN_EXECUTABLE: &[u8] = include_bytes!("../test_binaries/build/gcc-main");
+
+fn count_program_headers(data: &[u8], ty: ProgramHeaderType) -> usize {
+    let parser = ElfParser::new(data).unwrap();
+    parser
+        .program_headers()
+        .unwrap()
+        .iter()
+        .filter(|program_header| *program_header.as_ref().unwrap().ty() == ty)
+        .count()
+}
+
+#[test]
+fn repeated_edits_extend_the_same_segment() {
+    let parser = ElfParser::new(MAIN_EXECUTABLE).unwrap();
+    let mut editor = DynamicEditor::new(&parser).unwrap();
+    editor
+        .set_runpath(Some(
+            b"/a/long/enough/runpath/to/grow/the/string/table".as_slice(),
+        ))
+        .unwrap();
+    let first = editor.write().unwrap();
+
+    let parser = ElfParser::new(&first).unwrap();
+    let mut editor = DynamicEditor::new(&parser).unwrap();
+    editor.add_needed("libfake1.so").unwrap();
+    let second = editor.write().unwrap();
+
+    let parser = ElfParser::new(&second).unwrap();
+    let editor = DynamicEditor::new(&parser).unwrap();
+    assert_eq!(
+        editor.runpath(),
+        Some(b"/a/long/enough/runpath/to/grow/the/string/table".as_slice())
+    );
+    assert!(editor.needed().any(|needed| needed == b"libfake1.so"));
+
+    let original_loads = count_program_headers(MAIN_EXECUTABLE, ProgramHeaderType::Load);
+    assert_eq!(
+        count_program_headers(&second, ProgramHeaderType::Load),
+        original_loads + 1
+    );
+    assert_eq!(
+        count_program_headers(&second, ProgramHeaderType::Note),
+        count_program_headers(MAIN_EXECUTABLE, ProgramHeaderType::Note)
+    );
+}
+
+#[test]
+fn strings_with_null_bytes_are_rejected() {
+    let parser = ElfParser::new(MAIN_EXECUTABLE).unwrap();
+    let mut editor = DynamicEditor::new(&parser).unwrap();
+    let needed: Vec<Vec<u8>> = editor.needed().map(|needed| needed.to_vec()).collect();
+    assert!(matches!(
+        editor.add_needed("libfake\0.so"),
+        Err(Error::StringContainsNullByte)
+    ));
+    assert!(matches!(
+        editor.set_soname("lib\0.so"),
+        Err(Error::StringContainsNullByte)
+    ));
+    assert!(editor.needed().eq(needed.iter().map(Vec::as_slice)));
+    assert_eq!(editor.soname(), None);
+}
+
+#[test]
+fn added_strings_share_the_storage_of_existing_strings() {
+    let parser = ElfParser::new(MAIN_EXECUTABLE).unwrap();
+    let mut editor = DynamicEditor::new(&parser).unwrap();
+    let libc = editor.needed().next().unwrap().to_vec();
+    editor.set_soname(&libc[3..]).unwrap();
+    let output = editor.write().unwrap();
+
+    // the string table only grows when a string is added, so reusing the tail of an existing string shouldn't move
+    // anything to a new segment.
+    assert_eq!(output.len(), MAIN_EXECUTABLE.len());
+    let parser = ElfParser::new(&output).unwrap();
+    let editor = DynamicEditor::new(&parser).unwrap();
+    assert_eq!(editor.soname(), Some(&libc[3..]));
+}
@@ -0,0 +1,243 @@
+use elflib::{
+    ElfParser, ElfRewriter, Error, ProgramHeaderType, SectionData, SectionHeaderRef,
+    SectionHeaderType, StripMode,
+};
+
+/// a relocatable object with two comdat groups, built by `make objects` in `test_binaries`.
+const GROUP_OBJECT: &[u8] = include_bytes!("../test_binaries/build/g++-group.o");
+
+const MAIN_EXECUTABLE: &[u8] = include_bytes!("../test_binaries/build/gcc-main");
+
+/// returns the name of the symbol table linked to each group section, along with the names of its members.
+fn group_members(data: &[u8]) -> Vec<(Vec<u8>, Vec<Vec<u8>>)> {
+    let parser = ElfParser::new(data).unwrap();
+    let sections = parser.section_headers().unwrap();
+    let mut groups = Vec::new();
+    for section in &sections {
+        let section = section.unwrap();
+        if *section.ty() != SectionHeaderType::Group {
+            continue;
+        }
+        let link = sections.get(section.link() as usize).unwrap();
+        let members = section.content().unwrap()[4..]
+            .chunks_exact(4)
+            .map(|member| {
+                let index = u32::from_le_bytes(member.try_into().unwrap());
+                let member = sections.get(index as usize).unwrap();
+                member.name_bytes().unwrap().to_vec()
+            })
+            .collect();
+        groups.push((link.name_bytes().unwrap().to_vec(), members));
+    }
+    groups
+}
+
+/// returns the name of the signature symbol of each group section.
+fn group_signatures(data: &[u8]) -> Vec<Vec<u8>> {
+    let parser = ElfParser::new(data).unwrap();
+    let sections = parser.section_headers().unwrap();
+    let mut signatures = Vec::new();
+    for section in &sections {
+        let section = section.unwrap();
+        if *section.ty() != SectionHeaderType::Group {
+            continue;
+        }
+        let symbol_table = sections.get(section.link() as usize).unwrap();
+        let SectionData::SymbolTable(symbols) = symbol_table.data().unwrap() else {
+            panic!("group section is not linked to a symbol table");
+        };
+        let signature = symbols.get(section.info() as usize).unwrap();
+        signatures.push(signature.name_bytes().unwrap().to_vec());
+    }
+    signatures
+}
+
+/// returns the section with the given name.
+fn find_section<'a>(parser: &ElfParser<'a>, name: &str) -> SectionHeaderRef<'a> {
+    parser
+        .section_headers()
+        .unwrap()
+        .iter()
+        .map(|section| section.unwrap())
+        .find(|section| section.name_bytes().unwrap() == name.as_bytes())
+        .unwrap()
+}
+
+/// returns the names of the symbols of the symbol table, and the names of the symbols referenced by the relocations of
+/// `.rela.text`.
+fn symbol_names(data: &[u8]) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    let parser = ElfParser::new(data).unwrap();
+    let SectionData::SymbolTable(symbols) = find_section(&parser, ".symtab").data().unwrap() else {
+        panic!(".symtab is not a symbol table");
+    };
+    let name_of = |index: usize| symbols.get(index).unwrap().name_bytes().unwrap().to_vec();
+    let names = (0..symbols.len()).map(name_of).collect();
+    let SectionData::RelocationSection(relocations) =
+        find_section(&parser, ".rela.text").data().unwrap()
+    else {
+        panic!(".rela.text is not a relocation section");
+    };
+    let referenced = relocations
+        .entries
+        .iter()
+        .map(|relocation| name_of(relocation.unwrap().symbol_index() as usize))
+        .collect();
+    (names, referenced)
+}
+
+/// returns the type, size and content of each note segment.
+fn note_segments(data: &[u8]) -> Vec<(ProgramHeaderType, u64, Vec<u8>)> {
+    let parser = ElfParser::new(data).unwrap();
+    parser
+        .program_headers()
+        .unwrap()
+        .iter()
+        .map(|program_header| program_header.unwrap())
+        .filter(|program_header| {
+            matches!(
+                program_header.ty().known(),
+                Some(ProgramHeaderType::Note | ProgramHeaderType::GnuProperty)
+            )
+        })
+        .map(|program_header| {
+            (
+                program_header.ty().known().unwrap(),
+                program_header.size_in_file(),
+                program_header.content_in_file().unwrap().to_vec(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn writing_without_changes_preserves_the_file() {
+    let build_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/test_binaries/build");
+    for entry in std::fs::read_dir(build_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let data = std::fs::read(&path).unwrap();
+        let parser = ElfParser::new(&data).unwrap();
+        let written = ElfRewriter::new(&parser).unwrap().write().unwrap();
+        assert!(written == data, "{} was modified", path.display());
+    }
+}
+
+#[test]
+fn removing_a_section_keeps_group_members() {
+    let parser = ElfParser::new(GROUP_OBJECT).unwrap();
+    let mut rewriter = ElfRewriter::new(&parser).unwrap();
+    let comment = rewriter.find_section(".comment").unwrap();
+    rewriter.remove_section(comment);
+    let written = rewriter.write().unwrap();
+
+    let groups = group_members(&written);
+    assert_eq!(groups, group_members(GROUP_OBJECT));
+    assert_eq!(
+        groups,
+        [
+            (b".symtab".to_vec(), vec![b".text._Z1fIlET_S0_".to_vec()]),
+            (b".symtab".to_vec(), vec![b".text._Z1fIiET_S0_".to_vec()]),
+        ]
+    );
+}
+
+/// removes the debug sections, along with the relocation sections which apply to them.
+fn remove_debug_sections(rewriter: &mut ElfRewriter) {
+    let debug_sections: Vec<_> = rewriter
+        .section_ids()
+        .filter(|&id| rewriter.section(id).name().starts_with(b".debug_"))
+        .collect();
+    for id in debug_sections {
+        rewriter.remove_section(id);
+    }
+}
+
+#[test]
+fn removing_a_section_drops_its_symbols() {
+    let parser = ElfParser::new(GROUP_OBJECT).unwrap();
+    let mut rewriter = ElfRewriter::new(&parser).unwrap();
+    remove_debug_sections(&mut rewriter);
+    let written = rewriter.write().unwrap();
+
+    let (names, referenced) = symbol_names(&written);
+    assert_eq!(
+        names,
+        [
+            &b""[..],
+            b"group.cpp",
+            b".text",
+            b".text._Z1fIlET_S0_",
+            b".text._Z1fIiET_S0_",
+            b"g_data",
+            b"_Z8use_longl",
+            b"_Z1fIlET_S0_",
+            b"_Z7use_inti",
+            b"_Z1fIiET_S0_",
+        ]
+    );
+    assert_eq!(referenced, symbol_names(GROUP_OBJECT).1);
+    let parser = ElfParser::new(&written).unwrap();
+    let symbol_table = find_section(&parser, ".symtab");
+    assert_eq!(symbol_table.info(), 5);
+    assert_eq!(group_signatures(&written), group_signatures(GROUP_OBJECT));
+}
+
+#[test]
+fn removing_a_referenced_symbol_fails() {
+    let parser = ElfParser::new(GROUP_OBJECT).unwrap();
+    let mut rewriter = ElfRewriter::new(&parser).unwrap();
+    let data = rewriter.find_section(".data").unwrap();
+    rewriter.remove_section(data);
+    assert!(matches!(
+        rewriter.write(),
+        Err(Error::RemovedSymbolIsReferenced {
+            symbol_index: 11,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn removing_the_members_of_a_group_removes_the_group() {
+    let parser = ElfParser::new(GROUP_OBJECT).unwrap();
+    let mut rewriter = ElfRewriter::new(&parser).unwrap();
+    remove_debug_sections(&mut rewriter);
+    for name in [".text", ".eh_frame", ".text._Z1fIlET_S0_"] {
+        let id = rewriter.find_section(name).unwrap();
+        rewriter.remove_section(id);
+    }
+    let written = rewriter.write().unwrap();
+
+    assert_eq!(
+        group_members(&written),
+        [(b".symtab".to_vec(), vec![b".text._Z1fIiET_S0_".to_vec()])]
+    );
+    assert_eq!(group_signatures(&written), [b"_Z1fIiET_S0_".to_vec()]);
+}
+
+#[test]
+fn stripping_keeps_group_signatures() {
+    let original_signatures = group_signatures(GROUP_OBJECT);
+    assert_eq!(
+        original_signatures,
+        [b"_Z1fIlET_S0_".to_vec(), b"_Z1fIiET_S0_".to_vec()]
+    );
+    for mode in [StripMode::Debug, StripMode::Unneeded] {
+        let parser = ElfParser::new(GROUP_OBJECT).unwrap();
+        let mut rewriter = ElfRewriter::new(&parser).unwrap();
+        rewriter.strip(mode).unwrap();
+        let written = rewriter.write().unwrap();
+        assert_eq!(group_signatures(&written), original_signatures);
+    }
+}
+
+#[test]
+fn only_keep_debug_keeps_the_note_segments() {
+    let parser = ElfParser::new(MAIN_EXECUTABLE).unwrap();
+    let mut rewriter = ElfRewriter::new(&parser).unwrap();
+    rewriter.only_keep_debug();
+    let written = rewriter.write().unwrap();
+
+    let original_notes = note_segments(MAIN_EXECUTABLE);
+    assert_eq!(original_notes.len(), 3);
+    assert_eq!(note_segments(&written), original_notes);
+}